@@ -1,8 +1,12 @@
 use std::thread;
 use std::thread::JoinHandle;
 use std::path;
+use std::fs;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
 
-use crate::rendering::Screen;
+use crate::rendering::{Screen, TerminalPreview};
+use crate::maths_objects::Point;
 use crate::primitive_conversions::*;
 use crate::errors;
 
@@ -10,45 +14,291 @@ use crate::errors;
 pub struct Scene {
     generator : fn(Screen, f32, f32) -> Screen,
     length : f32,
+    motion_blur : Option<MotionBlur>,
+    preview : Option<(u32, TerminalPreview)>,
+    progress : Option<ProgressReporting>,
+}
+
+/// Represents the settings for motion blur by temporal supersampling, as a shutter angle and the number of sub-frame samples.
+#[derive(Copy, Clone)]
+pub struct MotionBlur {
+    shutter_angle : f32,
+    samples : u16,
+}
+
+impl MotionBlur {
+    /// Creates a new MotionBlur. The shutter angle is in degrees, where 360 keeps the shutter open for the entire frame and 180 for half of it.
+    pub fn new(shutter_angle : f32, samples : u16) -> MotionBlur {
+        MotionBlur {
+            shutter_angle,
+            samples,
+        }
+    }
+
+    /// Generates a frame by evaluating the generator at each sub-frame timestamp while the shutter is open, and averaging the results.
+    fn generate(&self, generator : fn(Screen, f32, f32) -> Screen, init : Screen, timestamp : f32, frame_time : f32, length : f32) -> Screen {
+        if self.samples <= 1 {
+            return generator(init, timestamp, length);
+        }
+
+        let open_time = frame_time * self.shutter_angle.clamp(0.0, 360.0) / 360.0;
+
+        let frames : Vec<Screen> =
+            (0..self.samples)
+            .map(|sample| {
+                let sub_frame_timestamp =
+                    timestamp + open_time * f32::from(sample) / f32::from(self.samples);
+
+                generator(init.clone(), sub_frame_timestamp, length)
+            })
+            .collect();
+
+        Screen::average(frames)
+    }
+}
+
+/// Represents the settings for rendering a quick low resolution draft of an animation, as divisors of the resolution and frame rate, and the subfolder of the output folder to write the frames to, so that final frames are not overwritten.
+/// The number of samples taken along Functions, Polygons and Vectors is left unchanged, so that a draft keeps the same shapes, dashes and arrowhead positions as the final render; the time saved comes from the fewer pixels each sample covers.
+#[derive(Copy, Clone)]
+pub struct Draft {
+    resolution_divisor : u16,
+    frame_rate_divisor : u16,
+    subfolder : &'static str,
+}
+
+impl Draft {
+    /// Creates a new Draft.
+    pub fn new(resolution_divisor : u16, frame_rate_divisor : u16, subfolder : &'static str) -> Draft {
+        Draft {
+            resolution_divisor,
+            frame_rate_divisor,
+            subfolder,
+        }
+    }
+
+    /// Reduces the resolution of the initial frame and the frame rate, and creates the output folder for the draft frames if it does not already exist.
+    fn prepare(&self, init : &Screen, fps : u16, path : &str) -> Result<(Screen, u16, String), errors::Error> {
+        if !path::Path::new(path).is_dir() {
+            return Err(errors::Error::InvalidDirectory(path.to_string()));
+        }
+
+        let draft_path = path::Path::new(path).join(self.subfolder);
+
+        fs::create_dir_all(&draft_path)
+        .map_err(|e| errors::Error::DirectoryCreation(draft_path.clone(), e))?;
+
+        Ok((
+            init.downscale(self.resolution_divisor),
+            (fps / self.frame_rate_divisor.max(1)).max(1),
+            draft_path.to_string_lossy().into_owned()
+        ))
+    }
 }
 
 /// Represents a collection of scenes.
 pub struct Video {
-    scenes : Vec<Scene>
+    scenes : Vec<Scene>,
+    progress : Option<ProgressReporting>,
 }
 
 impl Video {
     /// Creates a new Video from a vector of scenes.
     pub fn new(scenes : Vec<Scene>) -> Video {
         Video {
-            scenes
+            scenes,
+            progress : None,
         }
     }
+
+    /// Reports the progress of the video after each frame, and a summary after each scene. This replaces the progress reporting of the individual scenes.
+    pub fn with_progress(mut self, progress : ProgressReporting) -> Video {
+        self.progress = Some(progress);
+        self
+    }
+
     /// Animates the entire video as a sequence of scenes, maintaining frame number between scenes
     /// and passing the final frame of each scene as init to the next scene.
-    pub fn animate(self, mut init : Screen, fps : u16, path : &'static str) -> Result<(), errors::Error> {
+    pub fn animate(self, init : Screen, fps : u16, path : &'static str) -> Result<(), errors::Error> {
         if !path::Path::new(path).is_dir() {
             Err(errors::Error::InvalidDirectory(path.to_string()))
         }
         else {
-            let mut next_frame = 0;
-            for scene in self.scenes {
-                (init, next_frame) = animate_helper(init, scene, fps, next_frame, path);
+            self.animate_scenes(init, fps, path.to_string());
+            Ok(())
+        }
+    }
+
+    /// Animates a low resolution draft of the entire video, writing the frames to the subfolder of the path specified by the draft.
+    pub fn animate_draft(self, init : Screen, fps : u16, path : &'static str, draft : Draft) -> Result<(), errors::Error> {
+        let (init, fps, draft_path) = draft.prepare(&init, fps, path)?;
+
+        self.animate_scenes(init, fps, draft_path);
+        Ok(())
+    }
+
+    /// Animates each scene in turn to the provided output folder.
+    fn animate_scenes(self, mut init : Screen, fps : u16, output_folder : String) {
+        let frames_total =
+            self.scenes
+            .iter()
+            .map(|scene| frame_quantity(fps, scene.length))
+            .sum();
+
+        let mut video_tracker =
+            self.progress.map(|reporting| ProgressTracker::new(reporting, frames_total));
+
+        let mut next_frame = 0;
+        for (index, scene) in self.scenes.into_iter().enumerate() {
+            let mut scene_tracker =
+                match video_tracker {
+                    Some(_) => None,
+                    None => scene.progress.map(|reporting| ProgressTracker::new(reporting, frame_quantity(fps, scene.length))),
+                };
+
+            let tracker =
+                if video_tracker.is_some() { &mut video_tracker } else { &mut scene_tracker };
+
+            (init, next_frame) = animate_helper(init, scene, fps, next_frame, output_folder.clone(), index, tracker);
+        }
+    }
+}
+
+/// Possible ways of reporting the progress of an animation, either as a line printed to standard error that is updated after each frame, or through callbacks after each frame and after each scene.
+#[derive(Copy, Clone)]
+pub enum ProgressReporting {
+    Line,
+    Callback(fn(&Progress), fn(&SceneSummary)),
+}
+
+/// Represents the progress of an animation after a frame has been written.
+#[derive(Copy, Clone, Debug)]
+pub struct Progress {
+    pub scene : usize,
+    pub frames_done : u32,
+    pub frames_total : u32,
+    pub elapsed : Duration,
+    pub eta : Duration,
+    pub render_time : Duration,
+    pub encode_time : Duration,
+}
+
+/// Represents a summary of the time spent animating a scene, where the render and encode times are averages per frame.
+#[derive(Copy, Clone, Debug)]
+pub struct SceneSummary {
+    pub scene : usize,
+    pub frames : u32,
+    pub elapsed : Duration,
+    pub mean_render_time : Duration,
+    pub mean_encode_time : Duration,
+    pub max_render_time : Duration,
+}
+
+/// Tracks the progress of an animation across scenes.
+struct ProgressTracker {
+    reporting : ProgressReporting,
+    start : Instant,
+    frames_done : u32,
+    frames_total : u32,
+}
+
+impl ProgressTracker {
+    /// Creates a new ProgressTracker, starting the timer.
+    fn new(reporting : ProgressReporting, frames_total : u32) -> ProgressTracker {
+        ProgressTracker {
+            reporting,
+            start : Instant::now(),
+            frames_done : 0,
+            frames_total,
+        }
+    }
+
+    /// Records a completed frame and reports the progress.
+    fn frame_done(&mut self, scene : usize, render_time : Duration, encode_time : Duration) {
+        self.frames_done += 1;
+
+        let elapsed = self.start.elapsed();
+        let remaining = self.frames_total.saturating_sub(self.frames_done);
+
+        let progress = Progress {
+            scene,
+            frames_done : self.frames_done,
+            frames_total : self.frames_total,
+            elapsed,
+            eta : elapsed.mul_f64(f64::from(remaining) / f64::from(self.frames_done)),
+            render_time,
+            encode_time,
+        };
+
+        match self.reporting {
+            ProgressReporting::Line => {
+                eprint!(
+                    "\rframe {}/{} ({:.1}%) | elapsed {:.1}s | eta {:.1}s | render {} ms | encode {} ms   ",
+                    progress.frames_done,
+                    progress.frames_total,
+                    100.0 * f64::from(progress.frames_done) / f64::from(progress.frames_total.max(1)),
+                    progress.elapsed.as_secs_f64(),
+                    progress.eta.as_secs_f64(),
+                    progress.render_time.as_millis(),
+                    progress.encode_time.as_millis()
+                );
             }
+            ProgressReporting::Callback(on_frame, _) => on_frame(&progress),
+        }
+    }
 
-            Ok(())
+    /// Reports the summary of a completed scene.
+    fn scene_done(&self, summary : SceneSummary) {
+        match self.reporting {
+            ProgressReporting::Line => {
+                eprintln!(
+                    "\rscene {} | {} frames in {:.1}s | mean render {} ms | mean encode {} ms | max render {} ms   ",
+                    summary.scene,
+                    summary.frames,
+                    summary.elapsed.as_secs_f64(),
+                    summary.mean_render_time.as_millis(),
+                    summary.mean_encode_time.as_millis(),
+                    summary.max_render_time.as_millis()
+                );
+            }
+            ProgressReporting::Callback(_, on_scene) => on_scene(&summary),
         }
     }
 }
 
+/// Calculates the number of frames in a scene of the provided length.
+fn frame_quantity(frame_rate : u16, length : f32) -> u32 {
+    f32_to_u32(f32::from(frame_rate) * length)
+}
+
 impl Scene {
     /// Creates a new Scene.
     pub fn new(generator : fn(Screen, f32, f32) -> Screen, length : f32) -> Scene {
         Scene {
             generator,
             length,
+            motion_blur : None,
+            preview : None,
+            progress : None,
         }
     }
+
+    /// Applies motion blur to every frame of the scene, by evaluating the generator at several sub-frame timestamps and averaging them.
+    pub fn with_motion_blur(mut self, motion_blur : MotionBlur) -> Scene {
+        self.motion_blur = Some(motion_blur);
+        self
+    }
+    /// Prints a low resolution preview of every nth frame to the terminal while the scene is animated.
+    pub fn with_preview(mut self, every : u32, preview : TerminalPreview) -> Scene {
+        self.preview = Some((every.max(1), preview));
+        self
+    }
+
+    /// Reports the progress of the scene after each frame, and a summary once it is complete.
+    pub fn with_progress(mut self, progress : ProgressReporting) -> Scene {
+        self.progress = Some(progress);
+        self
+    }
+
     /// Animates the scene, using init as the initial frame to be passed to the generator function.
     /// Path specifies the output folder for the frames.
     pub fn animate(self, init : Screen, fps : u16, path : &'static str) -> Result<(), errors::Error> {
@@ -56,17 +306,31 @@ impl Scene {
             Err(errors::Error::InvalidDirectory(path.to_string()))
         }
         else {
-            animate_helper(init, self, fps, 0, path);
+            let mut tracker = self.progress.map(|reporting| ProgressTracker::new(reporting, frame_quantity(fps, self.length)));
+
+            animate_helper(init, self, fps, 0, path.to_string(), 0, &mut tracker);
             Ok(())
         }
     }
+
+    /// Animates a low resolution draft of the scene, writing the frames to the subfolder of the path specified by the draft.
+    pub fn animate_draft(self, init : Screen, fps : u16, path : &'static str, draft : Draft) -> Result<(), errors::Error> {
+        let (init, fps, draft_path) = draft.prepare(&init, fps, path)?;
+
+        let mut tracker = self.progress.map(|reporting| ProgressTracker::new(reporting, frame_quantity(fps, self.length)));
+
+        animate_helper(init, self, fps, 0, draft_path, 0, &mut tracker);
+        Ok(())
+    }
 }
 
 /// Internal function for animating a scene which passes back the final frame and frame count.
-fn animate_helper(initial_frame : Screen, scene : Scene, frame_rate : u16, initial_frame_number : u32, output_folder : &'static str) -> (Screen, u32) {
+fn animate_helper(initial_frame : Screen, scene : Scene, frame_rate : u16, initial_frame_number : u32, output_folder : String, scene_index : usize, tracker : &mut Option<ProgressTracker>) -> (Screen, u32) {
+
+    let scene_start = Instant::now();
 
     // Total number of frames of video.
-    let frame_qty = f32_to_u32(f32::from(frame_rate) * scene.length);
+    let frame_qty = frame_quantity(frame_rate, scene.length);
 
     // Time spent showing each frame.
     let frame_time = 1.0 / f32::from(frame_rate);
@@ -81,23 +345,75 @@ fn animate_helper(initial_frame : Screen, scene : Scene, frame_rate : u16, initi
 
     let mut handles : Vec<JoinHandle<Screen>> = Vec::new();
 
+    // Pairs of render and encode times sent by each frame once it is written.
+    let (sender, receiver) = mpsc::channel::<(Duration, Duration)>();
+
     for (frame, timestamp) in timestamps {
         let new_initial_frame = initial_frame.clone();
+        let output_folder = output_folder.clone();
+        let sender = sender.clone();
 
         let handle = thread::spawn(move || {
             let filename =
                 format!("frame_{:0>#8}", frame + initial_frame_number);
 
-            let current_frame = (scene.generator)(new_initial_frame, timestamp, scene.length);
-            if let Err(e) = current_frame.write_to_png(output_folder, &filename) {
+            let render_start = Instant::now();
+
+            let current_frame =
+                match scene.motion_blur {
+                    Some(motion_blur) => motion_blur.generate(scene.generator, new_initial_frame, timestamp, frame_time, scene.length),
+                    None => (scene.generator)(new_initial_frame, timestamp, scene.length),
+                };
+
+            let encode_start = Instant::now();
+
+            if let Err(e) = current_frame.write_to_png(&output_folder, &filename) {
                 panic!("{}", e.to_string())
             }
+
+            // The receiver only goes away once every frame is accounted for.
+            let _ = sender.send((encode_start - render_start, encode_start.elapsed()));
+
+            if let Some((every, preview)) = scene.preview {
+                if (frame + initial_frame_number).is_multiple_of(every) {
+                    print!("{}\n{}", filename, current_frame.terminal_preview_string(preview));
+                }
+            }
             current_frame
         });
 
         handles.push(handle);
     }
 
+    drop(sender);
+
+    let mut total_render_time = Duration::ZERO;
+    let mut total_encode_time = Duration::ZERO;
+    let mut max_render_time = Duration::ZERO;
+
+    for (render_time, encode_time) in receiver {
+        total_render_time += render_time;
+        total_encode_time += encode_time;
+        max_render_time = max_render_time.max(render_time);
+
+        if let Some(tracker) = tracker {
+            tracker.frame_done(scene_index, render_time, encode_time);
+        }
+    }
+
+    if let Some(tracker) = tracker {
+        tracker.scene_done(
+            SceneSummary {
+                scene : scene_index,
+                frames : frame_qty,
+                elapsed : scene_start.elapsed(),
+                mean_render_time : total_render_time / frame_qty.max(1),
+                mean_encode_time : total_encode_time / frame_qty.max(1),
+                max_render_time,
+            }
+        );
+    }
+
     let mut final_frame = None;
     let length = handles.len();
 
@@ -120,6 +436,72 @@ pub fn placeholder(init : Screen, _time : f32, _len : f32) -> Screen {
     init
 }
 
+/// Represents the trail left behind a moving object, as the rule giving its position at each timestamp, the current timestamp and how far back in time the trail extends.
+pub struct Trail {
+    pub (in crate) position : Box<dyn Fn(f32) -> Point>,
+    pub (in crate) timestamp : f32,
+    pub (in crate) length : f32,
+}
+
+impl Trail {
+    /// Creates a new Trail. The trail is never extended to before the start of the scene at timestamp 0.
+    pub fn new(position : Box<dyn Fn(f32) -> Point>, timestamp : f32, length : f32) -> Trail {
+        Trail {
+            position,
+            timestamp,
+            length,
+        }
+    }
+
+    /// Samples the trail the specified number of times from newest to oldest, pairing each position with its age as a proportion of the length of the trail.
+    pub (in crate) fn sample(&self, number_of_samples : u16) -> Vec<(Point, f32)> {
+        let start = (self.timestamp - self.length).max(0.0);
+        let span = self.timestamp - start;
+
+        let mut samples = Vec::with_capacity(usize::from(number_of_samples));
+
+        for sample_number in 0..number_of_samples {
+            let parameter =
+                if number_of_samples == 1 { 0.0 } else { f32::from(sample_number) / (f32::from(number_of_samples) - 1.0) };
+
+            let age =
+                if self.length > 0.0 { parameter * span / self.length } else { 0.0 };
+
+            samples.push(((self.position)(self.timestamp - parameter * span), age));
+        }
+
+        samples
+    }
+}
+
+/// Types of decay curves, determining how the opacity of a trail falls off with age.
+#[derive(Copy, Clone)]
+pub enum Decay {
+    None,
+    Linear,
+    Exponential(f32),
+    Power(f32),
+}
+
+impl Decay {
+    /// Calculates the opacity of a part of a trail from its age as a proportion of the length of the trail.
+    pub (in crate) fn opacity(&self, age : f32) -> f32 {
+        match self {
+            Decay::None => 1.0,
+            Decay::Linear => 1.0 - age,
+            Decay::Exponential(rate) => (- rate * age).exp(),
+            Decay::Power(exponent) => (1.0 - age).powf(*exponent),
+        }
+    }
+}
+
+/// Possible styles for drawing a trail, either as a continuous polyline or as the specified number of evenly spaced dots.
+#[derive(Copy, Clone)]
+pub enum TrailStyle {
+    Polyline,
+    Dots(u16),
+}
+
 /// Types of easing functions to be used with easy_ease.
 pub enum EaseFn {
     Arctan(f32),
@@ -138,3 +520,151 @@ pub fn easy_ease(t : f32, function : EaseFn) -> f32 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colours::{Colour, linear_to_byte, byte_to_linear};
+    use crate::rendering::Blending;
+
+    /// Creates a small screen filled with a single grey.
+    fn grey_screen(level : u8, blending : Blending) -> Screen {
+        Screen::new(4, 3, Point::new(0.0, 0.0), Point::new(1.0, 1.0), Colour::from_rgb(level, level, level))
+        .with_blending(blending)
+    }
+
+    /// Fills the screen with a grey that gets lighter over time, reaching white at timestamp 1.
+    fn brightening(mut screen : Screen, timestamp : f32, _length : f32) -> Screen {
+        let level = f32_to_u8((255.0 * timestamp).round());
+
+        for y in 0..3 {
+            for x in 0..4 {
+                screen.set_pixel(x, y, Colour::from_rgb(level, level, level));
+            }
+        }
+
+        screen
+    }
+
+    #[test]
+    fn average_is_the_per_pixel_mean() {
+        let mut first = grey_screen(40, Blending::Legacy);
+        first.set_pixel(1, 1, Colour::from_rgb(200, 0, 100));
+        let second = grey_screen(120, Blending::Legacy);
+
+        let averaged = Screen::average(vec![first, second]);
+
+        assert!(averaged.pixel(0, 0) == Some(Colour::from_rgb(80, 80, 80)));
+        assert!(averaged.pixel(1, 1) == Some(Colour::from_rgb(160, 60, 110)));
+
+        // Linear blending averages light rather than sRGB values.
+        let averaged = Screen::average(vec![grey_screen(0, Blending::Linear), grey_screen(255, Blending::Linear)]);
+        let expected = linear_to_byte((byte_to_linear(0) + byte_to_linear(255)) / 2.0);
+
+        assert!(averaged.pixel(2, 2) == Some(Colour::from_rgb(expected, expected, expected)));
+    }
+
+    #[test]
+    fn motion_blur_averages_sub_frames_while_the_shutter_is_open() {
+        let init = grey_screen(0, Blending::Legacy);
+
+        // A 180 degree shutter over a frame of 0.5 samples at 0.2 and 0.325.
+        let blurred = MotionBlur::new(180.0, 2).generate(brightening, init.clone(), 0.2, 0.5, 1.0);
+        let expected = ((255.0_f32 * 0.2).round() + (255.0_f32 * 0.325).round()) / 2.0;
+        let expected = f32_to_u8(expected.round());
+
+        assert!(blurred.pixel(3, 2) == Some(Colour::from_rgb(expected, expected, expected)));
+
+        // A single sample is the frame at the timestamp itself.
+        let unblurred = MotionBlur::new(360.0, 1).generate(brightening, init, 0.6, 0.5, 1.0);
+
+        assert!(unblurred.pixel(0, 0) == Some(Colour::from_rgb(153, 153, 153)));
+    }
+
+    #[test]
+    fn decay_starts_opaque_and_never_increases() {
+        for decay in [Decay::None, Decay::Linear, Decay::Exponential(3.0), Decay::Power(2.0), Decay::Power(0.5)] {
+            assert_eq!(decay.opacity(0.0), 1.0);
+
+            let opacities : Vec<f32> = (0..=100).map(|age| decay.opacity(age as f32 / 100.0)).collect();
+
+            assert!(opacities.windows(2).all(|pair| pair[1] <= pair[0]));
+        }
+
+        assert_eq!(Decay::Linear.opacity(1.0), 0.0);
+        assert_eq!(Decay::Power(2.0).opacity(0.5), 0.25);
+    }
+
+    #[test]
+    fn trail_samples_from_newest_to_oldest() {
+        let trail = Trail::new(Box::new(|t| Point::new(t, 0.0)), 3.0, 2.0);
+        let samples = trail.sample(5);
+
+        let expected = [(3.0, 0.0), (2.5, 0.25), (2.0, 0.5), (1.5, 0.75), (1.0, 1.0)];
+
+        for ((point, age), (x, expected_age)) in samples.iter().zip(expected) {
+            assert_eq!((point.x, *age), (x, expected_age));
+        }
+
+        // Near the start of the scene the trail is cut short, so its oldest part is not fully aged.
+        let samples = Trail::new(Box::new(|t| Point::new(t, 0.0)), 0.5, 2.0).sample(2);
+
+        assert_eq!((samples[1].0.x, samples[1].1), (0.0, 0.25));
+    }
+
+    #[test]
+    fn draft_divides_resolution_and_frame_rate() {
+        let folder = std::env::temp_dir().join(format!("mathil-draft-test-{}", std::process::id()));
+        fs::create_dir_all(&folder).unwrap();
+        let path = folder.to_str().unwrap();
+
+        let init = Screen::new(160, 90, Point::new(0.0, 0.0), Point::new(16.0, 9.0), Colour::from_rgb(0, 0, 0));
+        let (screen, fps, draft_path) = Draft::new(4, 3, "drafts").prepare(&init, 60, path).unwrap();
+
+        assert_eq!((screen.horizontal_resolution, screen.vertical_resolution, fps), (40, 22, 20));
+        assert_eq!(screen.pixel_scale, 0.25);
+        assert!(path::Path::new(&draft_path).is_dir());
+
+        // Divisors are never allowed to reduce the frame rate below 1 or divide by zero.
+        let (screen, fps, _) = Draft::new(0, 0, "drafts").prepare(&init, 60, path).unwrap();
+
+        assert_eq!((screen.horizontal_resolution, screen.vertical_resolution, fps), (160, 90, 60));
+        assert_eq!(Draft::new(1, 100, "drafts").prepare(&init, 24, path).unwrap().1, 1);
+
+        assert!(Draft::new(2, 2, "drafts").prepare(&init, 60, folder.join("missing").to_str().unwrap()).is_err());
+
+        fs::remove_dir_all(&folder).unwrap();
+    }
+
+    static FRAME_REPORTS : std::sync::Mutex<Vec<(usize, u32, u32)>> = std::sync::Mutex::new(Vec::new());
+    static SCENE_REPORTS : std::sync::Mutex<Vec<(usize, u32)>> = std::sync::Mutex::new(Vec::new());
+
+    fn record_frame(progress : &Progress) {
+        FRAME_REPORTS.lock().unwrap().push((progress.scene, progress.frames_done, progress.frames_total));
+    }
+
+    fn record_scene(summary : &SceneSummary) {
+        SCENE_REPORTS.lock().unwrap().push((summary.scene, summary.frames));
+    }
+
+    #[test]
+    fn progress_is_reported_for_every_frame_and_scene() {
+        let folder = std::env::temp_dir().join(format!("mathil-progress-test-{}", std::process::id()));
+        fs::create_dir_all(&folder).unwrap();
+        let path : &'static str = Box::leak(folder.to_str().unwrap().to_string().into_boxed_str());
+
+        let init = grey_screen(0, Blending::Legacy);
+
+        Scene::new(placeholder, 0.5)
+        .with_progress(ProgressReporting::Callback(record_frame, record_scene))
+        .animate(init, 8, path)
+        .unwrap();
+
+        // Frames are reported in the order they finish, so only the counts are in order.
+        assert!(*FRAME_REPORTS.lock().unwrap() == [(0, 1, 4), (0, 2, 4), (0, 3, 4), (0, 4, 4)]);
+        assert!(*SCENE_REPORTS.lock().unwrap() == [(0, 4)]);
+        assert!(fs::read_dir(&folder).unwrap().count() == 4);
+
+        fs::remove_dir_all(&folder).unwrap();
+    }
+}
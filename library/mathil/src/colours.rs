@@ -1,6 +1,10 @@
 use std::f32::consts::PI;
+use std::sync::OnceLock;
+use std::str::FromStr;
 
 use crate::primitive_conversions::*;
+use crate::errors;
+use crate::helpers::lerp_scalar;
 
 /// Represents a colour in RGB format.
 #[derive(Copy, Clone, PartialEq)]
@@ -10,7 +14,7 @@ pub struct Colour {
     pub (in crate) blue : u8,
 }
 
-/// Generates a colour on the rainbow from red to purple based on an input from 0 to 1 (loops back on itself).
+/// Generates a colour on the rainbow from red to purple based on an input from 0 to 1 (loops back on itself). This is not perceptually uniform, so prefer a ColourScale such as viridis or turbo for encoding values.
 pub fn rainbow(t : f32) -> Colour {
     Colour::from_rgb(
         f32_to_u8(127.5 * (2.0 * PI * (t + 0.17)).sin() + 127.5),
@@ -29,27 +33,67 @@ impl Colour {
         }
     }
 
-    /// Creates a colour from the provided hex code string.
+    /// Creates a colour from the provided hex code string, panicking if it is invalid. See try_from_hex for the accepted formats.
     pub fn from_hex (hex_code : &str) -> Colour {
-        
-        fn to_dec (hex_number : &str) -> u8 {
-            u8::from_str_radix(hex_number, 16)
-            .expect("Invalid hexadecimal value.")
+        match Colour::try_from_hex(hex_code) {
+            Ok(colour) => colour,
+            Err(e) => panic!("{}", e.to_string()),
         }
+    }
+
+    /// Creates a colour from the provided hex code string of the format #RGB, #RGBA, #RRGGBB or #RRGGBBAA. Colours are opaque, so any alpha component is validated and then discarded.
+    pub fn try_from_hex (hex_code : &str) -> Result<Colour, errors::Error> {
+        let invalid = || errors::Error::InvalidColour(hex_code.to_string());
+
+        let digits =
+            hex_code
+            .strip_prefix('#')
+            .filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(invalid)?;
 
-        if hex_code.len() == 7 && hex_code.starts_with('#') {
-            Colour {
-                red : to_dec(&hex_code[1..3]),
-                green : to_dec(&hex_code[3..5]),
-                blue : to_dec(&hex_code[5..7]),
+        let to_dec = |hex_number : &str| u8::from_str_radix(hex_number, 16).map_err(|_| invalid());
+
+        match digits.len() {
+            3 | 4 => {
+                // Each digit is repeated, so that #F80 is equivalent to #FF8800.
+                let channel = |i : usize| to_dec(&digits[i..=i]).map(|value| value * 17);
+                Ok(Colour::from_rgb(channel(0)?, channel(1)?, channel(2)?))
+            }
+            6 | 8 => {
+                Ok(Colour::from_rgb(to_dec(&digits[0..2])?, to_dec(&digits[2..4])?, to_dec(&digits[4..6])?))
             }
+            _ => Err(invalid()),
         }
-        else {
-            panic!("Hex code must be of the format #RRGGBB with each colour as a hexadecimal value.");
-        }       
     }
 
-    /// Linear interpolation between colours.
+    /// Creates a colour from the name of one of the colours in the css_colours module, ignoring case and treating spaces and hyphens as underscores.
+    pub fn from_name (name : &str) -> Result<Colour, errors::Error> {
+        let normalised : String =
+            name
+            .trim()
+            .chars()
+            .map(|c| if c == ' ' || c == '-' { '_' } else { c.to_ascii_uppercase() })
+            .collect();
+
+        css_colours::NAMED
+        .iter()
+        .find(|(colour_name, _)| *colour_name == normalised)
+        .map(|(_, colour)| **colour)
+        .ok_or_else(|| errors::Error::InvalidColour(name.to_string()))
+    }
+
+    /// Finds the perceptually nearest colour in the css_colours module, returning its name (as declared there) and value.
+    pub fn nearest_name (&self) -> (&'static str, Colour) {
+        let (name, colour) =
+            css_colours::NAMED
+            .iter()
+            .min_by(|(_, c1), (_, c2)| self.distance(**c1).total_cmp(&self.distance(**c2)))
+            .unwrap();
+
+        (name, **colour)
+    }
+
+    /// Linear interpolation between colours, directly on the sRGB values. Use lerp_in to interpolate in other colour spaces.
     pub fn lerp(start : Colour, finish : Colour, t : f32) -> Colour {
         Colour::from_rgb(
             ((1.0 - t) * f32::from(start.red) + t * f32::from(finish.red)) as u8,
@@ -57,875 +101,1648 @@ impl Colour {
             ((1.0 - t) * f32::from(start.blue) + t * f32::from(finish.blue)) as u8
         )
     }
+
+    /// Linear interpolation between colours in linear light, using lookup tables for the sRGB conversions, as used when blending.
+    pub (in crate) fn lerp_linear_light(start : Colour, finish : Colour, t : f32) -> Colour {
+        let channel = |start : u8, finish : u8| {
+            linear_to_byte((1.0 - t) * byte_to_linear(start) + t * byte_to_linear(finish))
+        };
+
+        Colour::from_rgb(
+            channel(start.red, finish.red),
+            channel(start.green, finish.green),
+            channel(start.blue, finish.blue)
+        )
+    }
+
+    /// Interpolation between colours within the specified colour space. Hues are interpolated the shorter way around the colour wheel.
+    pub fn lerp_in(space : ColourSpace, start : Colour, finish : Colour, t : f32) -> Colour {
+        let lerp_triple = |(a1, a2, a3) : (f32, f32, f32), (b1, b2, b3) : (f32, f32, f32)| {
+            (lerp_scalar(a1, b1, t), lerp_scalar(a2, b2, t), lerp_scalar(a3, b3, t))
+        };
+
+        match space {
+            ColourSpace::Srgb => {
+                let (r, g, b) = lerp_triple(start.to_srgb(), finish.to_srgb());
+                Colour::from_srgb(r, g, b)
+            }
+            ColourSpace::LinearRgb => {
+                let (r, g, b) = lerp_triple(start.to_linear_rgb(), finish.to_linear_rgb());
+                Colour::from_linear_rgb(r, g, b)
+            }
+            ColourSpace::Hsl => {
+                let ((h1, s1, l1), (h2, s2, l2)) = (start.to_hsl(), finish.to_hsl());
+                Colour::from_hsl(lerp_hue(h1, s1, h2, s2, t), lerp_scalar(s1, s2, t), lerp_scalar(l1, l2, t))
+            }
+            ColourSpace::Hsv => {
+                let ((h1, s1, v1), (h2, s2, v2)) = (start.to_hsv(), finish.to_hsv());
+                Colour::from_hsv(lerp_hue(h1, s1, h2, s2, t), lerp_scalar(s1, s2, t), lerp_scalar(v1, v2, t))
+            }
+            ColourSpace::Lab => {
+                let (l, a, b) = lerp_triple(start.to_lab(), finish.to_lab());
+                Colour::from_lab(l, a, b)
+            }
+            ColourSpace::Oklab => {
+                let (l, a, b) = lerp_triple(start.to_oklab(), finish.to_oklab());
+                Colour::from_oklab(l, a, b)
+            }
+            ColourSpace::Oklch => {
+                let ((l1, c1, h1), (l2, c2, h2)) = (start.to_oklch(), finish.to_oklch());
+                Colour::from_oklch(lerp_scalar(l1, l2, t), lerp_scalar(c1, c2, t), lerp_hue(h1, c1, h2, c2, t))
+            }
+        }
+    }
+
+    /// Simulates how the colour appears to someone with the specified colour vision deficiency.
+    pub fn simulate(&self, deficiency : ColourVisionDeficiency) -> Colour {
+        let (r, g, b) = deficiency.simulate_linear(self.to_linear_rgb());
+
+        Colour::from_linear_rgb(r, g, b)
+    }
+
+    /// Calculates the perceptual distance between two colours, as the euclidean distance between them in OKLab.
+    pub fn distance(&self, other : Colour) -> f32 {
+        let ((l1, a1, b1), (l2, a2, b2)) = (self.to_oklab(), other.to_oklab());
+
+        ((l1 - l2) * (l1 - l2) + (a1 - a2) * (a1 - a2) + (b1 - b2) * (b1 - b2)).sqrt()
+    }
+
+    /// Gets the red, green and blue values of the colour.
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        (self.red, self.green, self.blue)
+    }
+
+    /// Creates a colour from sRGB values from 0 to 1, clamping values outside of that range.
+    pub fn from_srgb(r : f32, g : f32, b : f32) -> Colour {
+        Colour::from_rgb(
+            unit_to_byte(r),
+            unit_to_byte(g),
+            unit_to_byte(b)
+        )
+    }
+
+    /// Gets the sRGB values of the colour from 0 to 1.
+    pub fn to_srgb(&self) -> (f32, f32, f32) {
+        (
+            f32::from(self.red) / 255.0,
+            f32::from(self.green) / 255.0,
+            f32::from(self.blue) / 255.0
+        )
+    }
+
+    /// Creates a colour from linear light RGB values from 0 to 1, clamping values outside of that range.
+    pub fn from_linear_rgb(r : f32, g : f32, b : f32) -> Colour {
+        Colour::from_srgb(
+            srgb_encode(r),
+            srgb_encode(g),
+            srgb_encode(b)
+        )
+    }
+
+    /// Gets the linear light RGB values of the colour from 0 to 1.
+    pub fn to_linear_rgb(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_srgb();
+
+        (srgb_decode(r), srgb_decode(g), srgb_decode(b))
+    }
+
+    /// Calculates the relative luminance of the colour from 0 to 1, as linear light.
+    pub fn luminance(&self) -> f32 {
+        let (r, g, b) = self.to_linear_rgb();
+        let [wr, wg, wb] = LUMINANCE_WEIGHTS;
+
+        (wr * r + wg * g + wb * b).clamp(0.0, 1.0)
+    }
+
+    /// Creates a colour from a hue in degrees, and a saturation and lightness from 0 to 1.
+    pub fn from_hsl(hue : f32, saturation : f32, lightness : f32) -> Colour {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+
+        let (r, g, b) = hue_to_rgb(hue, chroma);
+        let offset = lightness - chroma / 2.0;
+
+        Colour::from_srgb(r + offset, g + offset, b + offset)
+    }
+
+    /// Gets the hue in degrees, and the saturation and lightness from 0 to 1, of the colour.
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_srgb();
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = max - min;
+
+        let lightness = (max + min) / 2.0;
+
+        let saturation =
+            if lightness <= 0.0 || lightness >= 1.0 { 0.0 } else { chroma / (1.0 - (2.0 * lightness - 1.0).abs()) };
+
+        (rgb_to_hue(r, g, b), saturation, lightness)
+    }
+
+    /// Creates a colour from a hue in degrees, and a saturation and value from 0 to 1.
+    pub fn from_hsv(hue : f32, saturation : f32, value : f32) -> Colour {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let value = value.clamp(0.0, 1.0);
+
+        let chroma = value * saturation;
+
+        let (r, g, b) = hue_to_rgb(hue, chroma);
+        let offset = value - chroma;
+
+        Colour::from_srgb(r + offset, g + offset, b + offset)
+    }
+
+    /// Gets the hue in degrees, and the saturation and value from 0 to 1, of the colour.
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_srgb();
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+
+        let saturation =
+            if max <= 0.0 { 0.0 } else { (max - min) / max };
+
+        (rgb_to_hue(r, g, b), saturation, max)
+    }
+
+    /// Creates a colour from CIE L*a*b* coordinates, relative to the D65 white point, with lightness from 0 to 100.
+    pub fn from_lab(l : f32, a : f32, b : f32) -> Colour {
+        fn inverse(t : f32) -> f32 {
+            let delta = 6.0 / 29.0;
+            if t > delta { t * t * t } else { 3.0 * delta * delta * (t - 4.0 / 29.0) }
+        }
+
+        let f_y = (l + 16.0) / 116.0;
+        let f_x = f_y + a / 500.0;
+        let f_z = f_y - b / 200.0;
+
+        let (x, y, z) = (0.950_47 * inverse(f_x), inverse(f_y), 1.088_83 * inverse(f_z));
+
+        Colour::from_linear_rgb(
+            3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z,
+            -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z,
+            0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z
+        )
+    }
+
+    /// Gets the CIE L*a*b* coordinates of the colour, relative to the D65 white point, with lightness from 0 to 100.
+    pub fn to_lab(&self) -> (f32, f32, f32) {
+        fn forward(t : f32) -> f32 {
+            let delta = 6.0 / 29.0;
+            if t > delta * delta * delta { t.cbrt() } else { t / (3.0 * delta * delta) + 4.0 / 29.0 }
+        }
+
+        let (r, g, b) = self.to_linear_rgb();
+
+        let x = 0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b;
+        let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+        let z = 0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b;
+
+        let (f_x, f_y, f_z) = (forward(x / 0.950_47), forward(y), forward(z / 1.088_83));
+
+        (116.0 * f_y - 16.0, 500.0 * (f_x - f_y), 200.0 * (f_y - f_z))
+    }
+
+    /// Creates a colour from OKLab coordinates, with lightness from 0 to 1.
+    pub fn from_oklab(l : f32, a : f32, b : f32) -> Colour {
+        let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+        let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+        Colour::from_linear_rgb(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s
+        )
+    }
+
+    /// Gets the OKLab coordinates of the colour, with lightness from 0 to 1.
+    pub fn to_oklab(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_linear_rgb();
+
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        (
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s
+        )
+    }
+
+    /// Creates a colour from OKLCH coordinates, with lightness from 0 to 1 and hue in degrees.
+    pub fn from_oklch(lightness : f32, chroma : f32, hue : f32) -> Colour {
+        let hue = hue.to_radians();
+
+        Colour::from_oklab(lightness, chroma * hue.cos(), chroma * hue.sin())
+    }
+
+    /// Gets the OKLCH coordinates of the colour, with lightness from 0 to 1 and hue in degrees.
+    pub fn to_oklch(&self) -> (f32, f32, f32) {
+        let (l, a, b) = self.to_oklab();
+
+        (l, (a * a + b * b).sqrt(), b.atan2(a).to_degrees().rem_euclid(360.0))
+    }
+}
+
+/// Parses a colour from a hex code (see Colour::try_from_hex), a CSS style rgb(r, g, b) or hsl(h, s%, l%) function, or the name of a colour in the css_colours module.
+/// As with hex codes, any alpha argument is validated and then discarded.
+impl FromStr for Colour {
+    type Err = errors::Error;
+
+    fn from_str(string : &str) -> Result<Colour, errors::Error> {
+        let trimmed = string.trim();
+        let lowercase = trimmed.to_ascii_lowercase();
+
+        let invalid = || errors::Error::InvalidColour(string.to_string());
+
+        // Gets the arguments of a CSS style function, separated by commas, spaces or a slash before the alpha.
+        let arguments = |names : &[&str]| -> Option<Vec<String>> {
+            names
+            .iter()
+            .find_map(|name| lowercase.strip_prefix(name))
+            .and_then(|rest| rest.trim_start().strip_prefix('('))
+            .and_then(|rest| rest.strip_suffix(')'))
+            .map(|inner| {
+                inner
+                .split(|c : char| c == ',' || c == '/' || c.is_whitespace())
+                .filter(|argument| !argument.is_empty())
+                .map(String::from)
+                .collect()
+            })
+        };
+
+        // Parses a finite number, where percentages from 0% to 100% are scaled to the provided maximum.
+        let number = |argument : &str, maximum : f32| -> Result<f32, errors::Error> {
+            let value = match argument.strip_suffix('%') {
+                Some(percentage) => percentage.parse::<f32>().ok().filter(|value| (0.0..=100.0).contains(value)).map(|value| value / 100.0 * maximum),
+                None => argument.parse::<f32>().ok(),
+            };
+
+            value.filter(|value| value.is_finite()).ok_or_else(invalid)
+        };
+
+        // Parses a number that must lie between 0 and the provided maximum.
+        let bounded = |argument : &str, maximum : f32| -> Result<f32, errors::Error> {
+            number(argument, maximum).and_then(|value| if (0.0..=maximum).contains(&value) { Ok(value) } else { Err(invalid()) })
+        };
+
+        // Validates the optional alpha argument, from 0 to 1 or 0% to 100%.
+        let alpha = |arguments : &[String]| -> Result<(), errors::Error> {
+            arguments.get(3).map_or(Ok(()), |argument| bounded(argument, 1.0).map(|_| ()))
+        };
+
+        if trimmed.starts_with('#') {
+            Colour::try_from_hex(trimmed)
+        }
+        else if let Some(arguments) = arguments(&["rgba", "rgb"]) {
+            if arguments.len() != 3 && arguments.len() != 4 {
+                return Err(invalid());
+            }
+
+            let channel = |argument : &str| bounded(argument, 255.0).map(|value| value / 255.0);
+
+            alpha(&arguments)?;
+
+            Ok(Colour::from_srgb(channel(&arguments[0])?, channel(&arguments[1])?, channel(&arguments[2])?))
+        }
+        else if let Some(arguments) = arguments(&["hsla", "hsl"]) {
+            if arguments.len() != 3 && arguments.len() != 4 {
+                return Err(invalid());
+            }
+
+            let hue = number(arguments[0].trim_end_matches("deg"), 360.0)?;
+
+            alpha(&arguments)?;
+
+            Ok(Colour::from_hsl(hue, bounded(&arguments[1], 1.0)?, bounded(&arguments[2], 1.0)?))
+        }
+        else {
+            Colour::from_name(trimmed)
+        }
+    }
+}
+
+/// Represents a continuous colour map from a domain of values to colours, as a series of colour stops with positions from 0 to 1.
+#[derive(Clone)]
+pub struct ColourScale {
+    stops : Vec<(f32, Colour)>,
+    domain : (f32, f32),
+    space : ColourSpace,
+    bins : Option<u16>,
+}
+
+impl ColourScale {
+    /// Creates a ColourScale from colour stops with positions from 0 to 1, with a domain from 0 to 1 and interpolation in OKLab.
+    pub fn new(mut stops : Vec<(f32, Colour)>) -> ColourScale {
+        if stops.is_empty() {
+            panic!("A colour scale requires at least one colour stop.");
+        }
+
+        stops.sort_by(|(p1, _), (p2, _)| p1.total_cmp(p2));
+
+        ColourScale {
+            stops,
+            domain : (0.0, 1.0),
+            space : ColourSpace::Oklab,
+            bins : None,
+        }
+    }
+
+    /// Creates a ColourScale from evenly spaced colours.
+    pub fn from_colours(colours : Vec<Colour>) -> ColourScale {
+        let last = colours.len().saturating_sub(1).max(1) as f32;
+
+        ColourScale::new(
+            colours
+            .into_iter()
+            .enumerate()
+            .map(|(i, colour)| (i as f32 / last, colour))
+            .collect()
+        )
+    }
+
+    /// Creates a ColourScale from evenly spaced colours given as hexadecimal RGB values.
+    fn from_table(table : &[u32]) -> ColourScale {
+        ColourScale::from_colours(
+            table
+            .iter()
+            .map(|hex| Colour::from_rgb((hex >> 16) as u8, (hex >> 8) as u8, *hex as u8))
+            .collect()
+        )
+    }
+
+    /// The perceptually uniform viridis colour map, from dark blue through green to yellow.
+    pub fn viridis() -> ColourScale {
+        ColourScale::from_table(&VIRIDIS)
+    }
+
+    /// The perceptually uniform magma colour map, from black through purple to pale yellow.
+    pub fn magma() -> ColourScale {
+        ColourScale::from_table(&MAGMA)
+    }
+
+    /// The perceptually uniform inferno colour map, from black through red to pale yellow.
+    pub fn inferno() -> ColourScale {
+        ColourScale::from_table(&INFERNO)
+    }
+
+    /// The perceptually uniform plasma colour map, from dark blue through pink to yellow.
+    pub fn plasma() -> ColourScale {
+        ColourScale::from_table(&PLASMA)
+    }
+
+    /// The cividis colour map, from dark blue to yellow, designed to be readable with colour vision deficiencies.
+    pub fn cividis() -> ColourScale {
+        ColourScale::from_table(&CIVIDIS)
+    }
+
+    /// The turbo colour map, an improved rainbow from dark blue through green to dark red.
+    pub fn turbo() -> ColourScale {
+        ColourScale::from_table(&TURBO)
+    }
+
+    /// The diverging coolwarm colour map, from blue through light grey to red.
+    pub fn coolwarm() -> ColourScale {
+        ColourScale::from_table(&COOLWARM)
+    }
+
+    /// Sets the domain of values which is mapped onto the scale, where values outside of the domain are clamped.
+    pub fn domain(mut self, min : f32, max : f32) -> ColourScale {
+        self.domain = (min, max);
+        self
+    }
+
+    /// Reverses the order of the colours in the scale.
+    pub fn reversed(mut self) -> ColourScale {
+        self.stops =
+            self.stops
+            .into_iter()
+            .rev()
+            .map(|(position, colour)| (1.0 - position, colour))
+            .collect();
+        self
+    }
+
+    /// Discretises the scale into the specified number of bins of a solid colour, taken from the centre of each bin.
+    pub fn discrete(mut self, bins : u16) -> ColourScale {
+        self.bins = Some(bins.max(1));
+        self
+    }
+
+    /// Sets the colour space in which colours are interpolated between stops.
+    pub fn interpolated_in(mut self, space : ColourSpace) -> ColourScale {
+        self.space = space;
+        self
+    }
+
+    /// Gets the colour corresponding to the provided value.
+    pub fn colour(&self, value : f32) -> Colour {
+        let (min, max) = self.domain;
+
+        let t =
+            if max == min { 0.0 } else { (value - min) / (max - min) };
+
+        self.colour_at_fraction(t)
+    }
+
+    /// Gets the colour at the provided fraction of the way along the scale from 0 to 1, ignoring the domain.
+    pub (in crate) fn colour_at_fraction(&self, t : f32) -> Colour {
+        let mut t = t.clamp(0.0, 1.0);
+
+        if let Some(bins) = self.bins {
+            let bins = f32::from(bins);
+            t = ((t * bins).floor().min(bins - 1.0) + 0.5) / bins;
+        }
+
+        self.colour_at_position(t)
+    }
+
+    /// Gets the colour at the provided position from 0 to 1 along the colour stops.
+    fn colour_at_position(&self, t : f32) -> Colour {
+        let first = self.stops[0];
+        let last = self.stops[self.stops.len() - 1];
+
+        if t <= first.0 {
+            return first.1;
+        }
+        if t >= last.0 {
+            return last.1;
+        }
+
+        for window in self.stops.windows(2) {
+            let (start_position, start) = window[0];
+            let (finish_position, finish) = window[1];
+
+            if t <= finish_position {
+                let parameter =
+                    if finish_position == start_position { 1.0 } else { (t - start_position) / (finish_position - start_position) };
+
+                return Colour::lerp_in(self.space, start, finish, parameter);
+            }
+        }
+
+        last.1
+    }
+}
+
+// Colour maps sampled at evenly spaced positions, as hexadecimal RGB values.
+const VIRIDIS : [u32; 10] = [0x440154, 0x482878, 0x3E4A89, 0x31688E, 0x26828E, 0x1F9E89, 0x35B779, 0x6DCD59, 0xB4DE2C, 0xFDE725];
+const MAGMA : [u32; 10] = [0x000004, 0x180F3E, 0x451077, 0x721F81, 0x9F2F7F, 0xCD4071, 0xF1605D, 0xFD9567, 0xFEC98D, 0xFCFDBF];
+const INFERNO : [u32; 10] = [0x000004, 0x1B0C42, 0x4B0C6B, 0x781C6D, 0xA52C60, 0xCF4446, 0xED6925, 0xFB9A06, 0xF7D03C, 0xFCFFA4];
+const PLASMA : [u32; 10] = [0x0D0887, 0x47039F, 0x7301A8, 0x9C179E, 0xBD3786, 0xD8576B, 0xED7953, 0xFA9E3B, 0xFDC926, 0xF0F921];
+const CIVIDIS : [u32; 10] = [0x00204D, 0x00336F, 0x39486B, 0x575C6D, 0x707173, 0x8A8779, 0xA69D75, 0xC4B56C, 0xE4CF5B, 0xFFEA46];
+const TURBO : [u32; 10] = [0x30123B, 0x4662D7, 0x36AAF9, 0x1AE4B6, 0x72FE5E, 0xC8EF34, 0xFABA39, 0xF66B19, 0xCB2A04, 0x7A0403];
+const COOLWARM : [u32; 8] = [0x3B4CC0, 0x6788EE, 0x9ABBFF, 0xC9D7F0, 0xEDD1C2, 0xF7A889, 0xE26952, 0xB40426];
+
+/// Contributions of linear light red, green and blue to relative luminance.
+const LUMINANCE_WEIGHTS : [f32; 3] = [0.212_672_9, 0.715_152_2, 0.072_175];
+
+/// Types of colour vision deficiency which can be simulated.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ColourVisionDeficiency {
+    Protanopia,
+    Deuteranopia,
+    Tritanopia,
+    Achromatopsia,
+}
+
+impl ColourVisionDeficiency {
+    /// Gets every type of colour vision deficiency which can be simulated.
+    pub fn all() -> [ColourVisionDeficiency; 4] {
+        [
+            ColourVisionDeficiency::Protanopia,
+            ColourVisionDeficiency::Deuteranopia,
+            ColourVisionDeficiency::Tritanopia,
+            ColourVisionDeficiency::Achromatopsia,
+        ]
+    }
+
+    /// Simulates the deficiency on linear light RGB values, using the Machado model at full severity for dichromacy and relative luminance for achromatopsia.
+    pub (in crate) fn simulate_linear(&self, (r, g, b) : (f32, f32, f32)) -> (f32, f32, f32) {
+        let matrix =
+            match self {
+                ColourVisionDeficiency::Protanopia => [
+                    [0.152_286, 1.052_583, -0.204_868],
+                    [0.114_503, 0.786_281, 0.099_216],
+                    [-0.003_882, -0.048_116, 1.051_998],
+                ],
+                ColourVisionDeficiency::Deuteranopia => [
+                    [0.367_322, 0.860_646, -0.227_968],
+                    [0.280_085, 0.672_501, 0.047_413],
+                    [-0.011_820, 0.042_940, 0.968_881],
+                ],
+                ColourVisionDeficiency::Tritanopia => [
+                    [1.255_528, -0.076_749, -0.178_779],
+                    [-0.078_411, 0.930_809, 0.147_602],
+                    [0.004_733, 0.691_367, 0.303_900],
+                ],
+                ColourVisionDeficiency::Achromatopsia => [LUMINANCE_WEIGHTS, LUMINANCE_WEIGHTS, LUMINANCE_WEIGHTS],
+            };
+
+        let row = |[m1, m2, m3] : [f32; 3]| (m1 * r + m2 * g + m3 * b).clamp(0.0, 1.0);
+
+        (row(matrix[0]), row(matrix[1]), row(matrix[2]))
+    }
+}
+
+/// Represents how distinguishable a set of colours is under a colour vision deficiency, or under normal vision when there is no deficiency, as the smallest perceptual distance between any pair of the colours and the indices of that pair.
+#[derive(Copy, Clone, Debug)]
+pub struct Distinguishability {
+    pub deficiency : Option<ColourVisionDeficiency>,
+    pub minimum_distance : f32,
+    pub closest_pair : (usize, usize),
+}
+
+/// Calculates the minimum pairwise distinguishability of the provided colours under normal vision followed by each simulated colour vision deficiency. Distances are in OKLab, where a difference of around 0.02 is just noticeable.
+pub fn distinguishability(colours : &[Colour]) -> Vec<Distinguishability> {
+
+    fn minimum_distance(deficiency : Option<ColourVisionDeficiency>, colours : &[Colour]) -> Distinguishability {
+        let simulated : Vec<Colour> =
+            colours
+            .iter()
+            .map(|colour| match deficiency {
+                Some(deficiency) => colour.simulate(deficiency),
+                None => *colour,
+            })
+            .collect();
+
+        let mut result =
+            Distinguishability {
+                deficiency,
+                minimum_distance : f32::INFINITY,
+                closest_pair : (0, 0),
+            };
+
+        for i in 0..simulated.len() {
+            for j in (i + 1)..simulated.len() {
+                let distance = simulated[i].distance(simulated[j]);
+
+                if distance < result.minimum_distance {
+                    result.minimum_distance = distance;
+                    result.closest_pair = (i, j);
+                }
+            }
+        }
+
+        result
+    }
+
+    let mut results = vec![minimum_distance(None, colours)];
+
+    for deficiency in ColourVisionDeficiency::all() {
+        results.push(minimum_distance(Some(deficiency), colours));
+    }
+
+    results
+}
+
+/// Colour spaces in which colours can be represented and interpolated.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ColourSpace {
+    Srgb,
+    LinearRgb,
+    Hsl,
+    Hsv,
+    Lab,
+    Oklab,
+    Oklch,
+}
+
+/// Converts a value from 0 to 1 to a byte, clamping values outside of that range.
+fn unit_to_byte(value : f32) -> u8 {
+    f32_to_u8((value.clamp(0.0, 1.0) * 255.0).round())
+}
+
+/// Converts an sRGB encoded channel from 0 to 1 to linear light.
+pub (in crate) fn srgb_decode(value : f32) -> f32 {
+    if value <= 0.040_45 {
+        value / 12.92
+    }
+    else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Number of entries in the lookup table from linear light to sRGB encoded bytes.
+const ENCODE_TABLE_SIZE : usize = 4096;
+
+/// Converts an sRGB encoded byte to linear light from 0 to 1, using a lookup table.
+pub (in crate) fn byte_to_linear(byte : u8) -> f32 {
+    static TABLE : OnceLock<[f32; 256]> = OnceLock::new();
+
+    TABLE.get_or_init(|| {
+        let mut table = [0.0; 256];
+        for (byte, entry) in table.iter_mut().enumerate() {
+            *entry = srgb_decode(byte as f32 / 255.0);
+        }
+        table
+    })
+    [usize::from(byte)]
+}
+
+/// Converts linear light from 0 to 1 to an sRGB encoded byte, using a lookup table.
+pub (in crate) fn linear_to_byte(value : f32) -> u8 {
+    static TABLE : OnceLock<Vec<u8>> = OnceLock::new();
+
+    let table = TABLE.get_or_init(|| {
+        (0..ENCODE_TABLE_SIZE)
+        .map(|index| unit_to_byte(srgb_encode(index as f32 / (ENCODE_TABLE_SIZE - 1) as f32)))
+        .collect()
+    });
+
+    table[(value.clamp(0.0, 1.0) * (ENCODE_TABLE_SIZE - 1) as f32).round() as usize]
+}
+
+/// Converts a linear light channel from 0 to 1 to sRGB encoding.
+pub (in crate) fn srgb_encode(value : f32) -> f32 {
+    let value = value.clamp(0.0, 1.0);
+
+    if value <= 0.003_130_8 {
+        value * 12.92
+    }
+    else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Calculates the RGB values from 0 to 1 for a hue in degrees and chroma, before the lightness offset is added.
+fn hue_to_rgb(hue : f32, chroma : f32) -> (f32, f32, f32) {
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+    match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    }
+}
+
+/// Calculates the hue in degrees of RGB values from 0 to 1, which is 0 for greys.
+fn rgb_to_hue(r : f32, g : f32, b : f32) -> f32 {
+    let max = r.max(g).max(b);
+    let chroma = max - r.min(g).min(b);
+
+    if chroma <= 0.0 {
+        0.0
+    }
+    else if max == r {
+        (60.0 * ((g - b) / chroma)).rem_euclid(360.0)
+    }
+    else if max == g {
+        60.0 * ((b - r) / chroma + 2.0)
+    }
+    else {
+        60.0 * ((r - g) / chroma + 4.0)
+    }
+}
+
+/// Interpolates two hues in degrees the shorter way around the colour wheel, taking the hue of the other colour when one has no saturation.
+fn lerp_hue(start : f32, start_saturation : f32, finish : f32, finish_saturation : f32, t : f32) -> f32 {
+    let (start, finish) =
+        if start_saturation <= f32::EPSILON { (finish, finish) }
+        else if finish_saturation <= f32::EPSILON { (start, start) }
+        else { (start, finish) };
+
+    let difference = (finish - start + 540.0).rem_euclid(360.0) - 180.0;
+
+    (start + t * difference).rem_euclid(360.0)
 }
 
 /// Includes all colours from the CSS standard.
 pub mod css_colours {
     use crate::colours::Colour;
 
-    pub static AIR_FORCE_BLUE_RAF : Colour = Colour { red : 93, green : 138, blue : 168 }; 
-    pub static AIR_FORCE_BLUE_USAF : Colour = Colour { red : 0, green : 48, blue : 143 }; 
-    pub static AIR_SUPERIORITY_BLUE : Colour = Colour { red : 114, green : 160, blue : 193 }; 
-    pub static ALABAMA_CRIMSON : Colour = Colour { red : 163, green : 38, blue : 56 }; 
-    pub static ALICE_BLUE : Colour = Colour { red : 240, green : 248, blue : 255 }; 
-    pub static ALIZARIN_CRIMSON : Colour = Colour { red : 227, green : 38, blue : 54 }; 
-    pub static ALLOY_ORANGE : Colour = Colour { red : 196, green : 98, blue : 16 }; 
-    pub static ALMOND : Colour = Colour { red : 239, green : 222, blue : 205 }; 
-    pub static AMARANTH : Colour = Colour { red : 229, green : 43, blue : 80 }; 
-    pub static AMBER : Colour = Colour { red : 255, green : 191, blue : 0 }; 
-    pub static AMBER_SAE_ECE : Colour = Colour { red : 255, green : 126, blue : 0 }; 
-    pub static AMERICAN_ROSE : Colour = Colour { red : 255, green : 3, blue : 62 }; 
-    pub static AMETHYST : Colour = Colour { red : 153, green : 102, blue : 204 }; 
-    pub static ANDROID_GREEN : Colour = Colour { red : 164, green : 198, blue : 57 }; 
-    pub static ANTI_FLASH_WHITE : Colour = Colour { red : 242, green : 243, blue : 244 }; 
-    pub static ANTIQUE_BRASS : Colour = Colour { red : 205, green : 149, blue : 117 }; 
-    pub static ANTIQUE_FUCHSIA : Colour = Colour { red : 145, green : 92, blue : 131 }; 
-    pub static ANTIQUE_RUBY : Colour = Colour { red : 132, green : 27, blue : 45 }; 
-    pub static ANTIQUE_WHITE : Colour = Colour { red : 250, green : 235, blue : 215 }; 
-    pub static AO_ENGLISH : Colour = Colour { red : 0, green : 128, blue : 0 }; 
-    pub static APPLE_GREEN : Colour = Colour { red : 141, green : 182, blue : 0 }; 
-    pub static APRICOT : Colour = Colour { red : 251, green : 206, blue : 177 }; 
-    pub static AQUA : Colour = Colour { red : 0, green : 255, blue : 255 }; 
-    pub static AQUAMARINE : Colour = Colour { red : 127, green : 255, blue : 212 }; 
-    pub static ARMY_GREEN : Colour = Colour { red : 75, green : 83, blue : 32 }; 
-    pub static ARSENIC : Colour = Colour { red : 59, green : 68, blue : 75 }; 
-    pub static ARYLIDE_YELLOW : Colour = Colour { red : 233, green : 214, blue : 107 }; 
-    pub static ASH_GREY : Colour = Colour { red : 178, green : 190, blue : 181 }; 
-    pub static ASPARAGUS : Colour = Colour { red : 135, green : 169, blue : 107 }; 
-    pub static ATOMIC_TANGERINE : Colour = Colour { red : 255, green : 153, blue : 102 }; 
-    pub static AUBURN : Colour = Colour { red : 165, green : 42, blue : 42 }; 
-    pub static AUREOLIN : Colour = Colour { red : 253, green : 238, blue : 0 }; 
-    pub static AUROMETALSAURUS : Colour = Colour { red : 110, green : 127, blue : 128 }; 
-    pub static AVOCADO : Colour = Colour { red : 86, green : 130, blue : 3 }; 
-    pub static AZURE : Colour = Colour { red : 0, green : 127, blue : 255 }; 
-    pub static AZURE_MIST_WEB : Colour = Colour { red : 240, green : 255, blue : 255 }; 
-    pub static BABY_BLUE : Colour = Colour { red : 137, green : 207, blue : 240 }; 
-    pub static BABY_BLUE_EYES : Colour = Colour { red : 161, green : 202, blue : 241 }; 
-    pub static BABY_PINK : Colour = Colour { red : 244, green : 194, blue : 194 }; 
-    pub static BALL_BLUE : Colour = Colour { red : 33, green : 171, blue : 205 }; 
-    pub static BANANA_MANIA : Colour = Colour { red : 250, green : 231, blue : 181 }; 
-    pub static BANANA_YELLOW : Colour = Colour { red : 255, green : 225, blue : 53 }; 
-    pub static BARN_RED : Colour = Colour { red : 124, green : 10, blue : 2 }; 
-    pub static BATTLESHIP_GREY : Colour = Colour { red : 132, green : 132, blue : 130 }; 
-    pub static BAZAAR : Colour = Colour { red : 152, green : 119, blue : 123 }; 
-    pub static BEAU_BLUE : Colour = Colour { red : 188, green : 212, blue : 230 }; 
-    pub static BEAVER : Colour = Colour { red : 159, green : 129, blue : 112 }; 
-    pub static BEIGE : Colour = Colour { red : 245, green : 245, blue : 220 }; 
-    pub static BIG_DIP_O_RUBY : Colour = Colour { red : 156, green : 37, blue : 66 }; 
-    pub static BISQUE : Colour = Colour { red : 255, green : 228, blue : 196 }; 
-    pub static BISTRE : Colour = Colour { red : 61, green : 43, blue : 31 }; 
-    pub static BITTERSWEET : Colour = Colour { red : 254, green : 111, blue : 94 }; 
-    pub static BITTERSWEET_SHIMMER : Colour = Colour { red : 191, green : 79, blue : 81 }; 
-    pub static BLACK : Colour = Colour { red : 0, green : 0, blue : 0 }; 
-    pub static BLACK_BEAN : Colour = Colour { red : 61, green : 12, blue : 2 }; 
-    pub static BLACK_LEATHER_JACKET : Colour = Colour { red : 37, green : 53, blue : 41 }; 
-    pub static BLACK_OLIVE : Colour = Colour { red : 59, green : 60, blue : 54 }; 
-    pub static BLANCHED_ALMOND : Colour = Colour { red : 255, green : 235, blue : 205 }; 
-    pub static BLAST_OFF_BRONZE : Colour = Colour { red : 165, green : 113, blue : 100 }; 
-    pub static BLEU_DE_FRANCE : Colour = Colour { red : 49, green : 140, blue : 231 }; 
-    pub static BLIZZARD_BLUE : Colour = Colour { red : 172, green : 229, blue : 238 }; 
-    pub static BLOND : Colour = Colour { red : 250, green : 240, blue : 190 }; 
-    pub static BLUE : Colour = Colour { red : 0, green : 0, blue : 255 }; 
-    pub static BLUE_BELL : Colour = Colour { red : 162, green : 162, blue : 208 }; 
-    pub static BLUE_CRAYOLA : Colour = Colour { red : 31, green : 117, blue : 254 }; 
-    pub static BLUE_GRAY : Colour = Colour { red : 102, green : 153, blue : 204 }; 
-    pub static BLUE_GREEN : Colour = Colour { red : 13, green : 152, blue : 186 }; 
-    pub static BLUE_MUNSELL : Colour = Colour { red : 0, green : 147, blue : 175 }; 
-    pub static BLUE_NCS : Colour = Colour { red : 0, green : 135, blue : 189 }; 
-    pub static BLUE_PIGMENT : Colour = Colour { red : 51, green : 51, blue : 153 }; 
-    pub static BLUE_RYB : Colour = Colour { red : 2, green : 71, blue : 254 }; 
-    pub static BLUE_SAPPHIRE : Colour = Colour { red : 18, green : 97, blue : 128 }; 
-    pub static BLUE_VIOLET : Colour = Colour { red : 138, green : 43, blue : 226 }; 
-    pub static BLUSH : Colour = Colour { red : 222, green : 93, blue : 131 }; 
-    pub static BOLE : Colour = Colour { red : 121, green : 68, blue : 59 }; 
-    pub static BONDI_BLUE : Colour = Colour { red : 0, green : 149, blue : 182 }; 
-    pub static BONE : Colour = Colour { red : 227, green : 218, blue : 201 }; 
-    pub static BOSTON_UNIVERSITY_RED : Colour = Colour { red : 204, green : 0, blue : 0 }; 
-    pub static BOTTLE_GREEN : Colour = Colour { red : 0, green : 106, blue : 78 }; 
-    pub static BOYSENBERRY : Colour = Colour { red : 135, green : 50, blue : 96 }; 
-    pub static BRANDEIS_BLUE : Colour = Colour { red : 0, green : 112, blue : 255 }; 
-    pub static BRASS : Colour = Colour { red : 181, green : 166, blue : 66 }; 
-    pub static BRICK_RED : Colour = Colour { red : 203, green : 65, blue : 84 }; 
-    pub static BRIGHT_CERULEAN : Colour = Colour { red : 29, green : 172, blue : 214 }; 
-    pub static BRIGHT_GREEN : Colour = Colour { red : 102, green : 255, blue : 0 }; 
-    pub static BRIGHT_LAVENDER : Colour = Colour { red : 191, green : 148, blue : 228 }; 
-    pub static BRIGHT_MAROON : Colour = Colour { red : 195, green : 33, blue : 72 }; 
-    pub static BRIGHT_PINK : Colour = Colour { red : 255, green : 0, blue : 127 }; 
-    pub static BRIGHT_TURQUOISE : Colour = Colour { red : 8, green : 232, blue : 222 }; 
-    pub static BRIGHT_UBE : Colour = Colour { red : 209, green : 159, blue : 232 }; 
-    pub static BRILLIANT_LAVENDER : Colour = Colour { red : 244, green : 187, blue : 255 }; 
-    pub static BRILLIANT_ROSE : Colour = Colour { red : 255, green : 85, blue : 163 }; 
-    pub static BRINK_PINK : Colour = Colour { red : 251, green : 96, blue : 127 }; 
-    pub static BRITISH_RACING_GREEN : Colour = Colour { red : 0, green : 66, blue : 37 }; 
-    pub static BRONZE : Colour = Colour { red : 205, green : 127, blue : 50 }; 
-    pub static BROWN_TRADITIONAL : Colour = Colour { red : 150, green : 75, blue : 0 }; 
-    pub static BROWN_WEB : Colour = Colour { red : 165, green : 42, blue : 42 }; 
-    pub static BUBBLE_GUM : Colour = Colour { red : 255, green : 193, blue : 204 }; 
-    pub static BUBBLES : Colour = Colour { red : 231, green : 254, blue : 255 }; 
-    pub static BUFF : Colour = Colour { red : 240, green : 220, blue : 130 }; 
-    pub static BULGARIAN_ROSE : Colour = Colour { red : 72, green : 6, blue : 7 }; 
-    pub static BURGUNDY : Colour = Colour { red : 128, green : 0, blue : 32 }; 
-    pub static BURLYWOOD : Colour = Colour { red : 222, green : 184, blue : 135 }; 
-    pub static BURNT_ORANGE : Colour = Colour { red : 204, green : 85, blue : 0 }; 
-    pub static BURNT_SIENNA : Colour = Colour { red : 233, green : 116, blue : 81 }; 
-    pub static BURNT_UMBER : Colour = Colour { red : 138, green : 51, blue : 36 }; 
-    pub static BYZANTINE : Colour = Colour { red : 189, green : 51, blue : 164 }; 
-    pub static BYZANTIUM : Colour = Colour { red : 112, green : 41, blue : 99 }; 
-    pub static CADET : Colour = Colour { red : 83, green : 104, blue : 114 }; 
-    pub static CADET_BLUE : Colour = Colour { red : 95, green : 158, blue : 160 }; 
-    pub static CADET_GREY : Colour = Colour { red : 145, green : 163, blue : 176 }; 
-    pub static CADMIUM_GREEN : Colour = Colour { red : 0, green : 107, blue : 60 }; 
-    pub static CADMIUM_ORANGE : Colour = Colour { red : 237, green : 135, blue : 45 }; 
-    pub static CADMIUM_RED : Colour = Colour { red : 227, green : 0, blue : 34 }; 
-    pub static CADMIUM_YELLOW : Colour = Colour { red : 255, green : 246, blue : 0 }; 
-    pub static CAF_AU_LAIT : Colour = Colour { red : 166, green : 123, blue : 91 }; 
-    pub static CAF_NOIR : Colour = Colour { red : 75, green : 54, blue : 33 }; 
-    pub static CAL_POLY_GREEN : Colour = Colour { red : 30, green : 77, blue : 43 }; 
-    pub static CAMBRIDGE_BLUE : Colour = Colour { red : 163, green : 193, blue : 173 }; 
-    pub static CAMEL : Colour = Colour { red : 193, green : 154, blue : 107 }; 
-    pub static CAMEO_PINK : Colour = Colour { red : 239, green : 187, blue : 204 }; 
-    pub static CAMOUFLAGE_GREEN : Colour = Colour { red : 120, green : 134, blue : 107 }; 
-    pub static CANARY_YELLOW : Colour = Colour { red : 255, green : 239, blue : 0 }; 
-    pub static CANDY_APPLE_RED : Colour = Colour { red : 255, green : 8, blue : 0 }; 
-    pub static CANDY_PINK : Colour = Colour { red : 228, green : 113, blue : 122 }; 
-    pub static CAPRI : Colour = Colour { red : 0, green : 191, blue : 255 }; 
-    pub static CAPUT_MORTUUM : Colour = Colour { red : 89, green : 39, blue : 32 }; 
-    pub static CARDINAL : Colour = Colour { red : 196, green : 30, blue : 58 }; 
-    pub static CARIBBEAN_GREEN : Colour = Colour { red : 0, green : 204, blue : 153 }; 
-    pub static CARMINE : Colour = Colour { red : 150, green : 0, blue : 24 }; 
-    pub static CARMINE_MP : Colour = Colour { red : 215, green : 0, blue : 64 }; 
-    pub static CARMINE_PINK : Colour = Colour { red : 235, green : 76, blue : 66 }; 
-    pub static CARMINE_RED : Colour = Colour { red : 255, green : 0, blue : 56 }; 
-    pub static CARNATION_PINK : Colour = Colour { red : 255, green : 166, blue : 201 }; 
-    pub static CARNELIAN : Colour = Colour { red : 179, green : 27, blue : 27 }; 
-    pub static CAROLINA_BLUE : Colour = Colour { red : 153, green : 186, blue : 221 }; 
-    pub static CARROT_ORANGE : Colour = Colour { red : 237, green : 145, blue : 33 }; 
-    pub static CATALINA_BLUE : Colour = Colour { red : 6, green : 42, blue : 120 }; 
-    pub static CEIL : Colour = Colour { red : 146, green : 161, blue : 207 }; 
-    pub static CELADON : Colour = Colour { red : 172, green : 225, blue : 175 }; 
-    pub static CELADON_BLUE : Colour = Colour { red : 0, green : 123, blue : 167 }; 
-    pub static CELADON_GREEN : Colour = Colour { red : 47, green : 132, blue : 124 }; 
-    pub static CELESTE_COLOUR : Colour = Colour { red : 178, green : 255, blue : 255 }; 
-    pub static CELESTIAL_BLUE : Colour = Colour { red : 73, green : 151, blue : 208 }; 
-    pub static CERISE : Colour = Colour { red : 222, green : 49, blue : 99 }; 
-    pub static CERISE_PINK : Colour = Colour { red : 236, green : 59, blue : 131 }; 
-    pub static CERULEAN : Colour = Colour { red : 0, green : 123, blue : 167 }; 
-    pub static CERULEAN_BLUE : Colour = Colour { red : 42, green : 82, blue : 190 }; 
-    pub static CERULEAN_FROST : Colour = Colour { red : 109, green : 155, blue : 195 }; 
-    pub static CG_BLUE : Colour = Colour { red : 0, green : 122, blue : 165 }; 
-    pub static CG_RED : Colour = Colour { red : 224, green : 60, blue : 49 }; 
-    pub static CHAMOISEE : Colour = Colour { red : 160, green : 120, blue : 90 }; 
-    pub static CHAMPAGNE : Colour = Colour { red : 250, green : 214, blue : 165 }; 
-    pub static CHARCOAL : Colour = Colour { red : 54, green : 69, blue : 79 }; 
-    pub static CHARM_PINK : Colour = Colour { red : 230, green : 143, blue : 172 }; 
-    pub static CHARTREUSE_TRADITIONAL : Colour = Colour { red : 223, green : 255, blue : 0 }; 
-    pub static CHARTREUSE_WEB : Colour = Colour { red : 127, green : 255, blue : 0 }; 
-    pub static CHERRY : Colour = Colour { red : 222, green : 49, blue : 99 }; 
-    pub static CHERRY_BLOSSOM_PINK : Colour = Colour { red : 255, green : 183, blue : 197 }; 
-    pub static CHESTNUT : Colour = Colour { red : 205, green : 92, blue : 92 }; 
-    pub static CHINA_PINK : Colour = Colour { red : 222, green : 111, blue : 161 }; 
-    pub static CHINA_ROSE : Colour = Colour { red : 168, green : 81, blue : 110 }; 
-    pub static CHINESE_RED : Colour = Colour { red : 170, green : 56, blue : 30 }; 
-    pub static CHOCOLATE_TRADITIONAL : Colour = Colour { red : 123, green : 63, blue : 0 }; 
-    pub static CHOCOLATE_WEB : Colour = Colour { red : 210, green : 105, blue : 30 }; 
-    pub static CHROME_YELLOW : Colour = Colour { red : 255, green : 167, blue : 0 }; 
-    pub static CINEREOUS : Colour = Colour { red : 152, green : 129, blue : 123 }; 
-    pub static CINNABAR : Colour = Colour { red : 227, green : 66, blue : 52 }; 
-    pub static CINNAMON : Colour = Colour { red : 210, green : 105, blue : 30 }; 
-    pub static CITRINE : Colour = Colour { red : 228, green : 208, blue : 10 }; 
-    pub static CLASSIC_ROSE : Colour = Colour { red : 251, green : 204, blue : 231 }; 
-    pub static COBALT : Colour = Colour { red : 0, green : 71, blue : 171 }; 
-    pub static COCOA_BROWN : Colour = Colour { red : 210, green : 105, blue : 30 }; 
-    pub static COFFEE : Colour = Colour { red : 111, green : 78, blue : 55 }; 
-    pub static COLUMBIA_BLUE : Colour = Colour { red : 155, green : 221, blue : 255 }; 
-    pub static CONGO_PINK : Colour = Colour { red : 248, green : 131, blue : 121 }; 
-    pub static COOL_BLACK : Colour = Colour { red : 0, green : 46, blue : 99 }; 
-    pub static COOL_GREY : Colour = Colour { red : 140, green : 146, blue : 172 }; 
-    pub static COPPER : Colour = Colour { red : 184, green : 115, blue : 51 }; 
-    pub static COPPER_CRAYOLA : Colour = Colour { red : 218, green : 138, blue : 103 }; 
-    pub static COPPER_PENNY : Colour = Colour { red : 173, green : 111, blue : 105 }; 
-    pub static COPPER_RED : Colour = Colour { red : 203, green : 109, blue : 81 }; 
-    pub static COPPER_ROSE : Colour = Colour { red : 153, green : 102, blue : 102 }; 
-    pub static COQUELICOT : Colour = Colour { red : 255, green : 56, blue : 0 }; 
-    pub static CORAL : Colour = Colour { red : 255, green : 127, blue : 80 }; 
-    pub static CORAL_PINK : Colour = Colour { red : 248, green : 131, blue : 121 }; 
-    pub static CORAL_RED : Colour = Colour { red : 255, green : 64, blue : 64 }; 
-    pub static CORDOVAN : Colour = Colour { red : 137, green : 63, blue : 69 }; 
-    pub static CORN : Colour = Colour { red : 251, green : 236, blue : 93 }; 
-    pub static CORNELL_RED : Colour = Colour { red : 179, green : 27, blue : 27 }; 
-    pub static CORNFLOWER_BLUE : Colour = Colour { red : 100, green : 149, blue : 237 }; 
-    pub static CORNSILK : Colour = Colour { red : 255, green : 248, blue : 220 }; 
-    pub static COSMIC_LATTE : Colour = Colour { red : 255, green : 248, blue : 231 }; 
-    pub static COTTON_CANDY : Colour = Colour { red : 255, green : 188, blue : 217 }; 
-    pub static CREAM : Colour = Colour { red : 255, green : 253, blue : 208 }; 
-    pub static CRIMSON : Colour = Colour { red : 220, green : 20, blue : 60 }; 
-    pub static CRIMSON_GLORY : Colour = Colour { red : 190, green : 0, blue : 50 }; 
-    pub static CYAN : Colour = Colour { red : 0, green : 255, blue : 255 }; 
-    pub static CYAN_PROCESS : Colour = Colour { red : 0, green : 183, blue : 235 }; 
-    pub static DAFFODIL : Colour = Colour { red : 255, green : 255, blue : 49 }; 
-    pub static DANDELION : Colour = Colour { red : 240, green : 225, blue : 48 }; 
-    pub static DARK_BLUE : Colour = Colour { red : 0, green : 0, blue : 139 }; 
-    pub static DARK_BROWN : Colour = Colour { red : 101, green : 67, blue : 33 }; 
-    pub static DARK_BYZANTIUM : Colour = Colour { red : 93, green : 57, blue : 84 }; 
-    pub static DARK_CANDY_APPLE_RED : Colour = Colour { red : 164, green : 0, blue : 0 }; 
-    pub static DARK_CERULEAN : Colour = Colour { red : 8, green : 69, blue : 126 }; 
-    pub static DARK_CHESTNUT : Colour = Colour { red : 152, green : 105, blue : 96 }; 
-    pub static DARK_CORAL : Colour = Colour { red : 205, green : 91, blue : 69 }; 
-    pub static DARK_CYAN : Colour = Colour { red : 0, green : 139, blue : 139 }; 
-    pub static DARK_ELECTRIC_BLUE : Colour = Colour { red : 83, green : 104, blue : 120 }; 
-    pub static DARK_GOLDENROD : Colour = Colour { red : 184, green : 134, blue : 11 }; 
-    pub static DARK_GRAY : Colour = Colour { red : 169, green : 169, blue : 169 }; 
-    pub static DARK_GREEN : Colour = Colour { red : 1, green : 50, blue : 32 }; 
-    pub static DARK_IMPERIAL_BLUE : Colour = Colour { red : 0, green : 65, blue : 106 }; 
-    pub static DARK_JUNGLE_GREEN : Colour = Colour { red : 26, green : 36, blue : 33 }; 
-    pub static DARK_KHAKI : Colour = Colour { red : 189, green : 183, blue : 107 }; 
-    pub static DARK_LAVA : Colour = Colour { red : 72, green : 60, blue : 50 }; 
-    pub static DARK_LAVENDER : Colour = Colour { red : 115, green : 79, blue : 150 }; 
-    pub static DARK_MAGENTA : Colour = Colour { red : 139, green : 0, blue : 139 }; 
-    pub static DARK_MIDNIGHT_BLUE : Colour = Colour { red : 0, green : 51, blue : 102 }; 
-    pub static DARK_OLIVE_GREEN : Colour = Colour { red : 85, green : 107, blue : 47 }; 
-    pub static DARK_ORANGE : Colour = Colour { red : 255, green : 140, blue : 0 }; 
-    pub static DARK_ORCHID : Colour = Colour { red : 153, green : 50, blue : 204 }; 
-    pub static DARK_PASTEL_BLUE : Colour = Colour { red : 119, green : 158, blue : 203 }; 
-    pub static DARK_PASTEL_GREEN : Colour = Colour { red : 3, green : 192, blue : 60 }; 
-    pub static DARK_PASTEL_PURPLE : Colour = Colour { red : 150, green : 111, blue : 214 }; 
-    pub static DARK_PASTEL_RED : Colour = Colour { red : 194, green : 59, blue : 34 }; 
-    pub static DARK_PINK : Colour = Colour { red : 231, green : 84, blue : 128 }; 
-    pub static DARK_POWDER_BLUE : Colour = Colour { red : 0, green : 51, blue : 153 }; 
-    pub static DARK_RASPBERRY : Colour = Colour { red : 135, green : 38, blue : 87 }; 
-    pub static DARK_RED : Colour = Colour { red : 139, green : 0, blue : 0 }; 
-    pub static DARK_SALMON : Colour = Colour { red : 233, green : 150, blue : 122 }; 
-    pub static DARK_SCARLET : Colour = Colour { red : 86, green : 3, blue : 25 }; 
-    pub static DARK_SEA_GREEN : Colour = Colour { red : 143, green : 188, blue : 143 }; 
-    pub static DARK_SIENNA : Colour = Colour { red : 60, green : 20, blue : 20 }; 
-    pub static DARK_SLATE_BLUE : Colour = Colour { red : 72, green : 61, blue : 139 }; 
-    pub static DARK_SLATE_GRAY : Colour = Colour { red : 47, green : 79, blue : 79 }; 
-    pub static DARK_SPRING_GREEN : Colour = Colour { red : 23, green : 114, blue : 69 }; 
-    pub static DARK_TAN : Colour = Colour { red : 145, green : 129, blue : 81 }; 
-    pub static DARK_TANGERINE : Colour = Colour { red : 255, green : 168, blue : 18 }; 
-    pub static DARK_TAUPE : Colour = Colour { red : 72, green : 60, blue : 50 }; 
-    pub static DARK_TERRA_COTTA : Colour = Colour { red : 204, green : 78, blue : 92 }; 
-    pub static DARK_TURQUOISE : Colour = Colour { red : 0, green : 206, blue : 209 }; 
-    pub static DARK_VIOLET : Colour = Colour { red : 148, green : 0, blue : 211 }; 
-    pub static DARK_YELLOW : Colour = Colour { red : 155, green : 135, blue : 12 }; 
-    pub static DARTMOUTH_GREEN : Colour = Colour { red : 0, green : 112, blue : 60 }; 
-    pub static DAVY_S_GREY : Colour = Colour { red : 85, green : 85, blue : 85 }; 
-    pub static DEBIAN_RED : Colour = Colour { red : 215, green : 10, blue : 83 }; 
-    pub static DEEP_CARMINE : Colour = Colour { red : 169, green : 32, blue : 62 }; 
-    pub static DEEP_CARMINE_PINK : Colour = Colour { red : 239, green : 48, blue : 56 }; 
-    pub static DEEP_CARROT_ORANGE : Colour = Colour { red : 233, green : 105, blue : 44 }; 
-    pub static DEEP_CERISE : Colour = Colour { red : 218, green : 50, blue : 135 }; 
-    pub static DEEP_CHAMPAGNE : Colour = Colour { red : 250, green : 214, blue : 165 }; 
-    pub static DEEP_CHESTNUT : Colour = Colour { red : 185, green : 78, blue : 72 }; 
-    pub static DEEP_COFFEE : Colour = Colour { red : 112, green : 66, blue : 65 }; 
-    pub static DEEP_FUCHSIA : Colour = Colour { red : 193, green : 84, blue : 193 }; 
-    pub static DEEP_JUNGLE_GREEN : Colour = Colour { red : 0, green : 75, blue : 73 }; 
-    pub static DEEP_LILAC : Colour = Colour { red : 153, green : 85, blue : 187 }; 
-    pub static DEEP_MAGENTA : Colour = Colour { red : 204, green : 0, blue : 204 }; 
-    pub static DEEP_PEACH : Colour = Colour { red : 255, green : 203, blue : 164 }; 
-    pub static DEEP_PINK : Colour = Colour { red : 255, green : 20, blue : 147 }; 
-    pub static DEEP_RUBY : Colour = Colour { red : 132, green : 63, blue : 91 }; 
-    pub static DEEP_SAFFRON : Colour = Colour { red : 255, green : 153, blue : 51 }; 
-    pub static DEEP_SKY_BLUE : Colour = Colour { red : 0, green : 191, blue : 255 }; 
-    pub static DEEP_TUSCAN_RED : Colour = Colour { red : 102, green : 66, blue : 77 }; 
-    pub static DENIM : Colour = Colour { red : 21, green : 96, blue : 189 }; 
-    pub static DESERT : Colour = Colour { red : 193, green : 154, blue : 107 }; 
-    pub static DESERT_SAND : Colour = Colour { red : 237, green : 201, blue : 175 }; 
-    pub static DIM_GRAY : Colour = Colour { red : 105, green : 105, blue : 105 }; 
-    pub static DODGER_BLUE : Colour = Colour { red : 30, green : 144, blue : 255 }; 
-    pub static DOGWOOD_ROSE : Colour = Colour { red : 215, green : 24, blue : 104 }; 
-    pub static DOLLAR_BILL : Colour = Colour { red : 133, green : 187, blue : 101 }; 
-    pub static DRAB : Colour = Colour { red : 150, green : 113, blue : 23 }; 
-    pub static DUKE_BLUE : Colour = Colour { red : 0, green : 0, blue : 156 }; 
-    pub static EARTH_YELLOW : Colour = Colour { red : 225, green : 169, blue : 95 }; 
-    pub static EBONY : Colour = Colour { red : 85, green : 93, blue : 80 }; 
-    pub static ECRU : Colour = Colour { red : 194, green : 178, blue : 128 }; 
-    pub static EGGPLANT : Colour = Colour { red : 97, green : 64, blue : 81 }; 
-    pub static EGGSHELL : Colour = Colour { red : 240, green : 234, blue : 214 }; 
-    pub static EGYPTIAN_BLUE : Colour = Colour { red : 16, green : 52, blue : 166 }; 
-    pub static ELECTRIC_BLUE : Colour = Colour { red : 125, green : 249, blue : 255 }; 
-    pub static ELECTRIC_CRIMSON : Colour = Colour { red : 255, green : 0, blue : 63 }; 
-    pub static ELECTRIC_CYAN : Colour = Colour { red : 0, green : 255, blue : 255 }; 
-    pub static ELECTRIC_GREEN : Colour = Colour { red : 0, green : 255, blue : 0 }; 
-    pub static ELECTRIC_INDIGO : Colour = Colour { red : 111, green : 0, blue : 255 }; 
-    pub static ELECTRIC_LAVENDER : Colour = Colour { red : 244, green : 187, blue : 255 }; 
-    pub static ELECTRIC_LIME : Colour = Colour { red : 204, green : 255, blue : 0 }; 
-    pub static ELECTRIC_PURPLE : Colour = Colour { red : 191, green : 0, blue : 255 }; 
-    pub static ELECTRIC_ULTRAMARINE : Colour = Colour { red : 63, green : 0, blue : 255 }; 
-    pub static ELECTRIC_VIOLET : Colour = Colour { red : 143, green : 0, blue : 255 }; 
-    pub static ELECTRIC_YELLOW : Colour = Colour { red : 255, green : 255, blue : 0 }; 
-    pub static EMERALD : Colour = Colour { red : 80, green : 200, blue : 120 }; 
-    pub static ENGLISH_LAVENDER : Colour = Colour { red : 180, green : 131, blue : 149 }; 
-    pub static ETON_BLUE : Colour = Colour { red : 150, green : 200, blue : 162 }; 
-    pub static FALLOW : Colour = Colour { red : 193, green : 154, blue : 107 }; 
-    pub static FALU_RED : Colour = Colour { red : 128, green : 24, blue : 24 }; 
-    pub static FANDANGO : Colour = Colour { red : 181, green : 51, blue : 137 }; 
-    pub static FASHION_FUCHSIA : Colour = Colour { red : 244, green : 0, blue : 161 }; 
-    pub static FAWN : Colour = Colour { red : 229, green : 170, blue : 112 }; 
-    pub static FELDGRAU : Colour = Colour { red : 77, green : 93, blue : 83 }; 
-    pub static FERN_GREEN : Colour = Colour { red : 79, green : 121, blue : 66 }; 
-    pub static FERRARI_RED : Colour = Colour { red : 255, green : 40, blue : 0 }; 
-    pub static FIELD_DRAB : Colour = Colour { red : 108, green : 84, blue : 30 }; 
-    pub static FIRE_ENGINE_RED : Colour = Colour { red : 206, green : 32, blue : 41 }; 
-    pub static FIREBRICK : Colour = Colour { red : 178, green : 34, blue : 34 }; 
-    pub static FLAME : Colour = Colour { red : 226, green : 88, blue : 34 }; 
-    pub static FLAMINGO_PINK : Colour = Colour { red : 252, green : 142, blue : 172 }; 
-    pub static FLAVESCENT : Colour = Colour { red : 247, green : 233, blue : 142 }; 
-    pub static FLAX : Colour = Colour { red : 238, green : 220, blue : 130 }; 
-    pub static FLORAL_WHITE : Colour = Colour { red : 255, green : 250, blue : 240 }; 
-    pub static FLUORESCENT_ORANGE : Colour = Colour { red : 255, green : 191, blue : 0 }; 
-    pub static FLUORESCENT_PINK : Colour = Colour { red : 255, green : 20, blue : 147 }; 
-    pub static FLUORESCENT_YELLOW : Colour = Colour { red : 204, green : 255, blue : 0 }; 
-    pub static FOLLY : Colour = Colour { red : 255, green : 0, blue : 79 }; 
-    pub static FOREST_GREEN_TRADITIONAL : Colour = Colour { red : 1, green : 68, blue : 33 }; 
-    pub static FOREST_GREEN_WEB : Colour = Colour { red : 34, green : 139, blue : 34 }; 
-    pub static FRENCH_BEIGE : Colour = Colour { red : 166, green : 123, blue : 91 }; 
-    pub static FRENCH_BLUE : Colour = Colour { red : 0, green : 114, blue : 187 }; 
-    pub static FRENCH_LILAC : Colour = Colour { red : 134, green : 96, blue : 142 }; 
-    pub static FRENCH_LIME : Colour = Colour { red : 204, green : 255, blue : 0 }; 
-    pub static FRENCH_RASPBERRY : Colour = Colour { red : 199, green : 44, blue : 72 }; 
-    pub static FRENCH_ROSE : Colour = Colour { red : 246, green : 74, blue : 138 }; 
-    pub static FUCHSIA : Colour = Colour { red : 255, green : 0, blue : 255 }; 
-    pub static FUCHSIA_CRAYOLA : Colour = Colour { red : 193, green : 84, blue : 193 }; 
-    pub static FUCHSIA_PINK : Colour = Colour { red : 255, green : 119, blue : 255 }; 
-    pub static FUCHSIA_ROSE : Colour = Colour { red : 199, green : 67, blue : 117 }; 
-    pub static FULVOUS : Colour = Colour { red : 228, green : 132, blue : 0 }; 
-    pub static FUZZY_WUZZY : Colour = Colour { red : 204, green : 102, blue : 102 }; 
-    pub static GAINSBORO : Colour = Colour { red : 220, green : 220, blue : 220 }; 
-    pub static GAMBOGE : Colour = Colour { red : 228, green : 155, blue : 15 }; 
-    pub static GHOST_WHITE : Colour = Colour { red : 248, green : 248, blue : 255 }; 
-    pub static GINGER : Colour = Colour { red : 176, green : 101, blue : 0 }; 
-    pub static GLAUCOUS : Colour = Colour { red : 96, green : 130, blue : 182 }; 
-    pub static GLITTER : Colour = Colour { red : 230, green : 232, blue : 250 }; 
-    pub static GOLD_METALLIC : Colour = Colour { red : 212, green : 175, blue : 55 }; 
-    pub static GOLD_WEB_GOLDEN : Colour = Colour { red : 255, green : 215, blue : 0 }; 
-    pub static GOLDEN_BROWN : Colour = Colour { red : 153, green : 101, blue : 21 }; 
-    pub static GOLDEN_POPPY : Colour = Colour { red : 252, green : 194, blue : 0 }; 
-    pub static GOLDEN_YELLOW : Colour = Colour { red : 255, green : 223, blue : 0 }; 
-    pub static GOLDENROD : Colour = Colour { red : 218, green : 165, blue : 32 }; 
-    pub static GRANNY_SMITH_APPLE : Colour = Colour { red : 168, green : 228, blue : 160 }; 
-    pub static GRAY : Colour = Colour { red : 128, green : 128, blue : 128 }; 
-    pub static GRAY_ASPARAGUS : Colour = Colour { red : 70, green : 89, blue : 69 }; 
-    pub static GRAY_HTML_CSS_GRAY : Colour = Colour { red : 128, green : 128, blue : 128 }; 
-    pub static GRAY_X11_GRAY : Colour = Colour { red : 190, green : 190, blue : 190 }; 
-    pub static GREEN_COLOR_WHEEL_X11_GREEN : Colour = Colour { red : 0, green : 255, blue : 0 }; 
-    pub static GREEN_CRAYOLA : Colour = Colour { red : 28, green : 172, blue : 120 }; 
-    pub static GREEN_HTML_CSS_GREEN : Colour = Colour { red : 0, green : 128, blue : 0 }; 
-    pub static GREEN_MUNSELL : Colour = Colour { red : 0, green : 168, blue : 119 }; 
-    pub static GREEN_NCS : Colour = Colour { red : 0, green : 159, blue : 107 }; 
-    pub static GREEN_PIGMENT : Colour = Colour { red : 0, green : 165, blue : 80 }; 
-    pub static GREEN_RYB : Colour = Colour { red : 102, green : 176, blue : 50 }; 
-    pub static GREEN_YELLOW : Colour = Colour { red : 173, green : 255, blue : 47 }; 
-    pub static GRULLO : Colour = Colour { red : 169, green : 154, blue : 134 }; 
-    pub static GUPPIE_GREEN : Colour = Colour { red : 0, green : 255, blue : 127 }; 
-    pub static HALAY_BE : Colour = Colour { red : 102, green : 56, blue : 84 }; 
-    pub static HAN_BLUE : Colour = Colour { red : 68, green : 108, blue : 207 }; 
-    pub static HAN_PURPLE : Colour = Colour { red : 82, green : 24, blue : 250 }; 
-    pub static HANSA_YELLOW : Colour = Colour { red : 233, green : 214, blue : 107 }; 
-    pub static HARLEQUIN : Colour = Colour { red : 63, green : 255, blue : 0 }; 
-    pub static HARVARD_CRIMSON : Colour = Colour { red : 201, green : 0, blue : 22 }; 
-    pub static HARVEST_GOLD : Colour = Colour { red : 218, green : 145, blue : 0 }; 
-    pub static HEART_GOLD : Colour = Colour { red : 128, green : 128, blue : 0 }; 
-    pub static HELIOTROPE : Colour = Colour { red : 223, green : 115, blue : 255 }; 
-    pub static HOLLYWOOD_CERISE : Colour = Colour { red : 244, green : 0, blue : 161 }; 
-    pub static HONEYDEW : Colour = Colour { red : 240, green : 255, blue : 240 }; 
-    pub static HONOLULU_BLUE : Colour = Colour { red : 0, green : 127, blue : 191 }; 
-    pub static HOOKER_S_GREEN : Colour = Colour { red : 73, green : 121, blue : 107 }; 
-    pub static HOT_MAGENTA : Colour = Colour { red : 255, green : 29, blue : 206 }; 
-    pub static HOT_PINK : Colour = Colour { red : 255, green : 105, blue : 180 }; 
-    pub static HUNTER_GREEN : Colour = Colour { red : 53, green : 94, blue : 59 }; 
-    pub static ICEBERG : Colour = Colour { red : 113, green : 166, blue : 210 }; 
-    pub static ICTERINE : Colour = Colour { red : 252, green : 247, blue : 94 }; 
-    pub static IMPERIAL_BLUE : Colour = Colour { red : 0, green : 35, blue : 149 }; 
-    pub static INCHWORM : Colour = Colour { red : 178, green : 236, blue : 93 }; 
-    pub static INDIA_GREEN : Colour = Colour { red : 19, green : 136, blue : 8 }; 
-    pub static INDIAN_RED : Colour = Colour { red : 205, green : 92, blue : 92 }; 
-    pub static INDIAN_YELLOW : Colour = Colour { red : 227, green : 168, blue : 87 }; 
-    pub static INDIGO : Colour = Colour { red : 111, green : 0, blue : 255 }; 
-    pub static INDIGO_DYE : Colour = Colour { red : 0, green : 65, blue : 106 }; 
-    pub static INDIGO_WEB : Colour = Colour { red : 75, green : 0, blue : 130 }; 
-    pub static INTERNATIONAL_KLEIN_BLUE : Colour = Colour { red : 0, green : 47, blue : 167 }; 
-    pub static INTERNATIONAL_ORANGE_AEROSPACE : Colour = Colour { red : 255, green : 79, blue : 0 }; 
-    pub static INTERNATIONAL_ORANGE_ENGINEERING : Colour = Colour { red : 186, green : 22, blue : 12 }; 
-    pub static INTERNATIONAL_ORANGE_GOLDEN_GATE_BRIDGE : Colour = Colour { red : 192, green : 54, blue : 44 };
-    pub static IRIS : Colour = Colour { red : 90, green : 79, blue : 207 }; 
-    pub static ISABELLINE : Colour = Colour { red : 244, green : 240, blue : 236 }; 
-    pub static ISLAMIC_GREEN : Colour = Colour { red : 0, green : 144, blue : 0 }; 
-    pub static IVORY : Colour = Colour { red : 255, green : 255, blue : 240 }; 
-    pub static JADE : Colour = Colour { red : 0, green : 168, blue : 107 }; 
-    pub static JASMINE : Colour = Colour { red : 248, green : 222, blue : 126 }; 
-    pub static JASPER : Colour = Colour { red : 215, green : 59, blue : 62 }; 
-    pub static JAZZBERRY_JAM : Colour = Colour { red : 165, green : 11, blue : 94 }; 
-    pub static JET : Colour = Colour { red : 52, green : 52, blue : 52 }; 
-    pub static JONQUIL : Colour = Colour { red : 250, green : 218, blue : 94 }; 
-    pub static JUNE_BUD : Colour = Colour { red : 189, green : 218, blue : 87 }; 
-    pub static JUNGLE_GREEN : Colour = Colour { red : 41, green : 171, blue : 135 }; 
-    pub static KELLY_GREEN : Colour = Colour { red : 76, green : 187, blue : 23 }; 
-    pub static KENYAN_COPPER : Colour = Colour { red : 124, green : 28, blue : 5 }; 
-    pub static KHAKI_HTML_CSS_KHAKI : Colour = Colour { red : 195, green : 176, blue : 145 }; 
-    pub static KHAKI_X11_LIGHT_KHAKI : Colour = Colour { red : 240, green : 230, blue : 140 }; 
-    pub static KU_CRIMSON : Colour = Colour { red : 232, green : 0, blue : 13 }; 
-    pub static LA_SALLE_GREEN : Colour = Colour { red : 8, green : 120, blue : 48 }; 
-    pub static LANGUID_LAVENDER : Colour = Colour { red : 214, green : 202, blue : 221 }; 
-    pub static LAPIS_LAZULI : Colour = Colour { red : 38, green : 97, blue : 156 }; 
-    pub static LASER_LEMON : Colour = Colour { red : 254, green : 254, blue : 34 }; 
-    pub static LAUREL_GREEN : Colour = Colour { red : 169, green : 186, blue : 157 }; 
-    pub static LAVA : Colour = Colour { red : 207, green : 16, blue : 32 }; 
-    pub static LAVENDER_BLUE : Colour = Colour { red : 204, green : 204, blue : 255 }; 
-    pub static LAVENDER_BLUSH : Colour = Colour { red : 255, green : 240, blue : 245 }; 
-    pub static LAVENDER_FLORAL : Colour = Colour { red : 181, green : 126, blue : 220 }; 
-    pub static LAVENDER_GRAY : Colour = Colour { red : 196, green : 195, blue : 208 }; 
-    pub static LAVENDER_INDIGO : Colour = Colour { red : 148, green : 87, blue : 235 }; 
-    pub static LAVENDER_MAGENTA : Colour = Colour { red : 238, green : 130, blue : 238 }; 
-    pub static LAVENDER_MIST : Colour = Colour { red : 230, green : 230, blue : 250 }; 
-    pub static LAVENDER_PINK : Colour = Colour { red : 251, green : 174, blue : 210 }; 
-    pub static LAVENDER_PURPLE : Colour = Colour { red : 150, green : 123, blue : 182 }; 
-    pub static LAVENDER_ROSE : Colour = Colour { red : 251, green : 160, blue : 227 }; 
-    pub static LAVENDER_WEB : Colour = Colour { red : 230, green : 230, blue : 250 }; 
-    pub static LAWN_GREEN : Colour = Colour { red : 124, green : 252, blue : 0 }; 
-    pub static LEMON : Colour = Colour { red : 255, green : 247, blue : 0 }; 
-    pub static LEMON_CHIFFON : Colour = Colour { red : 255, green : 250, blue : 205 }; 
-    pub static LEMON_LIME : Colour = Colour { red : 227, green : 255, blue : 0 }; 
-    pub static LICORICE : Colour = Colour { red : 26, green : 17, blue : 16 }; 
-    pub static LIGHT_APRICOT : Colour = Colour { red : 253, green : 213, blue : 177 }; 
-    pub static LIGHT_BLUE : Colour = Colour { red : 173, green : 216, blue : 230 }; 
-    pub static LIGHT_BROWN : Colour = Colour { red : 181, green : 101, blue : 29 }; 
-    pub static LIGHT_CARMINE_PINK : Colour = Colour { red : 230, green : 103, blue : 113 }; 
-    pub static LIGHT_CORAL : Colour = Colour { red : 240, green : 128, blue : 128 }; 
-    pub static LIGHT_CORNFLOWER_BLUE : Colour = Colour { red : 147, green : 204, blue : 234 }; 
-    pub static LIGHT_CRIMSON : Colour = Colour { red : 245, green : 105, blue : 145 }; 
-    pub static LIGHT_CYAN : Colour = Colour { red : 224, green : 255, blue : 255 }; 
-    pub static LIGHT_FUCHSIA_PINK : Colour = Colour { red : 249, green : 132, blue : 239 }; 
-    pub static LIGHT_GOLDENROD_YELLOW : Colour = Colour { red : 250, green : 250, blue : 210 }; 
-    pub static LIGHT_GRAY : Colour = Colour { red : 211, green : 211, blue : 211 }; 
-    pub static LIGHT_GREEN : Colour = Colour { red : 144, green : 238, blue : 144 }; 
-    pub static LIGHT_KHAKI : Colour = Colour { red : 240, green : 230, blue : 140 }; 
-    pub static LIGHT_PASTEL_PURPLE : Colour = Colour { red : 177, green : 156, blue : 217 }; 
-    pub static LIGHT_PINK : Colour = Colour { red : 255, green : 182, blue : 193 }; 
-    pub static LIGHT_RED_OCHRE : Colour = Colour { red : 233, green : 116, blue : 81 }; 
-    pub static LIGHT_SALMON : Colour = Colour { red : 255, green : 160, blue : 122 }; 
-    pub static LIGHT_SALMON_PINK : Colour = Colour { red : 255, green : 153, blue : 153 }; 
-    pub static LIGHT_SEA_GREEN : Colour = Colour { red : 32, green : 178, blue : 170 }; 
-    pub static LIGHT_SKY_BLUE : Colour = Colour { red : 135, green : 206, blue : 250 }; 
-    pub static LIGHT_SLATE_GRAY : Colour = Colour { red : 119, green : 136, blue : 153 }; 
-    pub static LIGHT_TAUPE : Colour = Colour { red : 179, green : 139, blue : 109 }; 
-    pub static LIGHT_THULIAN_PINK : Colour = Colour { red : 230, green : 143, blue : 172 }; 
-    pub static LIGHT_YELLOW : Colour = Colour { red : 255, green : 255, blue : 224 }; 
-    pub static LILAC : Colour = Colour { red : 200, green : 162, blue : 200 }; 
-    pub static LIME_COLOR_WHEEL : Colour = Colour { red : 191, green : 255, blue : 0 }; 
-    pub static LIME_GREEN : Colour = Colour { red : 50, green : 205, blue : 50 }; 
-    pub static LIME_WEB_X11_GREEN : Colour = Colour { red : 0, green : 255, blue : 0 }; 
-    pub static LIMERICK : Colour = Colour { red : 157, green : 194, blue : 9 }; 
-    pub static LINCOLN_GREEN : Colour = Colour { red : 25, green : 89, blue : 5 }; 
-    pub static LINEN : Colour = Colour { red : 250, green : 240, blue : 230 }; 
-    pub static LION : Colour = Colour { red : 193, green : 154, blue : 107 }; 
-    pub static LITTLE_BOY_BLUE : Colour = Colour { red : 108, green : 160, blue : 220 }; 
-    pub static LIVER : Colour = Colour { red : 83, green : 75, blue : 79 }; 
-    pub static LUST : Colour = Colour { red : 230, green : 32, blue : 32 }; 
-    pub static MAGENTA : Colour = Colour { red : 255, green : 0, blue : 255 }; 
-    pub static MAGENTA_DYE : Colour = Colour { red : 202, green : 31, blue : 123 }; 
-    pub static MAGENTA_PROCESS : Colour = Colour { red : 255, green : 0, blue : 144 }; 
-    pub static MAGIC_MINT : Colour = Colour { red : 170, green : 240, blue : 209 }; 
-    pub static MAGNOLIA : Colour = Colour { red : 248, green : 244, blue : 255 }; 
-    pub static MAHOGANY : Colour = Colour { red : 192, green : 64, blue : 0 }; 
-    pub static MAIZE : Colour = Colour { red : 251, green : 236, blue : 93 }; 
-    pub static MAJORELLE_BLUE : Colour = Colour { red : 96, green : 80, blue : 220 }; 
-    pub static MALACHITE : Colour = Colour { red : 11, green : 218, blue : 81 }; 
-    pub static MANATEE : Colour = Colour { red : 151, green : 154, blue : 170 }; 
-    pub static MANGO_TANGO : Colour = Colour { red : 255, green : 130, blue : 67 }; 
-    pub static MANTIS : Colour = Colour { red : 116, green : 195, blue : 101 }; 
-    pub static MARDI_GRAS : Colour = Colour { red : 136, green : 0, blue : 133 }; 
-    pub static MAROON_CRAYOLA : Colour = Colour { red : 195, green : 33, blue : 72 }; 
-    pub static MAROON_HTML_CSS : Colour = Colour { red : 128, green : 0, blue : 0 }; 
-    pub static MAROON_X11 : Colour = Colour { red : 176, green : 48, blue : 96 }; 
-    pub static MAUVE : Colour = Colour { red : 224, green : 176, blue : 255 }; 
-    pub static MAUVE_TAUPE : Colour = Colour { red : 145, green : 95, blue : 109 }; 
-    pub static MAUVELOUS : Colour = Colour { red : 239, green : 152, blue : 170 }; 
-    pub static MAYA_BLUE : Colour = Colour { red : 115, green : 194, blue : 251 }; 
-    pub static MEAT_BROWN : Colour = Colour { red : 229, green : 183, blue : 59 }; 
-    pub static MEDIUM_AQUAMARINE : Colour = Colour { red : 102, green : 221, blue : 170 }; 
-    pub static MEDIUM_BLUE : Colour = Colour { red : 0, green : 0, blue : 205 }; 
-    pub static MEDIUM_CANDY_APPLE_RED : Colour = Colour { red : 226, green : 6, blue : 44 }; 
-    pub static MEDIUM_CARMINE : Colour = Colour { red : 175, green : 64, blue : 53 }; 
-    pub static MEDIUM_CHAMPAGNE : Colour = Colour { red : 243, green : 229, blue : 171 }; 
-    pub static MEDIUM_ELECTRIC_BLUE : Colour = Colour { red : 3, green : 80, blue : 150 }; 
-    pub static MEDIUM_JUNGLE_GREEN : Colour = Colour { red : 28, green : 53, blue : 45 }; 
-    pub static MEDIUM_LAVENDER_MAGENTA : Colour = Colour { red : 221, green : 160, blue : 221 }; 
-    pub static MEDIUM_ORCHID : Colour = Colour { red : 186, green : 85, blue : 211 }; 
-    pub static MEDIUM_PERSIAN_BLUE : Colour = Colour { red : 0, green : 103, blue : 165 }; 
-    pub static MEDIUM_PURPLE : Colour = Colour { red : 147, green : 112, blue : 219 }; 
-    pub static MEDIUM_RED_VIOLET : Colour = Colour { red : 187, green : 51, blue : 133 }; 
-    pub static MEDIUM_RUBY : Colour = Colour { red : 170, green : 64, blue : 105 }; 
-    pub static MEDIUM_SEA_GREEN : Colour = Colour { red : 60, green : 179, blue : 113 }; 
-    pub static MEDIUM_SLATE_BLUE : Colour = Colour { red : 123, green : 104, blue : 238 }; 
-    pub static MEDIUM_SPRING_BUD : Colour = Colour { red : 201, green : 220, blue : 135 }; 
-    pub static MEDIUM_SPRING_GREEN : Colour = Colour { red : 0, green : 250, blue : 154 }; 
-    pub static MEDIUM_TAUPE : Colour = Colour { red : 103, green : 76, blue : 71 }; 
-    pub static MEDIUM_TURQUOISE : Colour = Colour { red : 72, green : 209, blue : 204 }; 
-    pub static MEDIUM_TUSCAN_RED : Colour = Colour { red : 121, green : 68, blue : 59 }; 
-    pub static MEDIUM_VERMILION : Colour = Colour { red : 217, green : 96, blue : 59 }; 
-    pub static MEDIUM_VIOLET_RED : Colour = Colour { red : 199, green : 21, blue : 133 }; 
-    pub static MELLOW_APRICOT : Colour = Colour { red : 248, green : 184, blue : 120 }; 
-    pub static MELLOW_YELLOW : Colour = Colour { red : 248, green : 222, blue : 126 }; 
-    pub static MELON : Colour = Colour { red : 253, green : 188, blue : 180 }; 
-    pub static MIDNIGHT_BLUE : Colour = Colour { red : 25, green : 25, blue : 112 }; 
-    pub static MIDNIGHT_GREEN_EAGLE_GREEN : Colour = Colour { red : 0, green : 73, blue : 83 }; 
-    pub static MIKADO_YELLOW : Colour = Colour { red : 255, green : 196, blue : 12 }; 
-    pub static MINT : Colour = Colour { red : 62, green : 180, blue : 137 }; 
-    pub static MINT_CREAM : Colour = Colour { red : 245, green : 255, blue : 250 }; 
-    pub static MINT_GREEN : Colour = Colour { red : 152, green : 255, blue : 152 }; 
-    pub static MISTY_ROSE : Colour = Colour { red : 255, green : 228, blue : 225 }; 
-    pub static MOCCASIN : Colour = Colour { red : 250, green : 235, blue : 215 }; 
-    pub static MODE_BEIGE : Colour = Colour { red : 150, green : 113, blue : 23 }; 
-    pub static MOONSTONE_BLUE : Colour = Colour { red : 115, green : 169, blue : 194 }; 
-    pub static MORDANT_RED19 : Colour = Colour { red : 174, green : 12, blue : 0 }; 
-    pub static MOSS_GREEN : Colour = Colour { red : 173, green : 223, blue : 173 }; 
-    pub static MOUNTAIN_MEADOW : Colour = Colour { red : 48, green : 186, blue : 143 }; 
-    pub static MOUNTBATTEN_PINK : Colour = Colour { red : 153, green : 122, blue : 141 }; 
-    pub static MSU_GREEN : Colour = Colour { red : 24, green : 69, blue : 59 }; 
-    pub static MULBERRY : Colour = Colour { red : 197, green : 75, blue : 140 }; 
-    pub static MUSTARD : Colour = Colour { red : 255, green : 219, blue : 88 }; 
-    pub static MYRTLE : Colour = Colour { red : 33, green : 66, blue : 30 }; 
-    pub static NADESHIKO_PINK : Colour = Colour { red : 246, green : 173, blue : 198 }; 
-    pub static NAPIER_GREEN : Colour = Colour { red : 42, green : 128, blue : 0 }; 
-    pub static NAPLES_YELLOW : Colour = Colour { red : 250, green : 218, blue : 94 }; 
-    pub static NAVAJO_WHITE : Colour = Colour { red : 255, green : 222, blue : 173 }; 
-    pub static NAVY_BLUE : Colour = Colour { red : 0, green : 0, blue : 128 }; 
-    pub static NEON_CARROT : Colour = Colour { red : 255, green : 163, blue : 67 }; 
-    pub static NEON_FUCHSIA : Colour = Colour { red : 254, green : 65, blue : 100 }; 
-    pub static NEON_GREEN : Colour = Colour { red : 57, green : 255, blue : 20 }; 
-    pub static NEW_YORK_PINK : Colour = Colour { red : 215, green : 131, blue : 127 }; 
-    pub static NON_PHOTO_BLUE : Colour = Colour { red : 164, green : 221, blue : 237 }; 
-    pub static NORTH_TEXAS_GREEN : Colour = Colour { red : 5, green : 144, blue : 51 }; 
-    pub static OCEAN_BOAT_BLUE : Colour = Colour { red : 0, green : 119, blue : 190 }; 
-    pub static OCHRE : Colour = Colour { red : 204, green : 119, blue : 34 }; 
-    pub static OFFICE_GREEN : Colour = Colour { red : 0, green : 128, blue : 0 }; 
-    pub static OLD_GOLD : Colour = Colour { red : 207, green : 181, blue : 59 }; 
-    pub static OLD_LACE : Colour = Colour { red : 253, green : 245, blue : 230 }; 
-    pub static OLD_LAVENDER : Colour = Colour { red : 121, green : 104, blue : 120 }; 
-    pub static OLD_MAUVE : Colour = Colour { red : 103, green : 49, blue : 71 }; 
-    pub static OLD_ROSE : Colour = Colour { red : 192, green : 128, blue : 129 }; 
-    pub static OLIVE : Colour = Colour { red : 128, green : 128, blue : 0 }; 
-    pub static OLIVE_DRAB7 : Colour = Colour { red : 60, green : 52, blue : 31 }; 
-    pub static OLIVE_DRAB_WEB_OLIVE_DRAB3 : Colour = Colour { red : 107, green : 142, blue : 35 }; 
-    pub static OLIVINE : Colour = Colour { red : 154, green : 185, blue : 115 }; 
-    pub static ONYX : Colour = Colour { red : 53, green : 56, blue : 57 }; 
-    pub static OPERA_MAUVE : Colour = Colour { red : 183, green : 132, blue : 167 }; 
-    pub static ORANGE_COLOR_WHEEL : Colour = Colour { red : 255, green : 127, blue : 0 }; 
-    pub static ORANGE_PEEL : Colour = Colour { red : 255, green : 159, blue : 0 }; 
-    pub static ORANGE_RED : Colour = Colour { red : 255, green : 69, blue : 0 }; 
-    pub static ORANGE_RYB : Colour = Colour { red : 251, green : 153, blue : 2 }; 
-    pub static ORANGE_WEB_COLOR : Colour = Colour { red : 255, green : 165, blue : 0 }; 
-    pub static ORCHID : Colour = Colour { red : 218, green : 112, blue : 214 }; 
-    pub static OTTER_BROWN : Colour = Colour { red : 101, green : 67, blue : 33 }; 
-    pub static OU_CRIMSON_RED : Colour = Colour { red : 153, green : 0, blue : 0 }; 
-    pub static OUTER_SPACE : Colour = Colour { red : 65, green : 74, blue : 76 }; 
-    pub static OUTRAGEOUS_ORANGE : Colour = Colour { red : 255, green : 110, blue : 74 }; 
-    pub static OXFORD_BLUE : Colour = Colour { red : 0, green : 33, blue : 71 }; 
-    pub static PAKISTAN_GREEN : Colour = Colour { red : 0, green : 102, blue : 0 }; 
-    pub static PALATINATE_BLUE : Colour = Colour { red : 39, green : 59, blue : 226 }; 
-    pub static PALATINATE_PURPLE : Colour = Colour { red : 104, green : 40, blue : 96 }; 
-    pub static PALE_AQUA : Colour = Colour { red : 188, green : 212, blue : 230 }; 
-    pub static PALE_BLUE : Colour = Colour { red : 175, green : 238, blue : 238 }; 
-    pub static PALE_BROWN : Colour = Colour { red : 152, green : 118, blue : 84 }; 
-    pub static PALE_CARMINE : Colour = Colour { red : 175, green : 64, blue : 53 }; 
-    pub static PALE_CERULEAN : Colour = Colour { red : 155, green : 196, blue : 226 }; 
-    pub static PALE_CHESTNUT : Colour = Colour { red : 221, green : 173, blue : 175 }; 
-    pub static PALE_COPPER : Colour = Colour { red : 218, green : 138, blue : 103 }; 
-    pub static PALE_CORNFLOWER_BLUE : Colour = Colour { red : 171, green : 205, blue : 239 }; 
-    pub static PALE_GOLD : Colour = Colour { red : 230, green : 190, blue : 138 }; 
-    pub static PALE_GOLDENROD : Colour = Colour { red : 238, green : 232, blue : 170 }; 
-    pub static PALE_GREEN : Colour = Colour { red : 152, green : 251, blue : 152 }; 
-    pub static PALE_LAVENDER : Colour = Colour { red : 220, green : 208, blue : 255 }; 
-    pub static PALE_MAGENTA : Colour = Colour { red : 249, green : 132, blue : 229 }; 
-    pub static PALE_PINK : Colour = Colour { red : 250, green : 218, blue : 221 }; 
-    pub static PALE_PLUM : Colour = Colour { red : 221, green : 160, blue : 221 }; 
-    pub static PALE_RED_VIOLET : Colour = Colour { red : 219, green : 112, blue : 147 }; 
-    pub static PALE_ROBIN_EGG_BLUE : Colour = Colour { red : 150, green : 222, blue : 209 }; 
-    pub static PALE_SILVER : Colour = Colour { red : 201, green : 192, blue : 187 }; 
-    pub static PALE_SPRING_BUD : Colour = Colour { red : 236, green : 235, blue : 189 }; 
-    pub static PALE_TAUPE : Colour = Colour { red : 188, green : 152, blue : 126 }; 
-    pub static PALE_VIOLET_RED : Colour = Colour { red : 219, green : 112, blue : 147 }; 
-    pub static PANSY_PURPLE : Colour = Colour { red : 120, green : 24, blue : 74 }; 
-    pub static PAPAYA_WHIP : Colour = Colour { red : 255, green : 239, blue : 213 }; 
-    pub static PARIS_GREEN : Colour = Colour { red : 80, green : 200, blue : 120 }; 
-    pub static PASTEL_BLUE : Colour = Colour { red : 174, green : 198, blue : 207 }; 
-    pub static PASTEL_BROWN : Colour = Colour { red : 131, green : 105, blue : 83 }; 
-    pub static PASTEL_GRAY : Colour = Colour { red : 207, green : 207, blue : 196 }; 
-    pub static PASTEL_GREEN : Colour = Colour { red : 119, green : 221, blue : 119 }; 
-    pub static PASTEL_MAGENTA : Colour = Colour { red : 244, green : 154, blue : 194 }; 
-    pub static PASTEL_ORANGE : Colour = Colour { red : 255, green : 179, blue : 71 }; 
-    pub static PASTEL_PINK : Colour = Colour { red : 222, green : 165, blue : 164 }; 
-    pub static PASTEL_PURPLE : Colour = Colour { red : 179, green : 158, blue : 181 }; 
-    pub static PASTEL_RED : Colour = Colour { red : 255, green : 105, blue : 97 }; 
-    pub static PASTEL_VIOLET : Colour = Colour { red : 203, green : 153, blue : 201 }; 
-    pub static PASTEL_YELLOW : Colour = Colour { red : 253, green : 253, blue : 150 }; 
-    pub static PATRIARCH : Colour = Colour { red : 128, green : 0, blue : 128 }; 
-    pub static PAYNE_S_GREY : Colour = Colour { red : 83, green : 104, blue : 120 }; 
-    pub static PEACH : Colour = Colour { red : 255, green : 229, blue : 180 }; 
-    pub static PEACH_CRAYOLA : Colour = Colour { red : 255, green : 203, blue : 164 }; 
-    pub static PEACH_ORANGE : Colour = Colour { red : 255, green : 204, blue : 153 }; 
-    pub static PEACH_PUFF : Colour = Colour { red : 255, green : 218, blue : 185 }; 
-    pub static PEACH_YELLOW : Colour = Colour { red : 250, green : 223, blue : 173 }; 
-    pub static PEAR : Colour = Colour { red : 209, green : 226, blue : 49 }; 
-    pub static PEARL : Colour = Colour { red : 234, green : 224, blue : 200 }; 
-    pub static PEARL_AQUA : Colour = Colour { red : 136, green : 216, blue : 192 }; 
-    pub static PEARLY_PURPLE : Colour = Colour { red : 183, green : 104, blue : 162 }; 
-    pub static PERIDOT : Colour = Colour { red : 230, green : 226, blue : 0 }; 
-    pub static PERIWINKLE : Colour = Colour { red : 204, green : 204, blue : 255 }; 
-    pub static PERSIAN_BLUE : Colour = Colour { red : 28, green : 57, blue : 187 }; 
-    pub static PERSIAN_GREEN : Colour = Colour { red : 0, green : 166, blue : 147 }; 
-    pub static PERSIAN_INDIGO : Colour = Colour { red : 50, green : 18, blue : 122 }; 
-    pub static PERSIAN_ORANGE : Colour = Colour { red : 217, green : 144, blue : 88 }; 
-    pub static PERSIAN_PINK : Colour = Colour { red : 247, green : 127, blue : 190 }; 
-    pub static PERSIAN_PLUM : Colour = Colour { red : 112, green : 28, blue : 28 }; 
-    pub static PERSIAN_RED : Colour = Colour { red : 204, green : 51, blue : 51 }; 
-    pub static PERSIAN_ROSE : Colour = Colour { red : 254, green : 40, blue : 162 }; 
-    pub static PERSIMMON : Colour = Colour { red : 236, green : 88, blue : 0 }; 
-    pub static PERU : Colour = Colour { red : 205, green : 133, blue : 63 }; 
-    pub static PHLOX : Colour = Colour { red : 223, green : 0, blue : 255 }; 
-    pub static PHTHALO_BLUE : Colour = Colour { red : 0, green : 15, blue : 137 }; 
-    pub static PHTHALO_GREEN : Colour = Colour { red : 18, green : 53, blue : 36 }; 
-    pub static PIGGY_PINK : Colour = Colour { red : 253, green : 221, blue : 230 }; 
-    pub static PINE_GREEN : Colour = Colour { red : 1, green : 121, blue : 111 }; 
-    pub static PINK : Colour = Colour { red : 255, green : 192, blue : 203 }; 
-    pub static PINK_LACE : Colour = Colour { red : 255, green : 221, blue : 244 }; 
-    pub static PINK_ORANGE : Colour = Colour { red : 255, green : 153, blue : 102 }; 
-    pub static PINK_PEARL : Colour = Colour { red : 231, green : 172, blue : 207 }; 
-    pub static PINK_SHERBET : Colour = Colour { red : 247, green : 143, blue : 167 }; 
-    pub static PISTACHIO : Colour = Colour { red : 147, green : 197, blue : 114 }; 
-    pub static PLATINUM : Colour = Colour { red : 229, green : 228, blue : 226 }; 
-    pub static PLUM_TRADITIONAL : Colour = Colour { red : 142, green : 69, blue : 133 }; 
-    pub static PLUM_WEB : Colour = Colour { red : 221, green : 160, blue : 221 }; 
-    pub static PORTLAND_ORANGE : Colour = Colour { red : 255, green : 90, blue : 54 }; 
-    pub static POWDER_BLUE_WEB : Colour = Colour { red : 176, green : 224, blue : 230 }; 
-    pub static PRINCETON_ORANGE : Colour = Colour { red : 255, green : 143, blue : 0 }; 
-    pub static PRUNE : Colour = Colour { red : 112, green : 28, blue : 28 }; 
-    pub static PRUSSIAN_BLUE : Colour = Colour { red : 0, green : 49, blue : 83 }; 
-    pub static PSYCHEDELIC_PURPLE : Colour = Colour { red : 223, green : 0, blue : 255 }; 
-    pub static PUCE : Colour = Colour { red : 204, green : 136, blue : 153 }; 
-    pub static PUMPKIN : Colour = Colour { red : 255, green : 117, blue : 24 }; 
-    pub static PURPLE_HEART : Colour = Colour { red : 105, green : 53, blue : 156 }; 
-    pub static PURPLE_HTML_CSS : Colour = Colour { red : 128, green : 0, blue : 128 }; 
-    pub static PURPLE_MOUNTAIN_MAJESTY : Colour = Colour { red : 150, green : 120, blue : 182 }; 
-    pub static PURPLE_MUNSELL : Colour = Colour { red : 159, green : 0, blue : 197 }; 
-    pub static PURPLE_PIZZAZZ : Colour = Colour { red : 254, green : 78, blue : 218 }; 
-    pub static PURPLE_TAUPE : Colour = Colour { red : 80, green : 64, blue : 77 }; 
-    pub static PURPLE_X11 : Colour = Colour { red : 160, green : 32, blue : 240 }; 
-    pub static QUARTZ : Colour = Colour { red : 81, green : 72, blue : 79 }; 
-    pub static RACKLEY : Colour = Colour { red : 93, green : 138, blue : 168 }; 
-    pub static RADICAL_RED : Colour = Colour { red : 255, green : 53, blue : 94 }; 
-    pub static RAJAH : Colour = Colour { red : 251, green : 171, blue : 96 }; 
-    pub static RASPBERRY : Colour = Colour { red : 227, green : 11, blue : 93 }; 
-    pub static RASPBERRY_GLACE : Colour = Colour { red : 145, green : 95, blue : 109 }; 
-    pub static RASPBERRY_PINK : Colour = Colour { red : 226, green : 80, blue : 152 }; 
-    pub static RASPBERRY_ROSE : Colour = Colour { red : 179, green : 68, blue : 108 }; 
-    pub static RAW_UMBER : Colour = Colour { red : 130, green : 102, blue : 68 }; 
-    pub static RAZZLE_DAZZLE_ROSE : Colour = Colour { red : 255, green : 51, blue : 204 }; 
-    pub static RAZZMATAZZ : Colour = Colour { red : 227, green : 37, blue : 107 }; 
-    pub static RED : Colour = Colour { red : 255, green : 0, blue : 0 }; 
-    pub static RED_BROWN : Colour = Colour { red : 165, green : 42, blue : 42 }; 
-    pub static RED_DEVIL : Colour = Colour { red : 134, green : 1, blue : 17 }; 
-    pub static RED_MUNSELL : Colour = Colour { red : 242, green : 0, blue : 60 }; 
-    pub static RED_NCS : Colour = Colour { red : 196, green : 2, blue : 51 }; 
-    pub static RED_ORANGE : Colour = Colour { red : 255, green : 83, blue : 73 }; 
-    pub static RED_PIGMENT : Colour = Colour { red : 237, green : 28, blue : 36 }; 
-    pub static RED_RYB : Colour = Colour { red : 254, green : 39, blue : 18 }; 
-    pub static RED_VIOLET : Colour = Colour { red : 199, green : 21, blue : 133 }; 
-    pub static REDWOOD : Colour = Colour { red : 171, green : 78, blue : 82 }; 
-    pub static REGALIA : Colour = Colour { red : 82, green : 45, blue : 128 }; 
-    pub static RESOLUTION_BLUE : Colour = Colour { red : 0, green : 35, blue : 135 }; 
-    pub static RICH_BLACK : Colour = Colour { red : 0, green : 64, blue : 64 }; 
-    pub static RICH_BRILLIANT_LAVENDER : Colour = Colour { red : 241, green : 167, blue : 254 }; 
-    pub static RICH_CARMINE : Colour = Colour { red : 215, green : 0, blue : 64 }; 
-    pub static RICH_ELECTRIC_BLUE : Colour = Colour { red : 8, green : 146, blue : 208 }; 
-    pub static RICH_LAVENDER : Colour = Colour { red : 167, green : 107, blue : 207 }; 
-    pub static RICH_LILAC : Colour = Colour { red : 182, green : 102, blue : 210 }; 
-    pub static RICH_MAROON : Colour = Colour { red : 176, green : 48, blue : 96 }; 
-    pub static RIFLE_GREEN : Colour = Colour { red : 65, green : 72, blue : 51 }; 
-    pub static ROBIN_EGG_BLUE : Colour = Colour { red : 0, green : 204, blue : 204 }; 
-    pub static ROSE : Colour = Colour { red : 255, green : 0, blue : 127 }; 
-    pub static ROSE_BONBON : Colour = Colour { red : 249, green : 66, blue : 158 }; 
-    pub static ROSE_EBONY : Colour = Colour { red : 103, green : 72, blue : 70 }; 
-    pub static ROSE_GOLD : Colour = Colour { red : 183, green : 110, blue : 121 }; 
-    pub static ROSE_MADDER : Colour = Colour { red : 227, green : 38, blue : 54 }; 
-    pub static ROSE_PINK : Colour = Colour { red : 255, green : 102, blue : 204 }; 
-    pub static ROSE_QUARTZ : Colour = Colour { red : 170, green : 152, blue : 169 }; 
-    pub static ROSE_TAUPE : Colour = Colour { red : 144, green : 93, blue : 93 }; 
-    pub static ROSE_VALE : Colour = Colour { red : 171, green : 78, blue : 82 }; 
-    pub static ROSEWOOD : Colour = Colour { red : 101, green : 0, blue : 11 }; 
-    pub static ROSSO_CORSA : Colour = Colour { red : 212, green : 0, blue : 0 }; 
-    pub static ROSY_BROWN : Colour = Colour { red : 188, green : 143, blue : 143 }; 
-    pub static ROYAL_AZURE : Colour = Colour { red : 0, green : 56, blue : 168 }; 
-    pub static ROYAL_BLUE_TRADITIONAL : Colour = Colour { red : 0, green : 35, blue : 102 }; 
-    pub static ROYAL_BLUE_WEB : Colour = Colour { red : 65, green : 105, blue : 225 }; 
-    pub static ROYAL_FUCHSIA : Colour = Colour { red : 202, green : 44, blue : 146 }; 
-    pub static ROYAL_PURPLE : Colour = Colour { red : 120, green : 81, blue : 169 }; 
-    pub static ROYAL_YELLOW : Colour = Colour { red : 250, green : 218, blue : 94 }; 
-    pub static RUBINE_RED : Colour = Colour { red : 209, green : 0, blue : 86 }; 
-    pub static RUBY : Colour = Colour { red : 224, green : 17, blue : 95 }; 
-    pub static RUBY_RED : Colour = Colour { red : 155, green : 17, blue : 30 }; 
-    pub static RUDDY : Colour = Colour { red : 255, green : 0, blue : 40 }; 
-    pub static RUDDY_BROWN : Colour = Colour { red : 187, green : 101, blue : 40 }; 
-    pub static RUDDY_PINK : Colour = Colour { red : 225, green : 142, blue : 150 }; 
-    pub static RUFOUS : Colour = Colour { red : 168, green : 28, blue : 7 }; 
-    pub static RUSSET : Colour = Colour { red : 128, green : 70, blue : 27 }; 
-    pub static RUST : Colour = Colour { red : 183, green : 65, blue : 14 }; 
-    pub static RUSTY_RED : Colour = Colour { red : 218, green : 44, blue : 67 }; 
-    pub static SACRAMENTO_STATE_GREEN : Colour = Colour { red : 0, green : 86, blue : 63 }; 
-    pub static SADDLE_BROWN : Colour = Colour { red : 139, green : 69, blue : 19 }; 
-    pub static SAFETY_ORANGE_BLAZE_ORANGE : Colour = Colour { red : 255, green : 103, blue : 0 }; 
-    pub static SAFFRON : Colour = Colour { red : 244, green : 196, blue : 48 }; 
-    pub static SALMON : Colour = Colour { red : 255, green : 140, blue : 105 }; 
-    pub static SALMON_PINK : Colour = Colour { red : 255, green : 145, blue : 164 }; 
-    pub static SAND : Colour = Colour { red : 194, green : 178, blue : 128 }; 
-    pub static SAND_DUNE : Colour = Colour { red : 150, green : 113, blue : 23 }; 
-    pub static SANDSTORM : Colour = Colour { red : 236, green : 213, blue : 64 }; 
-    pub static SANDY_BROWN : Colour = Colour { red : 244, green : 164, blue : 96 }; 
-    pub static SANDY_TAUPE : Colour = Colour { red : 150, green : 113, blue : 23 }; 
-    pub static SANGRIA : Colour = Colour { red : 146, green : 0, blue : 10 }; 
-    pub static SAP_GREEN : Colour = Colour { red : 80, green : 125, blue : 42 }; 
-    pub static SAPPHIRE : Colour = Colour { red : 15, green : 82, blue : 186 }; 
-    pub static SAPPHIRE_BLUE : Colour = Colour { red : 0, green : 103, blue : 165 }; 
-    pub static SATIN_SHEEN_GOLD : Colour = Colour { red : 203, green : 161, blue : 53 }; 
-    pub static SCARLET : Colour = Colour { red : 255, green : 36, blue : 0 }; 
-    pub static SCARLET_CRAYOLA : Colour = Colour { red : 253, green : 14, blue : 53 }; 
-    pub static SCHOOL_BUS_YELLOW : Colour = Colour { red : 255, green : 216, blue : 0 }; 
-    pub static SCREAMIN_GREEN : Colour = Colour { red : 118, green : 255, blue : 122 }; 
-    pub static SEA_BLUE : Colour = Colour { red : 0, green : 105, blue : 148 }; 
-    pub static SEA_GREEN : Colour = Colour { red : 46, green : 139, blue : 87 }; 
-    pub static SEAL_BROWN : Colour = Colour { red : 50, green : 20, blue : 20 }; 
-    pub static SEASHELL : Colour = Colour { red : 255, green : 245, blue : 238 }; 
-    pub static SELECTIVE_YELLOW : Colour = Colour { red : 255, green : 186, blue : 0 }; 
-    pub static SEPIA : Colour = Colour { red : 112, green : 66, blue : 20 }; 
-    pub static SHADOW : Colour = Colour { red : 138, green : 121, blue : 93 }; 
-    pub static SHAMROCK_GREEN : Colour = Colour { red : 0, green : 158, blue : 96 }; 
-    pub static SHOCKING_PINK : Colour = Colour { red : 252, green : 15, blue : 192 }; 
-    pub static SHOCKING_PINK_CRAYOLA : Colour = Colour { red : 255, green : 111, blue : 255 }; 
-    pub static SIENNA : Colour = Colour { red : 136, green : 45, blue : 23 }; 
-    pub static SILVER : Colour = Colour { red : 192, green : 192, blue : 192 }; 
-    pub static SINOPIA : Colour = Colour { red : 203, green : 65, blue : 11 }; 
-    pub static SKOBELOFF : Colour = Colour { red : 0, green : 116, blue : 116 }; 
-    pub static SKY_BLUE : Colour = Colour { red : 135, green : 206, blue : 235 }; 
-    pub static SKY_MAGENTA : Colour = Colour { red : 207, green : 113, blue : 175 }; 
-    pub static SLATE_BLUE : Colour = Colour { red : 106, green : 90, blue : 205 }; 
-    pub static SLATE_GRAY : Colour = Colour { red : 112, green : 128, blue : 144 }; 
-    pub static SMALT_DARK_POWDER_BLUE : Colour = Colour { red : 0, green : 51, blue : 153 }; 
-    pub static SMOKEY_TOPAZ : Colour = Colour { red : 147, green : 61, blue : 65 }; 
-    pub static SMOKY_BLACK : Colour = Colour { red : 16, green : 12, blue : 8 }; 
-    pub static SNOW : Colour = Colour { red : 255, green : 250, blue : 250 }; 
-    pub static SPIRO_DISCO_BALL : Colour = Colour { red : 15, green : 192, blue : 252 }; 
-    pub static SPRING_BUD : Colour = Colour { red : 167, green : 252, blue : 0 }; 
-    pub static SPRING_GREEN : Colour = Colour { red : 0, green : 255, blue : 127 }; 
-    pub static ST_PATRICK_S_BLUE : Colour = Colour { red : 35, green : 41, blue : 122 }; 
-    pub static STEEL_BLUE : Colour = Colour { red : 70, green : 130, blue : 180 }; 
-    pub static STIL_DE_GRAIN_YELLOW : Colour = Colour { red : 250, green : 218, blue : 94 }; 
-    pub static STIZZA : Colour = Colour { red : 153, green : 0, blue : 0 }; 
-    pub static STORMCLOUD : Colour = Colour { red : 79, green : 102, blue : 106 }; 
-    pub static STRAW : Colour = Colour { red : 228, green : 217, blue : 111 }; 
-    pub static SUNGLOW : Colour = Colour { red : 255, green : 204, blue : 51 }; 
-    pub static SUNSET : Colour = Colour { red : 250, green : 214, blue : 165 }; 
-    pub static TAN : Colour = Colour { red : 210, green : 180, blue : 140 }; 
-    pub static TANGELO : Colour = Colour { red : 249, green : 77, blue : 0 }; 
-    pub static TANGERINE : Colour = Colour { red : 242, green : 133, blue : 0 }; 
-    pub static TANGERINE_YELLOW : Colour = Colour { red : 255, green : 204, blue : 0 }; 
-    pub static TANGO_PINK : Colour = Colour { red : 228, green : 113, blue : 122 }; 
-    pub static TAUPE : Colour = Colour { red : 72, green : 60, blue : 50 }; 
-    pub static TAUPE_GRAY : Colour = Colour { red : 139, green : 133, blue : 137 }; 
-    pub static TEA_GREEN : Colour = Colour { red : 208, green : 240, blue : 192 }; 
-    pub static TEA_ROSE_ORANGE : Colour = Colour { red : 248, green : 131, blue : 121 }; 
-    pub static TEA_ROSE_ROSE : Colour = Colour { red : 244, green : 194, blue : 194 }; 
-    pub static TEAL : Colour = Colour { red : 0, green : 128, blue : 128 }; 
-    pub static TEAL_BLUE : Colour = Colour { red : 54, green : 117, blue : 136 }; 
-    pub static TEAL_GREEN : Colour = Colour { red : 0, green : 130, blue : 127 }; 
-    pub static TELEMAGENTA : Colour = Colour { red : 207, green : 52, blue : 118 }; 
-    pub static TENN_TAWNY : Colour = Colour { red : 205, green : 87, blue : 0 }; 
-    pub static TERRA_COTTA : Colour = Colour { red : 226, green : 114, blue : 91 }; 
-    pub static THISTLE : Colour = Colour { red : 216, green : 191, blue : 216 }; 
-    pub static THULIAN_PINK : Colour = Colour { red : 222, green : 111, blue : 161 }; 
-    pub static TICKLE_ME_PINK : Colour = Colour { red : 252, green : 137, blue : 172 }; 
-    pub static TIFFANY_BLUE : Colour = Colour { red : 10, green : 186, blue : 181 }; 
-    pub static TIGER_S_EYE : Colour = Colour { red : 224, green : 141, blue : 60 }; 
-    pub static TIMBERWOLF : Colour = Colour { red : 219, green : 215, blue : 210 }; 
-    pub static TITANIUM_YELLOW : Colour = Colour { red : 238, green : 230, blue : 0 }; 
-    pub static TOMATO : Colour = Colour { red : 255, green : 99, blue : 71 }; 
-    pub static TOOLBOX : Colour = Colour { red : 116, green : 108, blue : 192 }; 
-    pub static TOPAZ : Colour = Colour { red : 255, green : 200, blue : 124 }; 
-    pub static TRACTOR_RED : Colour = Colour { red : 253, green : 14, blue : 53 }; 
-    pub static TROLLEY_GREY : Colour = Colour { red : 128, green : 128, blue : 128 }; 
-    pub static TROPICAL_RAIN_FOREST : Colour = Colour { red : 0, green : 117, blue : 94 }; 
-    pub static TRUE_BLUE : Colour = Colour { red : 0, green : 115, blue : 207 }; 
-    pub static TUFTS_BLUE : Colour = Colour { red : 65, green : 125, blue : 193 }; 
-    pub static TUMBLEWEED : Colour = Colour { red : 222, green : 170, blue : 136 }; 
-    pub static TURKISH_ROSE : Colour = Colour { red : 181, green : 114, blue : 129 }; 
-    pub static TURQUOISE : Colour = Colour { red : 48, green : 213, blue : 200 }; 
-    pub static TURQUOISE_BLUE : Colour = Colour { red : 0, green : 255, blue : 239 }; 
-    pub static TURQUOISE_GREEN : Colour = Colour { red : 160, green : 214, blue : 180 }; 
-    pub static TUSCAN_RED : Colour = Colour { red : 124, green : 72, blue : 72 }; 
-    pub static TWILIGHT_LAVENDER : Colour = Colour { red : 138, green : 73, blue : 107 }; 
-    pub static TYRIAN_PURPLE : Colour = Colour { red : 102, green : 2, blue : 60 }; 
-    pub static UA_BLUE : Colour = Colour { red : 0, green : 51, blue : 170 };
-    pub static UA_RED : Colour = Colour { red : 217, green : 0, blue : 76 };
-    pub static UBE : Colour = Colour { red : 136, green : 120, blue : 195 }; 
-    pub static UCLA_BLUE : Colour = Colour { red : 83, green : 104, blue : 149 }; 
-    pub static UCLA_GOLD : Colour = Colour { red : 255, green : 179, blue : 0 }; 
-    pub static UFO_GREEN : Colour = Colour { red : 60, green : 208, blue : 112 }; 
-    pub static ULTRA_PINK : Colour = Colour { red : 255, green : 111, blue : 255 }; 
-    pub static ULTRAMARINE : Colour = Colour { red : 18, green : 10, blue : 143 }; 
-    pub static ULTRAMARINE_BLUE : Colour = Colour { red : 65, green : 102, blue : 245 }; 
-    pub static UMBER : Colour = Colour { red : 99, green : 81, blue : 71 }; 
-    pub static UNBLEACHED_SILK : Colour = Colour { red : 255, green : 221, blue : 202 }; 
-    pub static UNITED_NATIONS_BLUE : Colour = Colour { red : 91, green : 146, blue : 229 }; 
-    pub static UNIVERSITY_OF_CALIFORNIA_GOLD : Colour = Colour { red : 183, blue : 135, green : 39 }; 
-    pub static UNMELLOW_YELLOW : Colour = Colour { red : 255, green : 255, blue : 102 }; 
-    pub static UP_FOREST_GREEN : Colour = Colour { red : 1, green : 68, blue : 33 }; 
-    pub static UP_MAROON : Colour = Colour { red : 123, green : 17, blue : 19 }; 
-    pub static UPSDELL_RED : Colour = Colour { red : 174, green : 32, blue : 41 }; 
-    pub static UROBILIN : Colour = Colour { red : 225, green : 173, blue : 33 }; 
-    pub static USAFA_BLUE : Colour = Colour { red : 0, green : 79, blue : 152 }; 
-    pub static USC_CARDINAL : Colour = Colour { red : 153, green : 0, blue : 0 }; 
-    pub static USC_GOLD : Colour = Colour { red : 255, green : 204, blue : 0 }; 
-    pub static UTAH_CRIMSON : Colour = Colour { red : 211, green : 0, blue : 63 }; 
-    pub static VANILLA : Colour = Colour { red : 243, green : 229, blue : 171 }; 
-    pub static VEGAS_GOLD : Colour = Colour { red : 197, green : 179, blue : 88 }; 
-    pub static VENETIAN_RED : Colour = Colour { red : 200, green : 8, blue : 21 }; 
-    pub static VERDIGRIS : Colour = Colour { red : 67, green : 179, blue : 174 };
-    pub static VERMILION_CINNABAR : Colour = Colour { red : 227, green : 66, blue : 52 }; 
-    pub static VERMILION_PLOCHERE : Colour = Colour { red : 217, green : 96, blue : 59 }; 
-    pub static VERONICA : Colour = Colour { red : 160, green : 32, blue : 240 }; 
-    pub static VIOLET : Colour = Colour { red : 143, green : 0, blue : 255 }; 
-    pub static VIOLET_BLUE : Colour = Colour { red : 50, green : 74, blue : 178 }; 
-    pub static VIOLET_COLOR_WHEEL : Colour = Colour { red : 127, green : 0, blue : 255 }; 
-    pub static VIOLET_RYB : Colour = Colour { red : 134, green : 1, blue : 175 }; 
-    pub static VIOLET_WEB : Colour = Colour { red : 238, green : 130, blue : 238 }; 
-    pub static VIRIDIAN : Colour = Colour { red : 64, green : 130, blue : 109 }; 
-    pub static VIVID_AUBURN : Colour = Colour { red : 146, green : 39, blue : 36 }; 
-    pub static VIVID_BURGUNDY : Colour = Colour { red : 159, green : 29, blue : 53 }; 
-    pub static VIVID_CERISE : Colour = Colour { red : 218, green : 29, blue : 129 }; 
-    pub static VIVID_TANGERINE : Colour = Colour { red : 255, green : 160, blue : 137 }; 
-    pub static VIVID_VIOLET : Colour = Colour { red : 159, green : 0, blue : 255 }; 
-    pub static WARM_BLACK : Colour = Colour { red : 0, green : 66, blue : 66 }; 
-    pub static WATERSPOUT : Colour = Colour { red : 164, green : 244, blue : 249 }; 
-    pub static WENGE : Colour = Colour { red : 100, green : 84, blue : 82 }; 
-    pub static WHEAT : Colour = Colour { red : 245, green : 222, blue : 179 }; 
-    pub static WHITE : Colour = Colour { red : 255, green : 255, blue : 255 }; 
-    pub static WHITE_SMOKE : Colour = Colour { red : 245, green : 245, blue : 245 }; 
-    pub static WILD_BLUE_YONDER : Colour = Colour { red : 162, green : 173, blue : 208 }; 
-    pub static WILD_STRAWBERRY : Colour = Colour { red : 255, green : 67, blue : 164 }; 
-    pub static WILD_WATERMELON : Colour = Colour { red : 252, green : 108, blue : 133 }; 
-    pub static WINE : Colour = Colour { red : 114, green : 47, blue : 55 }; 
-    pub static WINE_DREGS : Colour = Colour { red : 103, green : 49, blue : 71 }; 
-    pub static WISTERIA : Colour = Colour { red : 201, green : 160, blue : 220 }; 
-    pub static WOOD_BROWN : Colour = Colour { red : 193, green : 154, blue : 107 }; 
-    pub static XANADU : Colour = Colour { red : 115, green : 134, blue : 120 }; 
-    pub static YALE_BLUE : Colour = Colour { red : 15, green : 77, blue : 146 }; 
-    pub static YELLOW : Colour = Colour { red : 255, green : 255, blue : 0 }; 
-    pub static YELLOW_GREEN : Colour = Colour { red : 154, green : 205, blue : 50 }; 
-    pub static YELLOW_MUNSELL : Colour = Colour { red : 239, green : 204, blue : 0 }; 
-    pub static YELLOW_NCS : Colour = Colour { red : 255, green : 211, blue : 0 }; 
-    pub static YELLOW_ORANGE : Colour = Colour { red : 255, green : 174, blue : 66 }; 
-    pub static YELLOW_PROCESS : Colour = Colour { red : 255, green : 239, blue : 0 }; 
-    pub static YELLOW_RYB : Colour = Colour { red : 254, green : 254, blue : 51 }; 
-    pub static ZAFFRE : Colour = Colour { red : 0, green : 20, blue : 168 }; 
-    pub static ZINNWALDITE_BROWN : Colour = Colour { red : 44, green : 22, blue : 8 };
+    /// Declares each colour as a static, along with NAMED, so that every declared colour is named by construction.
+    macro_rules! css_colours {
+        ($($name : ident = ($red : literal, $green : literal, $blue : literal);)*) => {
+            $(pub static $name : Colour = Colour { red : $red, green : $green, blue : $blue };)*
+
+            /// Every colour in this module paired with its name, as declared above.
+            pub static NAMED : [(&str, &Colour); [$(stringify!($name)),*].len()] = [$((stringify!($name), &$name)),*];
+        };
+    }
+
+    css_colours! {
+        AIR_FORCE_BLUE_RAF = (93, 138, 168);
+        AIR_FORCE_BLUE_USAF = (0, 48, 143);
+        AIR_SUPERIORITY_BLUE = (114, 160, 193);
+        ALABAMA_CRIMSON = (163, 38, 56);
+        ALICE_BLUE = (240, 248, 255);
+        ALIZARIN_CRIMSON = (227, 38, 54);
+        ALLOY_ORANGE = (196, 98, 16);
+        ALMOND = (239, 222, 205);
+        AMARANTH = (229, 43, 80);
+        AMBER = (255, 191, 0);
+        AMBER_SAE_ECE = (255, 126, 0);
+        AMERICAN_ROSE = (255, 3, 62);
+        AMETHYST = (153, 102, 204);
+        ANDROID_GREEN = (164, 198, 57);
+        ANTI_FLASH_WHITE = (242, 243, 244);
+        ANTIQUE_BRASS = (205, 149, 117);
+        ANTIQUE_FUCHSIA = (145, 92, 131);
+        ANTIQUE_RUBY = (132, 27, 45);
+        ANTIQUE_WHITE = (250, 235, 215);
+        AO_ENGLISH = (0, 128, 0);
+        APPLE_GREEN = (141, 182, 0);
+        APRICOT = (251, 206, 177);
+        AQUA = (0, 255, 255);
+        AQUAMARINE = (127, 255, 212);
+        ARMY_GREEN = (75, 83, 32);
+        ARSENIC = (59, 68, 75);
+        ARYLIDE_YELLOW = (233, 214, 107);
+        ASH_GREY = (178, 190, 181);
+        ASPARAGUS = (135, 169, 107);
+        ATOMIC_TANGERINE = (255, 153, 102);
+        AUBURN = (165, 42, 42);
+        AUREOLIN = (253, 238, 0);
+        AUROMETALSAURUS = (110, 127, 128);
+        AVOCADO = (86, 130, 3);
+        AZURE = (0, 127, 255);
+        AZURE_MIST_WEB = (240, 255, 255);
+        BABY_BLUE = (137, 207, 240);
+        BABY_BLUE_EYES = (161, 202, 241);
+        BABY_PINK = (244, 194, 194);
+        BALL_BLUE = (33, 171, 205);
+        BANANA_MANIA = (250, 231, 181);
+        BANANA_YELLOW = (255, 225, 53);
+        BARN_RED = (124, 10, 2);
+        BATTLESHIP_GREY = (132, 132, 130);
+        BAZAAR = (152, 119, 123);
+        BEAU_BLUE = (188, 212, 230);
+        BEAVER = (159, 129, 112);
+        BEIGE = (245, 245, 220);
+        BIG_DIP_O_RUBY = (156, 37, 66);
+        BISQUE = (255, 228, 196);
+        BISTRE = (61, 43, 31);
+        BITTERSWEET = (254, 111, 94);
+        BITTERSWEET_SHIMMER = (191, 79, 81);
+        BLACK = (0, 0, 0);
+        BLACK_BEAN = (61, 12, 2);
+        BLACK_LEATHER_JACKET = (37, 53, 41);
+        BLACK_OLIVE = (59, 60, 54);
+        BLANCHED_ALMOND = (255, 235, 205);
+        BLAST_OFF_BRONZE = (165, 113, 100);
+        BLEU_DE_FRANCE = (49, 140, 231);
+        BLIZZARD_BLUE = (172, 229, 238);
+        BLOND = (250, 240, 190);
+        BLUE = (0, 0, 255);
+        BLUE_BELL = (162, 162, 208);
+        BLUE_CRAYOLA = (31, 117, 254);
+        BLUE_GRAY = (102, 153, 204);
+        BLUE_GREEN = (13, 152, 186);
+        BLUE_MUNSELL = (0, 147, 175);
+        BLUE_NCS = (0, 135, 189);
+        BLUE_PIGMENT = (51, 51, 153);
+        BLUE_RYB = (2, 71, 254);
+        BLUE_SAPPHIRE = (18, 97, 128);
+        BLUE_VIOLET = (138, 43, 226);
+        BLUSH = (222, 93, 131);
+        BOLE = (121, 68, 59);
+        BONDI_BLUE = (0, 149, 182);
+        BONE = (227, 218, 201);
+        BOSTON_UNIVERSITY_RED = (204, 0, 0);
+        BOTTLE_GREEN = (0, 106, 78);
+        BOYSENBERRY = (135, 50, 96);
+        BRANDEIS_BLUE = (0, 112, 255);
+        BRASS = (181, 166, 66);
+        BRICK_RED = (203, 65, 84);
+        BRIGHT_CERULEAN = (29, 172, 214);
+        BRIGHT_GREEN = (102, 255, 0);
+        BRIGHT_LAVENDER = (191, 148, 228);
+        BRIGHT_MAROON = (195, 33, 72);
+        BRIGHT_PINK = (255, 0, 127);
+        BRIGHT_TURQUOISE = (8, 232, 222);
+        BRIGHT_UBE = (209, 159, 232);
+        BRILLIANT_LAVENDER = (244, 187, 255);
+        BRILLIANT_ROSE = (255, 85, 163);
+        BRINK_PINK = (251, 96, 127);
+        BRITISH_RACING_GREEN = (0, 66, 37);
+        BRONZE = (205, 127, 50);
+        BROWN_TRADITIONAL = (150, 75, 0);
+        BROWN_WEB = (165, 42, 42);
+        BUBBLE_GUM = (255, 193, 204);
+        BUBBLES = (231, 254, 255);
+        BUFF = (240, 220, 130);
+        BULGARIAN_ROSE = (72, 6, 7);
+        BURGUNDY = (128, 0, 32);
+        BURLYWOOD = (222, 184, 135);
+        BURNT_ORANGE = (204, 85, 0);
+        BURNT_SIENNA = (233, 116, 81);
+        BURNT_UMBER = (138, 51, 36);
+        BYZANTINE = (189, 51, 164);
+        BYZANTIUM = (112, 41, 99);
+        CADET = (83, 104, 114);
+        CADET_BLUE = (95, 158, 160);
+        CADET_GREY = (145, 163, 176);
+        CADMIUM_GREEN = (0, 107, 60);
+        CADMIUM_ORANGE = (237, 135, 45);
+        CADMIUM_RED = (227, 0, 34);
+        CADMIUM_YELLOW = (255, 246, 0);
+        CAF_AU_LAIT = (166, 123, 91);
+        CAF_NOIR = (75, 54, 33);
+        CAL_POLY_GREEN = (30, 77, 43);
+        CAMBRIDGE_BLUE = (163, 193, 173);
+        CAMEL = (193, 154, 107);
+        CAMEO_PINK = (239, 187, 204);
+        CAMOUFLAGE_GREEN = (120, 134, 107);
+        CANARY_YELLOW = (255, 239, 0);
+        CANDY_APPLE_RED = (255, 8, 0);
+        CANDY_PINK = (228, 113, 122);
+        CAPRI = (0, 191, 255);
+        CAPUT_MORTUUM = (89, 39, 32);
+        CARDINAL = (196, 30, 58);
+        CARIBBEAN_GREEN = (0, 204, 153);
+        CARMINE = (150, 0, 24);
+        CARMINE_MP = (215, 0, 64);
+        CARMINE_PINK = (235, 76, 66);
+        CARMINE_RED = (255, 0, 56);
+        CARNATION_PINK = (255, 166, 201);
+        CARNELIAN = (179, 27, 27);
+        CAROLINA_BLUE = (153, 186, 221);
+        CARROT_ORANGE = (237, 145, 33);
+        CATALINA_BLUE = (6, 42, 120);
+        CEIL = (146, 161, 207);
+        CELADON = (172, 225, 175);
+        CELADON_BLUE = (0, 123, 167);
+        CELADON_GREEN = (47, 132, 124);
+        CELESTE_COLOUR = (178, 255, 255);
+        CELESTIAL_BLUE = (73, 151, 208);
+        CERISE = (222, 49, 99);
+        CERISE_PINK = (236, 59, 131);
+        CERULEAN = (0, 123, 167);
+        CERULEAN_BLUE = (42, 82, 190);
+        CERULEAN_FROST = (109, 155, 195);
+        CG_BLUE = (0, 122, 165);
+        CG_RED = (224, 60, 49);
+        CHAMOISEE = (160, 120, 90);
+        CHAMPAGNE = (250, 214, 165);
+        CHARCOAL = (54, 69, 79);
+        CHARM_PINK = (230, 143, 172);
+        CHARTREUSE_TRADITIONAL = (223, 255, 0);
+        CHARTREUSE_WEB = (127, 255, 0);
+        CHERRY = (222, 49, 99);
+        CHERRY_BLOSSOM_PINK = (255, 183, 197);
+        CHESTNUT = (205, 92, 92);
+        CHINA_PINK = (222, 111, 161);
+        CHINA_ROSE = (168, 81, 110);
+        CHINESE_RED = (170, 56, 30);
+        CHOCOLATE_TRADITIONAL = (123, 63, 0);
+        CHOCOLATE_WEB = (210, 105, 30);
+        CHROME_YELLOW = (255, 167, 0);
+        CINEREOUS = (152, 129, 123);
+        CINNABAR = (227, 66, 52);
+        CINNAMON = (210, 105, 30);
+        CITRINE = (228, 208, 10);
+        CLASSIC_ROSE = (251, 204, 231);
+        COBALT = (0, 71, 171);
+        COCOA_BROWN = (210, 105, 30);
+        COFFEE = (111, 78, 55);
+        COLUMBIA_BLUE = (155, 221, 255);
+        CONGO_PINK = (248, 131, 121);
+        COOL_BLACK = (0, 46, 99);
+        COOL_GREY = (140, 146, 172);
+        COPPER = (184, 115, 51);
+        COPPER_CRAYOLA = (218, 138, 103);
+        COPPER_PENNY = (173, 111, 105);
+        COPPER_RED = (203, 109, 81);
+        COPPER_ROSE = (153, 102, 102);
+        COQUELICOT = (255, 56, 0);
+        CORAL = (255, 127, 80);
+        CORAL_PINK = (248, 131, 121);
+        CORAL_RED = (255, 64, 64);
+        CORDOVAN = (137, 63, 69);
+        CORN = (251, 236, 93);
+        CORNELL_RED = (179, 27, 27);
+        CORNFLOWER_BLUE = (100, 149, 237);
+        CORNSILK = (255, 248, 220);
+        COSMIC_LATTE = (255, 248, 231);
+        COTTON_CANDY = (255, 188, 217);
+        CREAM = (255, 253, 208);
+        CRIMSON = (220, 20, 60);
+        CRIMSON_GLORY = (190, 0, 50);
+        CYAN = (0, 255, 255);
+        CYAN_PROCESS = (0, 183, 235);
+        DAFFODIL = (255, 255, 49);
+        DANDELION = (240, 225, 48);
+        DARK_BLUE = (0, 0, 139);
+        DARK_BROWN = (101, 67, 33);
+        DARK_BYZANTIUM = (93, 57, 84);
+        DARK_CANDY_APPLE_RED = (164, 0, 0);
+        DARK_CERULEAN = (8, 69, 126);
+        DARK_CHESTNUT = (152, 105, 96);
+        DARK_CORAL = (205, 91, 69);
+        DARK_CYAN = (0, 139, 139);
+        DARK_ELECTRIC_BLUE = (83, 104, 120);
+        DARK_GOLDENROD = (184, 134, 11);
+        DARK_GRAY = (169, 169, 169);
+        DARK_GREEN = (1, 50, 32);
+        DARK_IMPERIAL_BLUE = (0, 65, 106);
+        DARK_JUNGLE_GREEN = (26, 36, 33);
+        DARK_KHAKI = (189, 183, 107);
+        DARK_LAVA = (72, 60, 50);
+        DARK_LAVENDER = (115, 79, 150);
+        DARK_MAGENTA = (139, 0, 139);
+        DARK_MIDNIGHT_BLUE = (0, 51, 102);
+        DARK_OLIVE_GREEN = (85, 107, 47);
+        DARK_ORANGE = (255, 140, 0);
+        DARK_ORCHID = (153, 50, 204);
+        DARK_PASTEL_BLUE = (119, 158, 203);
+        DARK_PASTEL_GREEN = (3, 192, 60);
+        DARK_PASTEL_PURPLE = (150, 111, 214);
+        DARK_PASTEL_RED = (194, 59, 34);
+        DARK_PINK = (231, 84, 128);
+        DARK_POWDER_BLUE = (0, 51, 153);
+        DARK_RASPBERRY = (135, 38, 87);
+        DARK_RED = (139, 0, 0);
+        DARK_SALMON = (233, 150, 122);
+        DARK_SCARLET = (86, 3, 25);
+        DARK_SEA_GREEN = (143, 188, 143);
+        DARK_SIENNA = (60, 20, 20);
+        DARK_SLATE_BLUE = (72, 61, 139);
+        DARK_SLATE_GRAY = (47, 79, 79);
+        DARK_SPRING_GREEN = (23, 114, 69);
+        DARK_TAN = (145, 129, 81);
+        DARK_TANGERINE = (255, 168, 18);
+        DARK_TAUPE = (72, 60, 50);
+        DARK_TERRA_COTTA = (204, 78, 92);
+        DARK_TURQUOISE = (0, 206, 209);
+        DARK_VIOLET = (148, 0, 211);
+        DARK_YELLOW = (155, 135, 12);
+        DARTMOUTH_GREEN = (0, 112, 60);
+        DAVY_S_GREY = (85, 85, 85);
+        DEBIAN_RED = (215, 10, 83);
+        DEEP_CARMINE = (169, 32, 62);
+        DEEP_CARMINE_PINK = (239, 48, 56);
+        DEEP_CARROT_ORANGE = (233, 105, 44);
+        DEEP_CERISE = (218, 50, 135);
+        DEEP_CHAMPAGNE = (250, 214, 165);
+        DEEP_CHESTNUT = (185, 78, 72);
+        DEEP_COFFEE = (112, 66, 65);
+        DEEP_FUCHSIA = (193, 84, 193);
+        DEEP_JUNGLE_GREEN = (0, 75, 73);
+        DEEP_LILAC = (153, 85, 187);
+        DEEP_MAGENTA = (204, 0, 204);
+        DEEP_PEACH = (255, 203, 164);
+        DEEP_PINK = (255, 20, 147);
+        DEEP_RUBY = (132, 63, 91);
+        DEEP_SAFFRON = (255, 153, 51);
+        DEEP_SKY_BLUE = (0, 191, 255);
+        DEEP_TUSCAN_RED = (102, 66, 77);
+        DENIM = (21, 96, 189);
+        DESERT = (193, 154, 107);
+        DESERT_SAND = (237, 201, 175);
+        DIM_GRAY = (105, 105, 105);
+        DODGER_BLUE = (30, 144, 255);
+        DOGWOOD_ROSE = (215, 24, 104);
+        DOLLAR_BILL = (133, 187, 101);
+        DRAB = (150, 113, 23);
+        DUKE_BLUE = (0, 0, 156);
+        EARTH_YELLOW = (225, 169, 95);
+        EBONY = (85, 93, 80);
+        ECRU = (194, 178, 128);
+        EGGPLANT = (97, 64, 81);
+        EGGSHELL = (240, 234, 214);
+        EGYPTIAN_BLUE = (16, 52, 166);
+        ELECTRIC_BLUE = (125, 249, 255);
+        ELECTRIC_CRIMSON = (255, 0, 63);
+        ELECTRIC_CYAN = (0, 255, 255);
+        ELECTRIC_GREEN = (0, 255, 0);
+        ELECTRIC_INDIGO = (111, 0, 255);
+        ELECTRIC_LAVENDER = (244, 187, 255);
+        ELECTRIC_LIME = (204, 255, 0);
+        ELECTRIC_PURPLE = (191, 0, 255);
+        ELECTRIC_ULTRAMARINE = (63, 0, 255);
+        ELECTRIC_VIOLET = (143, 0, 255);
+        ELECTRIC_YELLOW = (255, 255, 0);
+        EMERALD = (80, 200, 120);
+        ENGLISH_LAVENDER = (180, 131, 149);
+        ETON_BLUE = (150, 200, 162);
+        FALLOW = (193, 154, 107);
+        FALU_RED = (128, 24, 24);
+        FANDANGO = (181, 51, 137);
+        FASHION_FUCHSIA = (244, 0, 161);
+        FAWN = (229, 170, 112);
+        FELDGRAU = (77, 93, 83);
+        FERN_GREEN = (79, 121, 66);
+        FERRARI_RED = (255, 40, 0);
+        FIELD_DRAB = (108, 84, 30);
+        FIRE_ENGINE_RED = (206, 32, 41);
+        FIREBRICK = (178, 34, 34);
+        FLAME = (226, 88, 34);
+        FLAMINGO_PINK = (252, 142, 172);
+        FLAVESCENT = (247, 233, 142);
+        FLAX = (238, 220, 130);
+        FLORAL_WHITE = (255, 250, 240);
+        FLUORESCENT_ORANGE = (255, 191, 0);
+        FLUORESCENT_PINK = (255, 20, 147);
+        FLUORESCENT_YELLOW = (204, 255, 0);
+        FOLLY = (255, 0, 79);
+        FOREST_GREEN_TRADITIONAL = (1, 68, 33);
+        FOREST_GREEN_WEB = (34, 139, 34);
+        FRENCH_BEIGE = (166, 123, 91);
+        FRENCH_BLUE = (0, 114, 187);
+        FRENCH_LILAC = (134, 96, 142);
+        FRENCH_LIME = (204, 255, 0);
+        FRENCH_RASPBERRY = (199, 44, 72);
+        FRENCH_ROSE = (246, 74, 138);
+        FUCHSIA = (255, 0, 255);
+        FUCHSIA_CRAYOLA = (193, 84, 193);
+        FUCHSIA_PINK = (255, 119, 255);
+        FUCHSIA_ROSE = (199, 67, 117);
+        FULVOUS = (228, 132, 0);
+        FUZZY_WUZZY = (204, 102, 102);
+        GAINSBORO = (220, 220, 220);
+        GAMBOGE = (228, 155, 15);
+        GHOST_WHITE = (248, 248, 255);
+        GINGER = (176, 101, 0);
+        GLAUCOUS = (96, 130, 182);
+        GLITTER = (230, 232, 250);
+        GOLD_METALLIC = (212, 175, 55);
+        GOLD_WEB_GOLDEN = (255, 215, 0);
+        GOLDEN_BROWN = (153, 101, 21);
+        GOLDEN_POPPY = (252, 194, 0);
+        GOLDEN_YELLOW = (255, 223, 0);
+        GOLDENROD = (218, 165, 32);
+        GRANNY_SMITH_APPLE = (168, 228, 160);
+        GRAY = (128, 128, 128);
+        GRAY_ASPARAGUS = (70, 89, 69);
+        GRAY_HTML_CSS_GRAY = (128, 128, 128);
+        GRAY_X11_GRAY = (190, 190, 190);
+        GREEN_COLOR_WHEEL_X11_GREEN = (0, 255, 0);
+        GREEN_CRAYOLA = (28, 172, 120);
+        GREEN_HTML_CSS_GREEN = (0, 128, 0);
+        GREEN_MUNSELL = (0, 168, 119);
+        GREEN_NCS = (0, 159, 107);
+        GREEN_PIGMENT = (0, 165, 80);
+        GREEN_RYB = (102, 176, 50);
+        GREEN_YELLOW = (173, 255, 47);
+        GRULLO = (169, 154, 134);
+        GUPPIE_GREEN = (0, 255, 127);
+        HALAY_BE = (102, 56, 84);
+        HAN_BLUE = (68, 108, 207);
+        HAN_PURPLE = (82, 24, 250);
+        HANSA_YELLOW = (233, 214, 107);
+        HARLEQUIN = (63, 255, 0);
+        HARVARD_CRIMSON = (201, 0, 22);
+        HARVEST_GOLD = (218, 145, 0);
+        HEART_GOLD = (128, 128, 0);
+        HELIOTROPE = (223, 115, 255);
+        HOLLYWOOD_CERISE = (244, 0, 161);
+        HONEYDEW = (240, 255, 240);
+        HONOLULU_BLUE = (0, 127, 191);
+        HOOKER_S_GREEN = (73, 121, 107);
+        HOT_MAGENTA = (255, 29, 206);
+        HOT_PINK = (255, 105, 180);
+        HUNTER_GREEN = (53, 94, 59);
+        ICEBERG = (113, 166, 210);
+        ICTERINE = (252, 247, 94);
+        IMPERIAL_BLUE = (0, 35, 149);
+        INCHWORM = (178, 236, 93);
+        INDIA_GREEN = (19, 136, 8);
+        INDIAN_RED = (205, 92, 92);
+        INDIAN_YELLOW = (227, 168, 87);
+        INDIGO = (111, 0, 255);
+        INDIGO_DYE = (0, 65, 106);
+        INDIGO_WEB = (75, 0, 130);
+        INTERNATIONAL_KLEIN_BLUE = (0, 47, 167);
+        INTERNATIONAL_ORANGE_AEROSPACE = (255, 79, 0);
+        INTERNATIONAL_ORANGE_ENGINEERING = (186, 22, 12);
+        INTERNATIONAL_ORANGE_GOLDEN_GATE_BRIDGE = (192, 54, 44);
+        IRIS = (90, 79, 207);
+        ISABELLINE = (244, 240, 236);
+        ISLAMIC_GREEN = (0, 144, 0);
+        IVORY = (255, 255, 240);
+        JADE = (0, 168, 107);
+        JASMINE = (248, 222, 126);
+        JASPER = (215, 59, 62);
+        JAZZBERRY_JAM = (165, 11, 94);
+        JET = (52, 52, 52);
+        JONQUIL = (250, 218, 94);
+        JUNE_BUD = (189, 218, 87);
+        JUNGLE_GREEN = (41, 171, 135);
+        KELLY_GREEN = (76, 187, 23);
+        KENYAN_COPPER = (124, 28, 5);
+        KHAKI_HTML_CSS_KHAKI = (195, 176, 145);
+        KHAKI_X11_LIGHT_KHAKI = (240, 230, 140);
+        KU_CRIMSON = (232, 0, 13);
+        LA_SALLE_GREEN = (8, 120, 48);
+        LANGUID_LAVENDER = (214, 202, 221);
+        LAPIS_LAZULI = (38, 97, 156);
+        LASER_LEMON = (254, 254, 34);
+        LAUREL_GREEN = (169, 186, 157);
+        LAVA = (207, 16, 32);
+        LAVENDER_BLUE = (204, 204, 255);
+        LAVENDER_BLUSH = (255, 240, 245);
+        LAVENDER_FLORAL = (181, 126, 220);
+        LAVENDER_GRAY = (196, 195, 208);
+        LAVENDER_INDIGO = (148, 87, 235);
+        LAVENDER_MAGENTA = (238, 130, 238);
+        LAVENDER_MIST = (230, 230, 250);
+        LAVENDER_PINK = (251, 174, 210);
+        LAVENDER_PURPLE = (150, 123, 182);
+        LAVENDER_ROSE = (251, 160, 227);
+        LAVENDER_WEB = (230, 230, 250);
+        LAWN_GREEN = (124, 252, 0);
+        LEMON = (255, 247, 0);
+        LEMON_CHIFFON = (255, 250, 205);
+        LEMON_LIME = (227, 255, 0);
+        LICORICE = (26, 17, 16);
+        LIGHT_APRICOT = (253, 213, 177);
+        LIGHT_BLUE = (173, 216, 230);
+        LIGHT_BROWN = (181, 101, 29);
+        LIGHT_CARMINE_PINK = (230, 103, 113);
+        LIGHT_CORAL = (240, 128, 128);
+        LIGHT_CORNFLOWER_BLUE = (147, 204, 234);
+        LIGHT_CRIMSON = (245, 105, 145);
+        LIGHT_CYAN = (224, 255, 255);
+        LIGHT_FUCHSIA_PINK = (249, 132, 239);
+        LIGHT_GOLDENROD_YELLOW = (250, 250, 210);
+        LIGHT_GRAY = (211, 211, 211);
+        LIGHT_GREEN = (144, 238, 144);
+        LIGHT_KHAKI = (240, 230, 140);
+        LIGHT_PASTEL_PURPLE = (177, 156, 217);
+        LIGHT_PINK = (255, 182, 193);
+        LIGHT_RED_OCHRE = (233, 116, 81);
+        LIGHT_SALMON = (255, 160, 122);
+        LIGHT_SALMON_PINK = (255, 153, 153);
+        LIGHT_SEA_GREEN = (32, 178, 170);
+        LIGHT_SKY_BLUE = (135, 206, 250);
+        LIGHT_SLATE_GRAY = (119, 136, 153);
+        LIGHT_TAUPE = (179, 139, 109);
+        LIGHT_THULIAN_PINK = (230, 143, 172);
+        LIGHT_YELLOW = (255, 255, 224);
+        LILAC = (200, 162, 200);
+        LIME_COLOR_WHEEL = (191, 255, 0);
+        LIME_GREEN = (50, 205, 50);
+        LIME_WEB_X11_GREEN = (0, 255, 0);
+        LIMERICK = (157, 194, 9);
+        LINCOLN_GREEN = (25, 89, 5);
+        LINEN = (250, 240, 230);
+        LION = (193, 154, 107);
+        LITTLE_BOY_BLUE = (108, 160, 220);
+        LIVER = (83, 75, 79);
+        LUST = (230, 32, 32);
+        MAGENTA = (255, 0, 255);
+        MAGENTA_DYE = (202, 31, 123);
+        MAGENTA_PROCESS = (255, 0, 144);
+        MAGIC_MINT = (170, 240, 209);
+        MAGNOLIA = (248, 244, 255);
+        MAHOGANY = (192, 64, 0);
+        MAIZE = (251, 236, 93);
+        MAJORELLE_BLUE = (96, 80, 220);
+        MALACHITE = (11, 218, 81);
+        MANATEE = (151, 154, 170);
+        MANGO_TANGO = (255, 130, 67);
+        MANTIS = (116, 195, 101);
+        MARDI_GRAS = (136, 0, 133);
+        MAROON_CRAYOLA = (195, 33, 72);
+        MAROON_HTML_CSS = (128, 0, 0);
+        MAROON_X11 = (176, 48, 96);
+        MAUVE = (224, 176, 255);
+        MAUVE_TAUPE = (145, 95, 109);
+        MAUVELOUS = (239, 152, 170);
+        MAYA_BLUE = (115, 194, 251);
+        MEAT_BROWN = (229, 183, 59);
+        MEDIUM_AQUAMARINE = (102, 221, 170);
+        MEDIUM_BLUE = (0, 0, 205);
+        MEDIUM_CANDY_APPLE_RED = (226, 6, 44);
+        MEDIUM_CARMINE = (175, 64, 53);
+        MEDIUM_CHAMPAGNE = (243, 229, 171);
+        MEDIUM_ELECTRIC_BLUE = (3, 80, 150);
+        MEDIUM_JUNGLE_GREEN = (28, 53, 45);
+        MEDIUM_LAVENDER_MAGENTA = (221, 160, 221);
+        MEDIUM_ORCHID = (186, 85, 211);
+        MEDIUM_PERSIAN_BLUE = (0, 103, 165);
+        MEDIUM_PURPLE = (147, 112, 219);
+        MEDIUM_RED_VIOLET = (187, 51, 133);
+        MEDIUM_RUBY = (170, 64, 105);
+        MEDIUM_SEA_GREEN = (60, 179, 113);
+        MEDIUM_SLATE_BLUE = (123, 104, 238);
+        MEDIUM_SPRING_BUD = (201, 220, 135);
+        MEDIUM_SPRING_GREEN = (0, 250, 154);
+        MEDIUM_TAUPE = (103, 76, 71);
+        MEDIUM_TURQUOISE = (72, 209, 204);
+        MEDIUM_TUSCAN_RED = (121, 68, 59);
+        MEDIUM_VERMILION = (217, 96, 59);
+        MEDIUM_VIOLET_RED = (199, 21, 133);
+        MELLOW_APRICOT = (248, 184, 120);
+        MELLOW_YELLOW = (248, 222, 126);
+        MELON = (253, 188, 180);
+        MIDNIGHT_BLUE = (25, 25, 112);
+        MIDNIGHT_GREEN_EAGLE_GREEN = (0, 73, 83);
+        MIKADO_YELLOW = (255, 196, 12);
+        MINT = (62, 180, 137);
+        MINT_CREAM = (245, 255, 250);
+        MINT_GREEN = (152, 255, 152);
+        MISTY_ROSE = (255, 228, 225);
+        MOCCASIN = (250, 235, 215);
+        MODE_BEIGE = (150, 113, 23);
+        MOONSTONE_BLUE = (115, 169, 194);
+        MORDANT_RED19 = (174, 12, 0);
+        MOSS_GREEN = (173, 223, 173);
+        MOUNTAIN_MEADOW = (48, 186, 143);
+        MOUNTBATTEN_PINK = (153, 122, 141);
+        MSU_GREEN = (24, 69, 59);
+        MULBERRY = (197, 75, 140);
+        MUSTARD = (255, 219, 88);
+        MYRTLE = (33, 66, 30);
+        NADESHIKO_PINK = (246, 173, 198);
+        NAPIER_GREEN = (42, 128, 0);
+        NAPLES_YELLOW = (250, 218, 94);
+        NAVAJO_WHITE = (255, 222, 173);
+        NAVY_BLUE = (0, 0, 128);
+        NEON_CARROT = (255, 163, 67);
+        NEON_FUCHSIA = (254, 65, 100);
+        NEON_GREEN = (57, 255, 20);
+        NEW_YORK_PINK = (215, 131, 127);
+        NON_PHOTO_BLUE = (164, 221, 237);
+        NORTH_TEXAS_GREEN = (5, 144, 51);
+        OCEAN_BOAT_BLUE = (0, 119, 190);
+        OCHRE = (204, 119, 34);
+        OFFICE_GREEN = (0, 128, 0);
+        OLD_GOLD = (207, 181, 59);
+        OLD_LACE = (253, 245, 230);
+        OLD_LAVENDER = (121, 104, 120);
+        OLD_MAUVE = (103, 49, 71);
+        OLD_ROSE = (192, 128, 129);
+        OLIVE = (128, 128, 0);
+        OLIVE_DRAB7 = (60, 52, 31);
+        OLIVE_DRAB_WEB_OLIVE_DRAB3 = (107, 142, 35);
+        OLIVINE = (154, 185, 115);
+        ONYX = (53, 56, 57);
+        OPERA_MAUVE = (183, 132, 167);
+        ORANGE_COLOR_WHEEL = (255, 127, 0);
+        ORANGE_PEEL = (255, 159, 0);
+        ORANGE_RED = (255, 69, 0);
+        ORANGE_RYB = (251, 153, 2);
+        ORANGE_WEB_COLOR = (255, 165, 0);
+        ORCHID = (218, 112, 214);
+        OTTER_BROWN = (101, 67, 33);
+        OU_CRIMSON_RED = (153, 0, 0);
+        OUTER_SPACE = (65, 74, 76);
+        OUTRAGEOUS_ORANGE = (255, 110, 74);
+        OXFORD_BLUE = (0, 33, 71);
+        PAKISTAN_GREEN = (0, 102, 0);
+        PALATINATE_BLUE = (39, 59, 226);
+        PALATINATE_PURPLE = (104, 40, 96);
+        PALE_AQUA = (188, 212, 230);
+        PALE_BLUE = (175, 238, 238);
+        PALE_BROWN = (152, 118, 84);
+        PALE_CARMINE = (175, 64, 53);
+        PALE_CERULEAN = (155, 196, 226);
+        PALE_CHESTNUT = (221, 173, 175);
+        PALE_COPPER = (218, 138, 103);
+        PALE_CORNFLOWER_BLUE = (171, 205, 239);
+        PALE_GOLD = (230, 190, 138);
+        PALE_GOLDENROD = (238, 232, 170);
+        PALE_GREEN = (152, 251, 152);
+        PALE_LAVENDER = (220, 208, 255);
+        PALE_MAGENTA = (249, 132, 229);
+        PALE_PINK = (250, 218, 221);
+        PALE_PLUM = (221, 160, 221);
+        PALE_RED_VIOLET = (219, 112, 147);
+        PALE_ROBIN_EGG_BLUE = (150, 222, 209);
+        PALE_SILVER = (201, 192, 187);
+        PALE_SPRING_BUD = (236, 235, 189);
+        PALE_TAUPE = (188, 152, 126);
+        PALE_VIOLET_RED = (219, 112, 147);
+        PANSY_PURPLE = (120, 24, 74);
+        PAPAYA_WHIP = (255, 239, 213);
+        PARIS_GREEN = (80, 200, 120);
+        PASTEL_BLUE = (174, 198, 207);
+        PASTEL_BROWN = (131, 105, 83);
+        PASTEL_GRAY = (207, 207, 196);
+        PASTEL_GREEN = (119, 221, 119);
+        PASTEL_MAGENTA = (244, 154, 194);
+        PASTEL_ORANGE = (255, 179, 71);
+        PASTEL_PINK = (222, 165, 164);
+        PASTEL_PURPLE = (179, 158, 181);
+        PASTEL_RED = (255, 105, 97);
+        PASTEL_VIOLET = (203, 153, 201);
+        PASTEL_YELLOW = (253, 253, 150);
+        PATRIARCH = (128, 0, 128);
+        PAYNE_S_GREY = (83, 104, 120);
+        PEACH = (255, 229, 180);
+        PEACH_CRAYOLA = (255, 203, 164);
+        PEACH_ORANGE = (255, 204, 153);
+        PEACH_PUFF = (255, 218, 185);
+        PEACH_YELLOW = (250, 223, 173);
+        PEAR = (209, 226, 49);
+        PEARL = (234, 224, 200);
+        PEARL_AQUA = (136, 216, 192);
+        PEARLY_PURPLE = (183, 104, 162);
+        PERIDOT = (230, 226, 0);
+        PERIWINKLE = (204, 204, 255);
+        PERSIAN_BLUE = (28, 57, 187);
+        PERSIAN_GREEN = (0, 166, 147);
+        PERSIAN_INDIGO = (50, 18, 122);
+        PERSIAN_ORANGE = (217, 144, 88);
+        PERSIAN_PINK = (247, 127, 190);
+        PERSIAN_PLUM = (112, 28, 28);
+        PERSIAN_RED = (204, 51, 51);
+        PERSIAN_ROSE = (254, 40, 162);
+        PERSIMMON = (236, 88, 0);
+        PERU = (205, 133, 63);
+        PHLOX = (223, 0, 255);
+        PHTHALO_BLUE = (0, 15, 137);
+        PHTHALO_GREEN = (18, 53, 36);
+        PIGGY_PINK = (253, 221, 230);
+        PINE_GREEN = (1, 121, 111);
+        PINK = (255, 192, 203);
+        PINK_LACE = (255, 221, 244);
+        PINK_ORANGE = (255, 153, 102);
+        PINK_PEARL = (231, 172, 207);
+        PINK_SHERBET = (247, 143, 167);
+        PISTACHIO = (147, 197, 114);
+        PLATINUM = (229, 228, 226);
+        PLUM_TRADITIONAL = (142, 69, 133);
+        PLUM_WEB = (221, 160, 221);
+        PORTLAND_ORANGE = (255, 90, 54);
+        POWDER_BLUE_WEB = (176, 224, 230);
+        PRINCETON_ORANGE = (255, 143, 0);
+        PRUNE = (112, 28, 28);
+        PRUSSIAN_BLUE = (0, 49, 83);
+        PSYCHEDELIC_PURPLE = (223, 0, 255);
+        PUCE = (204, 136, 153);
+        PUMPKIN = (255, 117, 24);
+        PURPLE_HEART = (105, 53, 156);
+        PURPLE_HTML_CSS = (128, 0, 128);
+        PURPLE_MOUNTAIN_MAJESTY = (150, 120, 182);
+        PURPLE_MUNSELL = (159, 0, 197);
+        PURPLE_PIZZAZZ = (254, 78, 218);
+        PURPLE_TAUPE = (80, 64, 77);
+        PURPLE_X11 = (160, 32, 240);
+        QUARTZ = (81, 72, 79);
+        RACKLEY = (93, 138, 168);
+        RADICAL_RED = (255, 53, 94);
+        RAJAH = (251, 171, 96);
+        RASPBERRY = (227, 11, 93);
+        RASPBERRY_GLACE = (145, 95, 109);
+        RASPBERRY_PINK = (226, 80, 152);
+        RASPBERRY_ROSE = (179, 68, 108);
+        RAW_UMBER = (130, 102, 68);
+        RAZZLE_DAZZLE_ROSE = (255, 51, 204);
+        RAZZMATAZZ = (227, 37, 107);
+        RED = (255, 0, 0);
+        RED_BROWN = (165, 42, 42);
+        RED_DEVIL = (134, 1, 17);
+        RED_MUNSELL = (242, 0, 60);
+        RED_NCS = (196, 2, 51);
+        RED_ORANGE = (255, 83, 73);
+        RED_PIGMENT = (237, 28, 36);
+        RED_RYB = (254, 39, 18);
+        RED_VIOLET = (199, 21, 133);
+        REDWOOD = (171, 78, 82);
+        REGALIA = (82, 45, 128);
+        RESOLUTION_BLUE = (0, 35, 135);
+        RICH_BLACK = (0, 64, 64);
+        RICH_BRILLIANT_LAVENDER = (241, 167, 254);
+        RICH_CARMINE = (215, 0, 64);
+        RICH_ELECTRIC_BLUE = (8, 146, 208);
+        RICH_LAVENDER = (167, 107, 207);
+        RICH_LILAC = (182, 102, 210);
+        RICH_MAROON = (176, 48, 96);
+        RIFLE_GREEN = (65, 72, 51);
+        ROBIN_EGG_BLUE = (0, 204, 204);
+        ROSE = (255, 0, 127);
+        ROSE_BONBON = (249, 66, 158);
+        ROSE_EBONY = (103, 72, 70);
+        ROSE_GOLD = (183, 110, 121);
+        ROSE_MADDER = (227, 38, 54);
+        ROSE_PINK = (255, 102, 204);
+        ROSE_QUARTZ = (170, 152, 169);
+        ROSE_TAUPE = (144, 93, 93);
+        ROSE_VALE = (171, 78, 82);
+        ROSEWOOD = (101, 0, 11);
+        ROSSO_CORSA = (212, 0, 0);
+        ROSY_BROWN = (188, 143, 143);
+        ROYAL_AZURE = (0, 56, 168);
+        ROYAL_BLUE_TRADITIONAL = (0, 35, 102);
+        ROYAL_BLUE_WEB = (65, 105, 225);
+        ROYAL_FUCHSIA = (202, 44, 146);
+        ROYAL_PURPLE = (120, 81, 169);
+        ROYAL_YELLOW = (250, 218, 94);
+        RUBINE_RED = (209, 0, 86);
+        RUBY = (224, 17, 95);
+        RUBY_RED = (155, 17, 30);
+        RUDDY = (255, 0, 40);
+        RUDDY_BROWN = (187, 101, 40);
+        RUDDY_PINK = (225, 142, 150);
+        RUFOUS = (168, 28, 7);
+        RUSSET = (128, 70, 27);
+        RUST = (183, 65, 14);
+        RUSTY_RED = (218, 44, 67);
+        SACRAMENTO_STATE_GREEN = (0, 86, 63);
+        SADDLE_BROWN = (139, 69, 19);
+        SAFETY_ORANGE_BLAZE_ORANGE = (255, 103, 0);
+        SAFFRON = (244, 196, 48);
+        SALMON = (255, 140, 105);
+        SALMON_PINK = (255, 145, 164);
+        SAND = (194, 178, 128);
+        SAND_DUNE = (150, 113, 23);
+        SANDSTORM = (236, 213, 64);
+        SANDY_BROWN = (244, 164, 96);
+        SANDY_TAUPE = (150, 113, 23);
+        SANGRIA = (146, 0, 10);
+        SAP_GREEN = (80, 125, 42);
+        SAPPHIRE = (15, 82, 186);
+        SAPPHIRE_BLUE = (0, 103, 165);
+        SATIN_SHEEN_GOLD = (203, 161, 53);
+        SCARLET = (255, 36, 0);
+        SCARLET_CRAYOLA = (253, 14, 53);
+        SCHOOL_BUS_YELLOW = (255, 216, 0);
+        SCREAMIN_GREEN = (118, 255, 122);
+        SEA_BLUE = (0, 105, 148);
+        SEA_GREEN = (46, 139, 87);
+        SEAL_BROWN = (50, 20, 20);
+        SEASHELL = (255, 245, 238);
+        SELECTIVE_YELLOW = (255, 186, 0);
+        SEPIA = (112, 66, 20);
+        SHADOW = (138, 121, 93);
+        SHAMROCK_GREEN = (0, 158, 96);
+        SHOCKING_PINK = (252, 15, 192);
+        SHOCKING_PINK_CRAYOLA = (255, 111, 255);
+        SIENNA = (136, 45, 23);
+        SILVER = (192, 192, 192);
+        SINOPIA = (203, 65, 11);
+        SKOBELOFF = (0, 116, 116);
+        SKY_BLUE = (135, 206, 235);
+        SKY_MAGENTA = (207, 113, 175);
+        SLATE_BLUE = (106, 90, 205);
+        SLATE_GRAY = (112, 128, 144);
+        SMALT_DARK_POWDER_BLUE = (0, 51, 153);
+        SMOKEY_TOPAZ = (147, 61, 65);
+        SMOKY_BLACK = (16, 12, 8);
+        SNOW = (255, 250, 250);
+        SPIRO_DISCO_BALL = (15, 192, 252);
+        SPRING_BUD = (167, 252, 0);
+        SPRING_GREEN = (0, 255, 127);
+        ST_PATRICK_S_BLUE = (35, 41, 122);
+        STEEL_BLUE = (70, 130, 180);
+        STIL_DE_GRAIN_YELLOW = (250, 218, 94);
+        STIZZA = (153, 0, 0);
+        STORMCLOUD = (79, 102, 106);
+        STRAW = (228, 217, 111);
+        SUNGLOW = (255, 204, 51);
+        SUNSET = (250, 214, 165);
+        TAN = (210, 180, 140);
+        TANGELO = (249, 77, 0);
+        TANGERINE = (242, 133, 0);
+        TANGERINE_YELLOW = (255, 204, 0);
+        TANGO_PINK = (228, 113, 122);
+        TAUPE = (72, 60, 50);
+        TAUPE_GRAY = (139, 133, 137);
+        TEA_GREEN = (208, 240, 192);
+        TEA_ROSE_ORANGE = (248, 131, 121);
+        TEA_ROSE_ROSE = (244, 194, 194);
+        TEAL = (0, 128, 128);
+        TEAL_BLUE = (54, 117, 136);
+        TEAL_GREEN = (0, 130, 127);
+        TELEMAGENTA = (207, 52, 118);
+        TENN_TAWNY = (205, 87, 0);
+        TERRA_COTTA = (226, 114, 91);
+        THISTLE = (216, 191, 216);
+        THULIAN_PINK = (222, 111, 161);
+        TICKLE_ME_PINK = (252, 137, 172);
+        TIFFANY_BLUE = (10, 186, 181);
+        TIGER_S_EYE = (224, 141, 60);
+        TIMBERWOLF = (219, 215, 210);
+        TITANIUM_YELLOW = (238, 230, 0);
+        TOMATO = (255, 99, 71);
+        TOOLBOX = (116, 108, 192);
+        TOPAZ = (255, 200, 124);
+        TRACTOR_RED = (253, 14, 53);
+        TROLLEY_GREY = (128, 128, 128);
+        TROPICAL_RAIN_FOREST = (0, 117, 94);
+        TRUE_BLUE = (0, 115, 207);
+        TUFTS_BLUE = (65, 125, 193);
+        TUMBLEWEED = (222, 170, 136);
+        TURKISH_ROSE = (181, 114, 129);
+        TURQUOISE = (48, 213, 200);
+        TURQUOISE_BLUE = (0, 255, 239);
+        TURQUOISE_GREEN = (160, 214, 180);
+        TUSCAN_RED = (124, 72, 72);
+        TWILIGHT_LAVENDER = (138, 73, 107);
+        TYRIAN_PURPLE = (102, 2, 60);
+        UA_BLUE = (0, 51, 170);
+        UA_RED = (217, 0, 76);
+        UBE = (136, 120, 195);
+        UCLA_BLUE = (83, 104, 149);
+        UCLA_GOLD = (255, 179, 0);
+        UFO_GREEN = (60, 208, 112);
+        ULTRA_PINK = (255, 111, 255);
+        ULTRAMARINE = (18, 10, 143);
+        ULTRAMARINE_BLUE = (65, 102, 245);
+        UMBER = (99, 81, 71);
+        UNBLEACHED_SILK = (255, 221, 202);
+        UNITED_NATIONS_BLUE = (91, 146, 229);
+        UNIVERSITY_OF_CALIFORNIA_GOLD = (183, 39, 135);
+        UNMELLOW_YELLOW = (255, 255, 102);
+        UP_FOREST_GREEN = (1, 68, 33);
+        UP_MAROON = (123, 17, 19);
+        UPSDELL_RED = (174, 32, 41);
+        UROBILIN = (225, 173, 33);
+        USAFA_BLUE = (0, 79, 152);
+        USC_CARDINAL = (153, 0, 0);
+        USC_GOLD = (255, 204, 0);
+        UTAH_CRIMSON = (211, 0, 63);
+        VANILLA = (243, 229, 171);
+        VEGAS_GOLD = (197, 179, 88);
+        VENETIAN_RED = (200, 8, 21);
+        VERDIGRIS = (67, 179, 174);
+        VERMILION_CINNABAR = (227, 66, 52);
+        VERMILION_PLOCHERE = (217, 96, 59);
+        VERONICA = (160, 32, 240);
+        VIOLET = (143, 0, 255);
+        VIOLET_BLUE = (50, 74, 178);
+        VIOLET_COLOR_WHEEL = (127, 0, 255);
+        VIOLET_RYB = (134, 1, 175);
+        VIOLET_WEB = (238, 130, 238);
+        VIRIDIAN = (64, 130, 109);
+        VIVID_AUBURN = (146, 39, 36);
+        VIVID_BURGUNDY = (159, 29, 53);
+        VIVID_CERISE = (218, 29, 129);
+        VIVID_TANGERINE = (255, 160, 137);
+        VIVID_VIOLET = (159, 0, 255);
+        WARM_BLACK = (0, 66, 66);
+        WATERSPOUT = (164, 244, 249);
+        WENGE = (100, 84, 82);
+        WHEAT = (245, 222, 179);
+        WHITE = (255, 255, 255);
+        WHITE_SMOKE = (245, 245, 245);
+        WILD_BLUE_YONDER = (162, 173, 208);
+        WILD_STRAWBERRY = (255, 67, 164);
+        WILD_WATERMELON = (252, 108, 133);
+        WINE = (114, 47, 55);
+        WINE_DREGS = (103, 49, 71);
+        WISTERIA = (201, 160, 220);
+        WOOD_BROWN = (193, 154, 107);
+        XANADU = (115, 134, 120);
+        YALE_BLUE = (15, 77, 146);
+        YELLOW = (255, 255, 0);
+        YELLOW_GREEN = (154, 205, 50);
+        YELLOW_MUNSELL = (239, 204, 0);
+        YELLOW_NCS = (255, 211, 0);
+        YELLOW_ORANGE = (255, 174, 66);
+        YELLOW_PROCESS = (255, 239, 0);
+        YELLOW_RYB = (254, 254, 51);
+        ZAFFRE = (0, 20, 168);
+        ZINNWALDITE_BROWN = (44, 22, 8);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_round_trip_through_from_str() {
+        for (name, colour) in css_colours::NAMED {
+            assert!(Colour::from_str(name).is_ok_and(|parsed| parsed == *colour), "{} does not parse to itself", name);
+            assert!(Colour::from_name(&name.replace('_', " ").to_lowercase()).is_ok_and(|parsed| parsed == *colour), "{} does not parse with spaces", name);
+        }
+    }
+
+    #[test]
+    fn names_are_unique() {
+        for (i, (name, _)) in css_colours::NAMED.iter().enumerate() {
+            assert!(css_colours::NAMED[i + 1..].iter().all(|(other, _)| other != name), "{} is named twice", name);
+        }
+    }
+
+    #[test]
+    fn functions_parse_with_and_without_alpha() {
+        let orange = Colour::from_rgb(255, 128, 0);
+
+        assert!(Colour::from_str("rgb(255, 128, 0)").is_ok_and(|parsed| parsed == orange));
+        assert!(Colour::from_str("rgba(255 128 0 / 50%)").is_ok_and(|parsed| parsed == orange));
+        assert!(Colour::from_str("RGB(100%, 0%, 0%)").is_ok_and(|parsed| parsed == Colour::from_rgb(255, 0, 0)));
+        assert!(Colour::from_str("hsla(120deg, 100%, 50%, 0.5)").is_ok_and(|parsed| parsed == Colour::from_rgb(0, 255, 0)));
+        assert!(Colour::from_str("#ff8000").is_ok_and(|parsed| parsed == orange));
+    }
+
+    #[test]
+    fn malformed_colours_are_rejected() {
+        let rejected = [
+            "#12", "#12345", "#ggg",
+            "rgb(1,2)", "rgb(1,2,3,4,5)", "rgb(1,2,3,zzz)", "rgb(256,0,0)", "rgb(-1,0,0)", "rgb(101%,0,0)", "rgba(1,2,3,1.5)", "rgb(1,2,3",
+            "hsl(x,1,1)", "hsl(0,150%,50%)", "hsl(0,1,2)", "hsla(0,1,1,200%)",
+            "not a colour", "",
+        ];
+
+        for string in rejected {
+            assert!(matches!(Colour::from_str(string), Err(errors::Error::InvalidColour(_))), "{} was accepted", string);
+        }
+    }
 }
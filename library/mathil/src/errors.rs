@@ -4,15 +4,44 @@ use std::io;
 #[derive(Debug)]
 pub enum Error {
     InvalidDirectory(String),
+    DirectoryCreation(path::PathBuf, io::Error),
     FileCreation(path::PathBuf, io::Error),
     FileWrite(path::PathBuf, io::Error),
-    PngError(path::PathBuf, png::EncodingError)
+    #[cfg(feature = "png")]
+    PngError(path::PathBuf, png::EncodingError),
+    Write(io::Error),
+    #[cfg(feature = "png")]
+    PngEncoding(png::EncodingError),
+    InvalidColour(String),
+    FileRead(path::PathBuf, io::Error),
+    Read(io::Error),
+    InvalidImage(String),
+    UnknownFormat(String),
+}
+
+impl Error {
+    /// Attaches the path of the file being written or read to an error from writing or reading image data.
+    pub (in crate) fn at_path(self, path : path::PathBuf) -> Error {
+        match self {
+            Error::Write(io_err) => Error::FileWrite(path, io_err),
+            Error::Read(io_err) => Error::FileRead(path, io_err),
+            #[cfg(feature = "png")]
+            Error::PngEncoding(png_err) => Error::PngError(path, png_err),
+            other => other,
+        }
+    }
 }
 
 impl ToString for Error {
     fn to_string(&self) -> String {
         match self {
             Error::InvalidDirectory(path) => format!("Provided directory \"{}\" is invalid.", path),
+            Error::DirectoryCreation(path, io_err) => {
+                match &io_err.raw_os_error() {
+                    Some(err) => format!("Could not create directory at \"{:?}\". OS error {}", path, err),
+                    None => format!("Could not create directory at \"{:?}\"", path),
+                }
+            }
             Error::FileCreation(path, io_err) => {
                 match &io_err.raw_os_error() {
                     Some(err) => format!("Could not create file at \"{:?}\". OS error {}", path, err),
@@ -25,7 +54,21 @@ impl ToString for Error {
                     None => format!("Could not write to file at \"{:?}\"", path),
                 }
             }
-            Error::PngError(path, png_err) => format!("PNG encoding error for \"{:?}\": {}", path, png_err.to_string())
+            #[cfg(feature = "png")]
+            Error::PngError(path, png_err) => format!("PNG encoding error for \"{:?}\": {}", path, png_err.to_string()),
+            Error::Write(io_err) => format!("Could not write image data: {}", io_err),
+            #[cfg(feature = "png")]
+            Error::PngEncoding(png_err) => format!("PNG encoding error: {}", png_err),
+            Error::InvalidColour(string) => format!("\"{}\" is not a valid colour. Expected a hex code (#RGB, #RRGGBB or #RRGGBBAA), rgb(...), hsl(...) or a colour name.", string),
+            Error::FileRead(path, io_err) => {
+                match &io_err.raw_os_error() {
+                    Some(err) => format!("Could not read file at \"{:?}\". OS error {}", path, err),
+                    None => format!("Could not read file at \"{:?}\"", path),
+                }
+            }
+            Error::Read(io_err) => format!("Could not read image data: {}", io_err),
+            Error::InvalidImage(message) => format!("Invalid image: {}", message),
+            Error::UnknownFormat(path) => format!("Could not infer an image format for \"{}\". Expected an extension of png, bmp, ppm, pgm, tga or qoi.", path),
         }
     }
 }
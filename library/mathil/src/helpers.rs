@@ -0,0 +1,32 @@
+use crate::maths_objects::Point;
+
+/// Gets the least upper bounding rectangle from a series of points.
+pub (in crate) fn get_bounds(vertices : &Vec<Point>) -> (Point, Point) {
+
+    let mut min_x = vertices[0].x;
+    let mut min_y = vertices[0].y;
+    let mut max_x = vertices[0].x;
+    let mut max_y = vertices[0].y;
+
+    for point in vertices {
+        if point.x < min_x {
+            min_x = point.x;
+        }
+        if point.y < min_y {
+            min_y = point.y;
+        }
+        if point.x > max_x {
+            max_x = point.x;
+        }
+        if point.y > max_y {
+            max_y = point.y;
+        }
+    }
+
+    (Point::new(min_x, min_y), Point::new(max_x, max_y))
+}
+
+/// Linear interpolation of floats.
+pub (in crate) fn lerp_scalar(start : f32, end : f32, parameter : f32) -> f32 {
+    (1.0 - parameter) * start + parameter * end
+}
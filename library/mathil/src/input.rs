@@ -0,0 +1,651 @@
+use crate::{
+    colours::Colour,
+    rendering::Image,
+    errors,
+};
+
+const PNG_SIGNATURE : [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+/// Decodes a PNG or BitMap, detected from the first bytes of the file.
+pub (in crate) fn decode_image(bytes : &[u8]) -> Result<Image, errors::Error> {
+    if bytes.starts_with(&PNG_SIGNATURE) {
+        png::decode_png(bytes)
+    }
+    else if bytes.starts_with(b"BM") {
+        bitmap::decode_bitmap(bytes)
+    }
+    else {
+        Err(errors::Error::InvalidImage(String::from("Unrecognised image format, expected a PNG or BitMap.")))
+    }
+}
+
+/// Creates an invalid image error with the provided message.
+fn invalid(message : &str) -> errors::Error {
+    errors::Error::InvalidImage(String::from(message))
+}
+
+
+pub (in crate) mod bitmap {
+    use super::*;
+
+    /// Reads a little endian u16 at the offset.
+    fn read_u16(bytes : &[u8], offset : usize) -> Result<u16, errors::Error> {
+        bytes
+        .get(offset..offset + 2)
+        .map(|slice| u16::from_le_bytes([slice[0], slice[1]]))
+        .ok_or_else(|| invalid("BitMap header is truncated."))
+    }
+
+    /// Reads a little endian u32 at the offset.
+    fn read_u32(bytes : &[u8], offset : usize) -> Result<u32, errors::Error> {
+        bytes
+        .get(offset..offset + 4)
+        .map(|slice| u32::from_le_bytes([slice[0], slice[1], slice[2], slice[3]]))
+        .ok_or_else(|| invalid("BitMap header is truncated."))
+    }
+
+    /// Decodes an uncompressed 24-bit or 32-bit BitMap, with an alpha channel if it has an alpha mask. Rows may be stored from the bottom up, or from the top down when the height is negative.
+    pub (in crate) fn decode_bitmap(bytes : &[u8]) -> Result<Image, errors::Error> {
+        let data_offset = read_u32(bytes, 10)? as usize;
+        let dib_header_size = read_u32(bytes, 14)?;
+
+        if dib_header_size < 40 {
+            return Err(invalid("Only BitMaps with a BITMAPINFOHEADER or later are supported."));
+        }
+
+        let width = read_u32(bytes, 18)? as i32;
+        let height = read_u32(bytes, 22)? as i32;
+        let bits_per_pixel = read_u16(bytes, 28)?;
+        let compression = read_u32(bytes, 30)?;
+
+        // 32-bit BitMaps may use bit fields, for which only the usual byte aligned masks are supported.
+        let alpha_mask =
+            match (bits_per_pixel, compression) {
+                (24, 0) | (32, 0) => 0,
+                (32, 3) => {
+                    // The masks follow a BITMAPINFOHEADER, or are part of the later headers, in the same place.
+                    let masks = [read_u32(bytes, 54)?, read_u32(bytes, 58)?, read_u32(bytes, 62)?];
+
+                    if masks != [0x00FF_0000, 0x0000_FF00, 0x0000_00FF] {
+                        return Err(invalid("Only BitMaps with BGRA bit fields are supported."));
+                    }
+
+                    if dib_header_size >= 56 { read_u32(bytes, 66)? } else { 0 }
+                }
+                _ => return Err(invalid("Only uncompressed 24-bit and 32-bit BitMaps are supported.")),
+            };
+
+        if width <= 0 || height == 0 {
+            return Err(invalid("BitMap has no pixels."));
+        }
+
+        let width = width.unsigned_abs();
+        let top_down = height < 0;
+        let height = height.unsigned_abs();
+
+        let bytes_per_pixel = usize::from(bits_per_pixel / 8);
+        let row_size = (width as usize * bytes_per_pixel).div_ceil(4) * 4;
+
+        // A corrupt header can claim far more pixel data than could ever be addressed.
+        let data_end =
+            row_size
+            .checked_mul(height as usize)
+            .and_then(|size| size.checked_add(data_offset))
+            .ok_or_else(|| invalid("BitMap is too large."))?;
+
+        let data =
+            bytes
+            .get(data_offset..data_end)
+            .ok_or_else(|| invalid("BitMap pixel data is truncated."))?;
+
+        let mut pixels = Vec::with_capacity(width as usize * height as usize);
+
+        for y in 0..height as usize {
+            let row = if top_down { y } else { height as usize - 1 - y };
+
+            for pixel in data[row * row_size..].chunks(bytes_per_pixel).take(width as usize) {
+                let alpha = if alpha_mask == 0xFF00_0000 { pixel[3] } else { 255 };
+
+                pixels.push((Colour::from_rgb(pixel[2], pixel[1], pixel[0]), alpha));
+            }
+        }
+
+        Ok(Image::new(width, height, pixels))
+    }
+}
+
+
+pub (in crate) mod png {
+    use super::*;
+
+    /// Decodes a PNG using the PNG crate, expanding every colour type to 8-bit colour with alpha.
+    #[cfg(feature = "png")]
+    pub (in crate) fn decode_png(bytes : &[u8]) -> Result<Image, errors::Error> {
+        let mut decoder = ::png::Decoder::new(bytes);
+        decoder.set_transformations(::png::Transformations::EXPAND | ::png::Transformations::STRIP_16);
+
+        let mut reader =
+            decoder
+            .read_info()
+            .map_err(|e| errors::Error::InvalidImage(e.to_string()))?;
+
+        let mut buffer = vec![0; reader.output_buffer_size()];
+
+        let info =
+            reader
+            .next_frame(&mut buffer)
+            .map_err(|e| errors::Error::InvalidImage(e.to_string()))?;
+
+        let channels =
+            match info.color_type {
+                ::png::ColorType::Grayscale => 1,
+                ::png::ColorType::GrayscaleAlpha => 2,
+                ::png::ColorType::Rgb => 3,
+                ::png::ColorType::Rgba => 4,
+                ::png::ColorType::Indexed => return Err(invalid("PNG palette could not be expanded.")),
+            };
+
+        let pixels =
+            buffer[..info.buffer_size()]
+            .chunks(info.line_size)
+            .flat_map(|row| row.chunks(channels).take(info.width as usize))
+            .map(|pixel| {
+                match channels {
+                    1 => (Colour::from_rgb(pixel[0], pixel[0], pixel[0]), 255),
+                    2 => (Colour::from_rgb(pixel[0], pixel[0], pixel[0]), pixel[1]),
+                    3 => (Colour::from_rgb(pixel[0], pixel[1], pixel[2]), 255),
+                    _ => (Colour::from_rgb(pixel[0], pixel[1], pixel[2]), pixel[3]),
+                }
+            })
+            .collect();
+
+        Ok(Image::new(info.width, info.height, pixels))
+    }
+
+    /// Decodes a non-interlaced PNG with the built in decoder, expanding every colour type to 8-bit colour with alpha.
+    #[cfg(not(feature = "png"))]
+    pub (in crate) fn decode_png(bytes : &[u8]) -> Result<Image, errors::Error> {
+        let mut header = None;
+        let mut palette : &[u8] = &[];
+        let mut transparency : &[u8] = &[];
+        let mut compressed = Vec::new();
+
+        let mut offset = PNG_SIGNATURE.len();
+
+        while offset + 8 <= bytes.len() {
+            let length = u32::from_be_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]]) as usize;
+            let chunk_type = &bytes[offset + 4..offset + 8];
+            let data =
+                bytes
+                .get(offset + 8..offset + 8 + length)
+                .ok_or_else(|| invalid("PNG chunk is truncated."))?;
+
+            match chunk_type {
+                b"IHDR" if length >= 13 => header = Some(data),
+                b"PLTE" => palette = data,
+                b"tRNS" => transparency = data,
+                b"IDAT" => compressed.extend_from_slice(data),
+                b"IEND" => break,
+                _ => (),
+            }
+
+            offset += 12 + length;
+        }
+
+        let header = header.ok_or_else(|| invalid("PNG has no header."))?;
+
+        let width = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+        let height = u32::from_be_bytes([header[4], header[5], header[6], header[7]]);
+        let bit_depth = usize::from(header[8]);
+        let colour_type = header[9];
+
+        if header[12] != 0 {
+            return Err(invalid("Interlaced PNGs are not supported without the png feature."));
+        }
+
+        if width == 0 || height == 0 {
+            return Err(invalid("PNG has no pixels."));
+        }
+
+        // Only the bit depths allowed by the PNG specification for each colour type can be sampled.
+        let channels =
+            match (colour_type, bit_depth) {
+                (0, 1 | 2 | 4 | 8 | 16) | (3, 1 | 2 | 4 | 8) => 1,
+                (2, 8 | 16) => 3,
+                (4, 8 | 16) => 2,
+                (6, 8 | 16) => 4,
+                (0 | 2 | 3 | 4 | 6, _) => return Err(invalid("PNG has a bit depth that is not allowed for its colour type.")),
+                _ => return Err(invalid("PNG has an unknown colour type.")),
+            };
+
+        let bits_per_pixel = channels * bit_depth;
+        let row_bytes = (width as usize * bits_per_pixel).div_ceil(8);
+        let bytes_per_pixel = bits_per_pixel.div_ceil(8);
+
+        // Skip the two byte zlib header, and ignore the Adler-32 checksum at the end.
+        let filtered = inflate::decompress(compressed.get(2..).unwrap_or(&[]))?;
+
+        let data_size =
+            (row_bytes + 1)
+            .checked_mul(height as usize)
+            .ok_or_else(|| invalid("PNG is too large."))?;
+
+        if filtered.len() < data_size {
+            return Err(invalid("PNG image data is truncated."));
+        }
+
+        let mut previous = vec![0; row_bytes];
+        let mut row = vec![0; row_bytes];
+        let mut pixels = Vec::with_capacity(width as usize * height as usize);
+
+        for line in filtered.chunks(row_bytes + 1).take(height as usize) {
+            unfilter(line[0], &line[1..], &previous, bytes_per_pixel, &mut row)?;
+
+            for x in 0..width as usize {
+                let sample = |channel : usize| -> usize {
+                    if bit_depth == 16 {
+                        usize::from(row[2 * (x * channels + channel)])
+                    }
+                    else if bit_depth == 8 {
+                        usize::from(row[x * channels + channel])
+                    }
+                    else {
+                        let bit = x * bit_depth;
+                        usize::from(row[bit / 8] >> (8 - bit_depth - bit % 8)) & ((1 << bit_depth) - 1)
+                    }
+                };
+
+                // Scales a greyscale sample of less than 8 bits to a byte.
+                let grey = |value : usize| -> u8 {
+                    if bit_depth >= 8 { value as u8 } else { (value * 255 / ((1 << bit_depth) - 1)) as u8 }
+                };
+
+                let pixel =
+                    match colour_type {
+                        0 => {
+                            let value = grey(sample(0));
+                            (Colour::from_rgb(value, value, value), 255)
+                        }
+                        3 => {
+                            let index = sample(0);
+                            let entry =
+                                palette
+                                .get(3 * index..3 * index + 3)
+                                .ok_or_else(|| invalid("PNG palette index is out of range."))?;
+
+                            (Colour::from_rgb(entry[0], entry[1], entry[2]), transparency.get(index).copied().unwrap_or(255))
+                        }
+                        2 => (Colour::from_rgb(sample(0) as u8, sample(1) as u8, sample(2) as u8), 255),
+                        4 => {
+                            let value = grey(sample(0));
+                            (Colour::from_rgb(value, value, value), sample(1) as u8)
+                        }
+                        _ => (Colour::from_rgb(sample(0) as u8, sample(1) as u8, sample(2) as u8), sample(3) as u8),
+                    };
+
+                pixels.push(pixel);
+            }
+
+            std::mem::swap(&mut previous, &mut row);
+        }
+
+        Ok(Image::new(width, height, pixels))
+    }
+
+    /// Reverses a scanline filter, given the unfiltered previous row.
+    #[cfg(not(feature = "png"))]
+    fn unfilter(filter_type : u8, line : &[u8], previous : &[u8], bytes_per_pixel : usize, output : &mut [u8]) -> Result<(), errors::Error> {
+        for i in 0..line.len() {
+            let left = if i >= bytes_per_pixel { output[i - bytes_per_pixel] } else { 0 };
+            let above = previous[i];
+            let upper_left = if i >= bytes_per_pixel { previous[i - bytes_per_pixel] } else { 0 };
+
+            let predictor =
+                match filter_type {
+                    0 => 0,
+                    1 => left,
+                    2 => above,
+                    3 => ((u16::from(left) + u16::from(above)) / 2) as u8,
+                    4 => {
+                        let estimate = i16::from(left) + i16::from(above) - i16::from(upper_left);
+                        let distance_left = (estimate - i16::from(left)).abs();
+                        let distance_above = (estimate - i16::from(above)).abs();
+                        let distance_upper_left = (estimate - i16::from(upper_left)).abs();
+
+                        if distance_left <= distance_above && distance_left <= distance_upper_left { left }
+                        else if distance_above <= distance_upper_left { above }
+                        else { upper_left }
+                    }
+                    _ => return Err(invalid("PNG has an unknown filter type.")),
+                };
+
+            output[i] = line[i].wrapping_add(predictor);
+        }
+
+        Ok(())
+    }
+
+    /// A DEFLATE decompressor, supporting stored, fixed Huffman and dynamic Huffman blocks.
+    #[cfg(not(feature = "png"))]
+    mod inflate {
+        use super::*;
+
+        const LENGTH_BASES : [u16; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+        const LENGTH_EXTRA_BITS : [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+        const DISTANCE_BASES : [u16; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+        const DISTANCE_EXTRA_BITS : [u8; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+        const CODE_LENGTH_ORDER : [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+
+        /// Reads bits least significant first, as DEFLATE requires.
+        struct BitReader<'a> {
+            bytes : &'a [u8],
+            position : usize,
+            buffer : u64,
+            count : u32,
+        }
+
+        impl<'a> BitReader<'a> {
+            fn new(bytes : &'a [u8]) -> BitReader<'a> {
+                BitReader {
+                    bytes,
+                    position : 0,
+                    buffer : 0,
+                    count : 0,
+                }
+            }
+
+            fn read(&mut self, bits : u32) -> Result<u32, errors::Error> {
+                while self.count < bits {
+                    let byte = *self.bytes.get(self.position).ok_or_else(|| invalid("PNG image data is truncated."))?;
+                    self.buffer |= u64::from(byte) << self.count;
+                    self.position += 1;
+                    self.count += 8;
+                }
+
+                let value = (self.buffer & ((1 << bits) - 1)) as u32;
+                self.buffer >>= bits;
+                self.count -= bits;
+
+                Ok(value)
+            }
+
+            fn align(&mut self) {
+                let remainder = self.count % 8;
+                self.buffer >>= remainder;
+                self.count -= remainder;
+            }
+        }
+
+        /// A canonical Huffman code, stored as the number of codes of each length and the symbols in code order.
+        struct Huffman {
+            counts : [u16; 16],
+            symbols : Vec<u16>,
+        }
+
+        impl Huffman {
+            fn new(lengths : &[u8]) -> Huffman {
+                let mut counts = [0_u16; 16];
+                for length in lengths {
+                    counts[usize::from(*length)] += 1;
+                }
+                counts[0] = 0;
+
+                let mut symbols : Vec<(u8, u16)> =
+                    lengths
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, length)| **length > 0)
+                    .map(|(symbol, length)| (*length, symbol as u16))
+                    .collect();
+                symbols.sort_unstable();
+
+                Huffman {
+                    counts,
+                    symbols : symbols.into_iter().map(|(_, symbol)| symbol).collect(),
+                }
+            }
+
+            /// Decodes a symbol one bit at a time, since codes are stored most significant bit first.
+            fn decode(&self, reader : &mut BitReader) -> Result<u16, errors::Error> {
+                let mut code : i32 = 0;
+                let mut first : i32 = 0;
+                let mut index : i32 = 0;
+
+                for length in 1..16 {
+                    code |= reader.read(1)? as i32;
+                    let count = i32::from(self.counts[length]);
+
+                    if code - first < count {
+                        return Ok(self.symbols[(index + code - first) as usize]);
+                    }
+
+                    index += count;
+                    first = (first + count) << 1;
+                    code <<= 1;
+                }
+
+                Err(invalid("PNG image data has an invalid Huffman code."))
+            }
+        }
+
+        /// Decompresses a raw DEFLATE stream.
+        pub (super) fn decompress(bytes : &[u8]) -> Result<Vec<u8>, errors::Error> {
+            let mut reader = BitReader::new(bytes);
+            let mut output = Vec::with_capacity(bytes.len() * 4);
+
+            loop {
+                let last = reader.read(1)? == 1;
+
+                match reader.read(2)? {
+                    0 => {
+                        reader.align();
+                        let length = reader.read(16)?;
+                        let complement = reader.read(16)?;
+
+                        if length != !complement & 0xFFFF {
+                            return Err(invalid("PNG image data has an invalid stored block."));
+                        }
+
+                        for _ in 0..length {
+                            output.push(reader.read(8)? as u8);
+                        }
+                    }
+                    1 => {
+                        let lengths : Vec<u8> =
+                            (0..288)
+                            .map(|symbol| match symbol { 0..=143 => 8, 144..=255 => 9, 256..=279 => 7, _ => 8 })
+                            .collect();
+
+                        inflate_block(&mut reader, &mut output, &Huffman::new(&lengths), &Huffman::new(&[5; 30]))?;
+                    }
+                    2 => {
+                        let (lengths, distances) = read_dynamic_header(&mut reader)?;
+                        inflate_block(&mut reader, &mut output, &lengths, &distances)?;
+                    }
+                    _ => return Err(invalid("PNG image data has an invalid block type.")),
+                }
+
+                if last {
+                    return Ok(output);
+                }
+            }
+        }
+
+        /// Reads the run length encoded code lengths of a dynamic Huffman block.
+        fn read_dynamic_header(reader : &mut BitReader) -> Result<(Huffman, Huffman), errors::Error> {
+            let literal_count = reader.read(5)? as usize + 257;
+            let distance_count = reader.read(5)? as usize + 1;
+            let code_length_count = reader.read(4)? as usize + 4;
+
+            let mut code_length_lengths = [0_u8; 19];
+            for symbol in &CODE_LENGTH_ORDER[..code_length_count] {
+                code_length_lengths[*symbol] = reader.read(3)? as u8;
+            }
+
+            let code_lengths = Huffman::new(&code_length_lengths);
+            let mut lengths = Vec::with_capacity(literal_count + distance_count);
+
+            while lengths.len() < literal_count + distance_count {
+                let (value, repeat) =
+                    match code_lengths.decode(reader)? {
+                        16 => {
+                            let previous = *lengths.last().ok_or_else(|| invalid("PNG image data repeats a missing code length."))?;
+                            (previous, 3 + reader.read(2)?)
+                        }
+                        17 => (0, 3 + reader.read(3)?),
+                        18 => (0, 11 + reader.read(7)?),
+                        length => (length as u8, 1),
+                    };
+
+                for _ in 0..repeat {
+                    lengths.push(value);
+                }
+            }
+
+            if lengths.len() > literal_count + distance_count {
+                return Err(invalid("PNG image data has too many code lengths."));
+            }
+
+            Ok((Huffman::new(&lengths[..literal_count]), Huffman::new(&lengths[literal_count..])))
+        }
+
+        /// Decodes literals and back references until the end of the block.
+        fn inflate_block(reader : &mut BitReader, output : &mut Vec<u8>, lengths : &Huffman, distances : &Huffman) -> Result<(), errors::Error> {
+            loop {
+                let symbol = usize::from(lengths.decode(reader)?);
+
+                if symbol < 256 {
+                    output.push(symbol as u8);
+                }
+                else if symbol == 256 {
+                    return Ok(());
+                }
+                else {
+                    let code = symbol - 257;
+                    if code >= LENGTH_BASES.len() {
+                        return Err(invalid("PNG image data has an invalid length."));
+                    }
+                    let length = usize::from(LENGTH_BASES[code]) + reader.read(u32::from(LENGTH_EXTRA_BITS[code]))? as usize;
+
+                    let code = usize::from(distances.decode(reader)?);
+                    if code >= DISTANCE_BASES.len() {
+                        return Err(invalid("PNG image data has an invalid distance."));
+                    }
+                    let distance = usize::from(DISTANCE_BASES[code]) + reader.read(u32::from(DISTANCE_EXTRA_BITS[code]))? as usize;
+
+                    if distance > output.len() {
+                        return Err(invalid("PNG image data refers back too far."));
+                    }
+
+                    let start = output.len() - distance;
+                    for i in 0..length {
+                        output.push(output[start + i]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates the headers of an uncompressed BitMap, followed by the provided pixel data.
+    fn bitmap(width : i32, height : i32, bits_per_pixel : u16, data : &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(b"BM");
+        bytes.extend_from_slice(&(54 + data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&54_u32.to_le_bytes());
+        bytes.extend_from_slice(&40_u32.to_le_bytes());
+        bytes.extend_from_slice(&width.to_le_bytes());
+        bytes.extend_from_slice(&height.to_le_bytes());
+        bytes.extend_from_slice(&1_u16.to_le_bytes());
+        bytes.extend_from_slice(&bits_per_pixel.to_le_bytes());
+        bytes.extend_from_slice(&[0; 24]);
+        bytes.extend_from_slice(data);
+
+        bytes
+    }
+
+    /// Creates a PNG with the provided header fields and a single pixel of zeroed image data.
+    #[cfg(not(feature = "png"))]
+    fn png(width : u32, height : u32, bit_depth : u8, colour_type : u8) -> Vec<u8> {
+        fn chunk(bytes : &mut Vec<u8>, chunk_type : &[u8], data : &[u8]) {
+            bytes.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            bytes.extend_from_slice(chunk_type);
+            bytes.extend_from_slice(data);
+            // The built in decoder does not check CRCs.
+            bytes.extend_from_slice(&[0; 4]);
+        }
+
+        let mut header = Vec::new();
+        header.extend_from_slice(&width.to_be_bytes());
+        header.extend_from_slice(&height.to_be_bytes());
+        header.extend_from_slice(&[bit_depth, colour_type, 0, 0, 0]);
+
+        let mut bytes = PNG_SIGNATURE.to_vec();
+        chunk(&mut bytes, b"IHDR", &header);
+        chunk(&mut bytes, b"PLTE", &[0; 3]);
+        // A stored DEFLATE block holding 16 zero bytes, enough for one pixel of any colour type.
+        chunk(&mut bytes, b"IDAT", &[0x78, 0x01, 0x01, 0x10, 0x00, 0xEF, 0xFF, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        chunk(&mut bytes, b"IEND", &[]);
+
+        bytes
+    }
+
+    #[test]
+    fn bitmap_decodes() {
+        let image = decode_image(&bitmap(1, 2, 24, &[1, 2, 3, 0, 4, 5, 6, 0])).unwrap();
+
+        assert_eq!((image.width(), image.height()), (1, 2));
+    }
+
+    #[test]
+    fn bitmap_with_overflowing_size_is_rejected() {
+        assert!(decode_image(&bitmap(i32::MAX, i32::MAX, 32, &[])).is_err());
+        assert!(decode_image(&bitmap(i32::MAX, i32::MIN + 1, 24, &[])).is_err());
+    }
+
+    #[test]
+    fn bitmap_with_malformed_header_is_rejected() {
+        assert!(decode_image(&bitmap(1, 1, 24, &[])[..30]).is_err());
+        assert!(decode_image(&bitmap(1, 1, 24, &[])).is_err());
+        assert!(decode_image(&bitmap(0, 1, 24, &[0; 4])).is_err());
+        assert!(decode_image(&bitmap(1, 1, 16, &[0; 4])).is_err());
+    }
+
+    #[cfg(not(feature = "png"))]
+    #[test]
+    fn png_with_allowed_bit_depths_decodes() {
+        for (colour_type, bit_depths) in [(0, &[1, 2, 4, 8, 16][..]), (2, &[8, 16]), (3, &[1, 2, 4, 8]), (4, &[8, 16]), (6, &[8, 16])] {
+            for bit_depth in bit_depths {
+                let image = decode_image(&png(1, 1, *bit_depth, colour_type));
+
+                assert!(image.is_ok_and(|image| image.width() == 1 && image.height() == 1), "colour type {} with bit depth {}", colour_type, bit_depth);
+            }
+        }
+    }
+
+    #[cfg(not(feature = "png"))]
+    #[test]
+    fn png_with_malformed_header_is_rejected() {
+        for colour_type in [0, 2, 3, 4, 6] {
+            for bit_depth in [0, 3, 5, 6, 7, 9, 12, 15, 32, 255] {
+                assert!(decode_image(&png(1, 1, bit_depth, colour_type)).is_err(), "colour type {} with bit depth {}", colour_type, bit_depth);
+            }
+        }
+
+        for (colour_type, bit_depth) in [(2, 1), (2, 4), (3, 16), (4, 2), (6, 4), (1, 8), (5, 8), (7, 8)] {
+            assert!(decode_image(&png(1, 1, bit_depth, colour_type)).is_err(), "colour type {} with bit depth {}", colour_type, bit_depth);
+        }
+
+        assert!(decode_image(&png(0, 1, 8, 2)).is_err());
+        assert!(decode_image(&png(1, 0, 8, 2)).is_err());
+        assert!(decode_image(&png(u32::MAX, u32::MAX, 16, 6)).is_err());
+        assert!(decode_image(&png(1, 1, 8, 2)[..20]).is_err());
+    }
+}
@@ -1,8 +1,13 @@
+#[cfg(not(any(feature = "png", feature = "native-png")))]
+compile_error!("Either the `png` or `native-png` feature must be enabled to write PNGs.");
+
 pub mod colours;
 pub mod maths_objects;
 mod output;
+mod input;
 pub mod rendering;
 pub mod utilities;
 pub mod animation;
 mod primitive_conversions;
+mod helpers;
 pub mod errors;
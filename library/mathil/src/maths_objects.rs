@@ -1,6 +1,8 @@
 use std::ops;
+use std::f32::consts::TAU;
 
 use crate::primitive_conversions::*;
+use crate::helpers::lerp_scalar;
 
 /// Represents a point.
 #[derive(Copy, Clone, Debug)]
@@ -200,6 +202,50 @@ impl Function {
         )
     }
 
+    /// Creates an arc of a circle from its centre and radius, anticlockwise between two angles in radians.
+    pub fn new_arc(radius : f32, centre : Point, start_angle : f32, end_angle : f32) -> Function {
+        Function::new_circle(
+            radius,
+            centre,
+            (start_angle, end_angle)
+        )
+    }
+
+    /// Creates an arc of an ellipse from its dimensions, anticlockwise between two angles in radians measured from its centre, so the arc ends on the rays at those angles.
+    pub fn new_elliptical_arc(radius_x : f32, radius_y : f32, centre : Point, start_angle : f32, end_angle : f32) -> Function {
+        Function::new(
+            Box::new(
+                move |angle : f32| {
+                    let radius =
+                        radius_x * radius_y / ((radius_y * angle.cos()).powi(2) + (radius_x * angle.sin()).powi(2)).sqrt();
+
+                    Point::new(
+                        radius * angle.cos() + centre.x,
+                        radius * angle.sin() + centre.y
+                    )
+                }
+            ),
+            (start_angle, end_angle)
+        )
+    }
+
+    /// Creates a superellipse from its dimensions and exponent, where an exponent of 2 gives an ellipse, larger exponents approach a rectangle and smaller ones pinch in towards the axes.
+    pub fn new_superellipse(radius_x : f32, radius_y : f32, exponent : f32, centre : Point) -> Function {
+        Function::new(
+            Box::new(
+                move |t : f32| {
+                    let power = |value : f32| value.signum() * value.abs().powf(2.0 / exponent);
+
+                    Point::new(
+                        radius_x * power(t.cos()) + centre.x,
+                        radius_y * power(t.sin()) + centre.y
+                    )
+                }
+            ),
+            (0.0, TAU)
+        )
+    }
+
     /// Creates a Bezier curve from a vector of points.
     pub fn new_bezier_curve(points: Vec<Point>, domain : (f32, f32)) -> Function {
 
@@ -253,14 +299,17 @@ impl Function {
 
     /// Samples a function the specified number of times.
     pub fn sample(&self, number_of_samples : u16) -> Vec<Point> {
+        self.sample_with_parameters(number_of_samples)
+        .into_iter()
+        .map(|(_, point)| point)
+        .collect()
+    }
 
-        fn lerp_scalar(start : f32, end : f32, parameter : f32) -> f32 {
-            (1.0 - parameter) * start + parameter * end
-        }
-        
+    /// Samples a function the specified number of times, pairing each sample with the parameter it was sampled at.
+    pub fn sample_with_parameters(&self, number_of_samples : u16) -> Vec<(f32, Point)> {
         let (start, finish) = self.domain;
 
-        let mut samples = Vec::with_capacity(usize::try_from(number_of_samples).unwrap());
+        let mut samples = Vec::with_capacity(usize::from(number_of_samples));
 
         for sample_number in 0..number_of_samples {
             let parameter =
@@ -268,11 +317,32 @@ impl Function {
 
             let sample = lerp_scalar(start, finish, parameter);
 
-            samples.push((self.rule)(sample));
+            samples.push((sample, (self.rule)(sample)));
         }
 
         samples
     }
+
+    /// Calculates the proportion of the total arc length from 0 to 1 travelled by each of a sequence of samples, approximating the curve as straight lines between them.
+    pub fn arc_length_fractions(samples : &[Point]) -> Vec<f32> {
+        let mut lengths = Vec::with_capacity(samples.len());
+        let mut total = 0.0;
+
+        for (i, sample) in samples.iter().enumerate() {
+            if i > 0 {
+                total += (*sample - samples[i - 1]).distance();
+            }
+            lengths.push(total);
+        }
+
+        if total > 0.0 {
+            for length in lengths.iter_mut() {
+                *length /= total;
+            }
+        }
+
+        lengths
+    }
 }
 
 /// Represents a dashed line as a vector of line segments.
@@ -331,57 +401,305 @@ impl Polygon {
             edges,
         }
     }
+
+    /// Creates a rectangle with sides parallel to the axes from two opposite corners.
+    pub fn new_rectangle(corner : Point, opposite_corner : Point) -> Polygon {
+        let (left, right) = (corner.x.min(opposite_corner.x), corner.x.max(opposite_corner.x));
+        let (bottom, top) = (corner.y.min(opposite_corner.y), corner.y.max(opposite_corner.y));
+
+        Polygon::new(
+            vec![
+                Point::new(left, bottom),
+                Point::new(right, bottom),
+                Point::new(right, top),
+                Point::new(left, top),
+            ]
+        )
+    }
+
+    /// Creates a rectangle from its centre, width and height, rotated anticlockwise about its centre by an angle in radians.
+    pub fn new_rotated_rectangle(centre : Point, width : f32, height : f32, angle : f32) -> Polygon {
+        let along = Point::new(angle.cos(), angle.sin());
+        let across = Point::new(- angle.sin(), angle.cos());
+
+        Polygon::new(
+            [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+            .into_iter()
+            .map(|(x, y)| centre + (x * width / 2.0) * along + (y * height / 2.0) * across)
+            .collect()
+        )
+    }
+
+    /// Creates a rectangle with sides parallel to the axes from two opposite corners, with its corners rounded off by quarter circles of the radius, each made of the provided number of vertices. The radius is limited to half of the shorter side.
+    pub fn new_rounded_rectangle(corner : Point, opposite_corner : Point, radius : f32, vertices_per_corner : u16) -> Polygon {
+        let (left, right) = (corner.x.min(opposite_corner.x), corner.x.max(opposite_corner.x));
+        let (bottom, top) = (corner.y.min(opposite_corner.y), corner.y.max(opposite_corner.y));
+
+        let radius = radius.clamp(0.0, (right - left).min(top - bottom) / 2.0);
+        let vertices_per_corner = vertices_per_corner.max(2);
+
+        // Centres of the corners anticlockwise from the bottom right, each with the angle its quarter circle starts at.
+        let corners = [
+            (Point::new(right - radius, bottom + radius), - TAU / 4.0),
+            (Point::new(right - radius, top - radius), 0.0),
+            (Point::new(left + radius, top - radius), TAU / 4.0),
+            (Point::new(left + radius, bottom + radius), TAU / 2.0),
+        ];
+
+        let mut vertices = Vec::with_capacity(4 * usize::from(vertices_per_corner));
+
+        for (centre, start_angle) in corners {
+            vertices.extend(
+                Function::new_arc(radius, centre, start_angle, start_angle + TAU / 4.0).sample(vertices_per_corner)
+            );
+        }
+
+        Polygon::new(vertices)
+    }
+
+    /// Creates a regular polygon with the number of sides and the distance from its centre to each vertex, with the first vertex at an angle in radians anticlockwise from the positive x direction.
+    pub fn new_regular(sides : u16, centre : Point, radius : f32, rotation : f32) -> Polygon {
+        let sides = sides.max(3);
+
+        Polygon::new(
+            (0..sides)
+            .map(|side| {
+                let angle = rotation + TAU * f32::from(side) / f32::from(sides);
+
+                centre + radius * Point::new(angle.cos(), angle.sin())
+            })
+            .collect()
+        )
+    }
+
+    /// Creates a star with the number of points, alternating between the outer radius at its points and the inner radius between them, with the first point at an angle in radians anticlockwise from the positive x direction.
+    pub fn new_star(points : u16, centre : Point, outer_radius : f32, inner_radius : f32, rotation : f32) -> Polygon {
+        // Counted as u32, since twice the number of points can be more than a u16 can hold.
+        let vertices = 2 * u32::from(points.max(2));
+
+        Polygon::new(
+            (0..vertices)
+            .map(|vertex| {
+                let angle = rotation + TAU * u32_to_f32(vertex) / u32_to_f32(vertices);
+                let radius = if vertex % 2 == 0 { outer_radius } else { inner_radius };
+
+                centre + radius * Point::new(angle.cos(), angle.sin())
+            })
+            .collect()
+        )
+    }
+
+    /// Creates a star polygon by joining every step-th of the vertices of a regular polygon, such as a pentagram with 5 vertices and a step of 2. When the vertices and step share a factor, only the first of the overlapping polygons it makes is created.
+    pub fn new_star_polygon(vertices : u16, step : u16, centre : Point, radius : f32, rotation : f32) -> Polygon {
+        // Counted as u32, so that stepping past the last vertex cannot overflow.
+        let vertices = u32::from(vertices.max(3));
+        let step = (u32::from(step) % vertices).max(1);
+
+        let mut indices = vec![0];
+
+        while let Some(last) = indices.last() {
+            let next = (last + step) % vertices;
+
+            if next == 0 {
+                break;
+            }
+
+            indices.push(next);
+        }
+
+        Polygon::new(
+            indices
+            .into_iter()
+            .map(|index| {
+                let angle = rotation + TAU * u32_to_f32(index) / u32_to_f32(vertices);
+
+                centre + radius * Point::new(angle.cos(), angle.sin())
+            })
+            .collect()
+        )
+    }
+
+    /// Creates the region between two circles with the same centre and two angles in radians, made of the provided number of vertices along each arc. An inner radius of 0 gives a sector of a circle.
+    pub fn new_annular_sector(centre : Point, inner_radius : f32, outer_radius : f32, start_angle : f32, end_angle : f32, vertices_per_arc : u16) -> Polygon {
+        let vertices_per_arc = vertices_per_arc.max(2);
+
+        let mut vertices =
+            Function::new_arc(outer_radius, centre, start_angle, end_angle).sample(vertices_per_arc);
+
+        if inner_radius > 0.0 {
+            vertices.extend(
+                Function::new_arc(inner_radius, centre, end_angle, start_angle).sample(vertices_per_arc)
+            );
+        }
+        else {
+            vertices.push(centre);
+        }
+
+        Polygon::new(vertices)
+    }
 }
 
 // /// Represents a vector as a line segment and polygon.
 pub struct Vector {
     pub (in crate) line : Option<Function>,
-    pub (in crate) arrow_head : Polygon,
+    pub (in crate) arrow_heads : Vec<Polygon>,
+    pub (in crate) arrow_lines : Vec<Function>,
 }
 
 impl Vector {
     /// Creates a new Vector.
     pub fn new(head : Point, tail : Point, arrow_width : f32, arrow_height : f32) -> Vector {
-        let desired_vector =
-            head - tail;
+        Vector::with_arrow_head(head, tail, ArrowHead::new(ArrowHeadShape::Triangle, arrow_width, arrow_height, ArrowHeadPlacement::End))
+    }
+
+    /// Creates a new Vector with the provided style of arrowhead.
+    pub fn with_arrow_head(head : Point, tail : Point, arrow_head : ArrowHead) -> Vector {
         let vector_length =
-            Point::distance(&desired_vector);
+            Point::distance(&(head - tail));
 
-        let extension_factor_width =
-            (arrow_width / 2.0) / vector_length;
-        let extension_factor_length =
-            arrow_height / vector_length;
+        let mut arrow_heads = Vec::new();
+        let mut arrow_lines = Vec::new();
 
-        let head_adjustment_factor =
-            arrow_height / vector_length;
+        // The line stops short of the arrowheads at either end, as fractions of its length.
+        let mut head_adjustment_factor = 0.0;
+        let mut tail_adjustment_factor = 0.0;
 
-        let adjusted_head =
-            Point::lerp(
-                head,
-                tail,
-                head_adjustment_factor
-            );
+        let mut add_arrow_head = |tip : Point, from : Point| {
+            let (polygons, lines) = arrow_head.parts(tip, from);
 
-        let arrow_head =
-            Polygon::new(
-                vec![
-                    adjusted_head + extension_factor_length * desired_vector,
-                    adjusted_head + extension_factor_width * Point::rotate_counter_clockwise(&desired_vector),
-                    adjusted_head + extension_factor_width * Point::rotate_clockwise(&desired_vector),
-                ]
-            );
+            arrow_heads.extend(polygons);
+            arrow_lines.extend(lines);
+        };
 
-        if head_adjustment_factor > 1.0 {
-            Vector {
-                line : None,
-                arrow_head
+        match arrow_head.placement {
+            ArrowHeadPlacement::End | ArrowHeadPlacement::Both => {
+                add_arrow_head(head, tail);
+                head_adjustment_factor = arrow_head.setback() / vector_length;
             }
+            _ => {}
         }
-        else {
-            Vector {
-                line : Some(Function::new_line_segment(adjusted_head, tail, (0.0, 1.0))),
-                arrow_head,
+
+        match arrow_head.placement {
+            ArrowHeadPlacement::Start | ArrowHeadPlacement::Both => {
+                add_arrow_head(tail, head);
+                tail_adjustment_factor = arrow_head.setback() / vector_length;
+            }
+            ArrowHeadPlacement::Middle => {
+                add_arrow_head(Point::lerp(tail, head, 0.5 + 0.5 * arrow_head.length / vector_length), tail);
             }
+            _ => {}
+        }
+
+        let line =
+            if head_adjustment_factor + tail_adjustment_factor > 1.0 {
+                None
+            }
+            else {
+                Some(Function::new_line_segment(Point::lerp(head, tail, head_adjustment_factor), Point::lerp(tail, head, tail_adjustment_factor), (0.0, 1.0)))
+            };
+
+        Vector {
+            line,
+            arrow_heads,
+            arrow_lines,
+        }
+    }
+}
+
+/// Possible shapes of arrowhead, either a filled triangle, two open lines, a triangle with barbs swept back past where the line meets it, a stealth head with a notch cut into its back, or a filled circle.
+#[derive(Copy, Clone)]
+pub enum ArrowHeadShape {
+    Triangle,
+    Open,
+    Barbed,
+    Stealth,
+    Circle,
+}
+
+/// Possible places for the arrowheads on a line, either at the end, at the start pointing backwards, at both ends, or in the middle pointing along it.
+#[derive(Copy, Clone)]
+pub enum ArrowHeadPlacement {
+    End,
+    Start,
+    Both,
+    Middle,
+}
+
+/// Represents the style of the arrowheads on a line, with their shape, their width and length in the coordinate system, and where they are placed.
+#[derive(Copy, Clone)]
+pub struct ArrowHead {
+    pub (in crate) shape : ArrowHeadShape,
+    pub (in crate) width : f32,
+    pub (in crate) length : f32,
+    pub (in crate) placement : ArrowHeadPlacement,
+}
+
+impl ArrowHead {
+    /// Creates a new ArrowHead.
+    pub fn new(shape : ArrowHeadShape, width : f32, length : f32, placement : ArrowHeadPlacement) -> ArrowHead {
+        ArrowHead {
+            shape,
+            width,
+            length,
+            placement,
+        }
+    }
+
+    /// Gets how far back from its tip the line meets the arrowhead.
+    pub (in crate) fn setback(&self) -> f32 {
+        match self.shape {
+            ArrowHeadShape::Triangle => self.length,
+            ArrowHeadShape::Open => 0.0,
+            ArrowHeadShape::Barbed | ArrowHeadShape::Stealth => 0.6 * self.length,
+            ArrowHeadShape::Circle => self.width,
+        }
+    }
+
+    /// Creates the filled polygons and open lines of an arrowhead with its tip at the provided point, pointing away from the other point.
+    pub (in crate) fn parts(&self, tip : Point, from : Point) -> (Vec<Polygon>, Vec<Function>) {
+        let vector = tip - from;
+        let vector_length = Point::distance(&vector);
+
+        // Points the distance back from the tip along the line, and offsets the distance across it.
+        let along = |distance : f32| Point::lerp(tip, from, distance / vector_length);
+        let across = |distance : f32| (distance / vector_length) * Point::rotate_counter_clockwise(&vector);
+
+        let base = along(self.length);
+
+        match self.shape {
+            ArrowHeadShape::Triangle => (
+                vec![Polygon::new(vec![tip, base + across(self.width / 2.0), base - across(self.width / 2.0)])],
+                Vec::new()
+            ),
+            ArrowHeadShape::Open => (
+                Vec::new(),
+                vec![
+                    Function::new_line_segment(base + across(self.width / 2.0), tip, (0.0, 1.0)),
+                    Function::new_line_segment(base - across(self.width / 2.0), tip, (0.0, 1.0)),
+                ]
+            ),
+            ArrowHeadShape::Barbed => {
+                let neck = along(0.6 * self.length);
+
+                (
+                    vec![Polygon::new(vec![
+                        tip,
+                        base + across(self.width / 2.0),
+                        neck + across(self.width / 8.0),
+                        neck - across(self.width / 8.0),
+                        base - across(self.width / 2.0),
+                    ])],
+                    Vec::new()
+                )
+            }
+            ArrowHeadShape::Stealth => (
+                vec![Polygon::new(vec![tip, base + across(self.width / 2.0), along(0.6 * self.length), base - across(self.width / 2.0)])],
+                Vec::new()
+            ),
+            ArrowHeadShape::Circle => (
+                vec![Polygon::new(Function::new_circle(self.width / 2.0, along(self.width / 2.0), (0.0, std::f32::consts::TAU)).sample(48))],
+                Vec::new()
+            ),
         }
     }
 }
@@ -391,8 +709,7 @@ pub struct CartesianPlane {
     pub (in crate) bottom_left_bound : Point,
     pub (in crate) top_right_bound : Point,
     pub (in crate) origin : Point,
-    pub (in crate) arrow_width : f32,
-    pub (in crate) arrow_height : f32,
+    pub (in crate) arrow_head : ArrowHead,
     //pub (in crate) axis : Vec<Vector>,
 }
 
@@ -403,8 +720,121 @@ impl CartesianPlane {
             bottom_left_bound,
             top_right_bound,
             origin,
-            arrow_width,
-            arrow_height,
+            arrow_head : ArrowHead::new(ArrowHeadShape::Triangle, arrow_width, arrow_height, ArrowHeadPlacement::End),
         }
     }
+
+    /// Gives the axes the provided style of arrowhead. Each axis is drawn outwards from the origin, so End places an arrowhead at the outer end of every axis, Start places one at the origin end of every axis, pointing in to the origin, Both places one at each end, and Middle places one halfway along every axis, pointing outwards.
+    pub fn with_arrow_head(mut self, arrow_head : ArrowHead) -> CartesianPlane {
+        self.arrow_head = arrow_head;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    /// Checks that the points are the same, allowing for rounding in the trigonometry.
+    fn assert_points(actual : &[Point], expected : &[(f32, f32)]) {
+        assert_eq!(actual.len(), expected.len());
+
+        for (point, (x, y)) in actual.iter().zip(expected) {
+            assert!((point.x - x).abs() < 1e-5 && (point.y - y).abs() < 1e-5, "{:?} is not ({}, {})", point, x, y);
+        }
+    }
+
+    #[test]
+    fn rectangle_corners() {
+        let rectangle = Polygon::new_rectangle(Point::new(3.0, 1.0), Point::new(-1.0, 4.0));
+
+        assert_points(&rectangle.vertices, &[(-1.0, 1.0), (3.0, 1.0), (3.0, 4.0), (-1.0, 4.0)]);
+    }
+
+    #[test]
+    fn regular_square_corners() {
+        let square = Polygon::new_regular(4, Point::new(1.0, 2.0), 2.0, PI / 4.0);
+        let offset = 2.0_f32.sqrt();
+
+        assert_points(
+            &square.vertices,
+            &[(1.0 + offset, 2.0 + offset), (1.0 - offset, 2.0 + offset), (1.0 - offset, 2.0 - offset), (1.0 + offset, 2.0 - offset)]
+        );
+    }
+
+    #[test]
+    fn rotated_rectangle_corners() {
+        let rectangle = Polygon::new_rotated_rectangle(Point::new(0.0, 0.0), 4.0, 2.0, PI / 2.0);
+
+        assert_points(&rectangle.vertices, &[(1.0, -2.0), (1.0, 2.0), (-1.0, 2.0), (-1.0, -2.0)]);
+    }
+
+    #[test]
+    fn pentagram_order() {
+        let pentagram = Polygon::new_star_polygon(5, 2, Point::new(0.0, 0.0), 1.0, 0.0);
+        let vertex = |index : f32| {
+            let angle = TAU * index / 5.0;
+            (angle.cos(), angle.sin())
+        };
+
+        assert_points(&pentagram.vertices, &[vertex(0.0), vertex(2.0), vertex(4.0), vertex(1.0), vertex(3.0)]);
+    }
+
+    #[test]
+    fn star_polygon_with_shared_factor_is_one_polygon() {
+        let triangle = Polygon::new_star_polygon(6, 2, Point::new(0.0, 0.0), 1.0, 0.0);
+        let vertex = |index : f32| {
+            let angle = TAU * index / 6.0;
+            (angle.cos(), angle.sin())
+        };
+
+        assert_points(&triangle.vertices, &[vertex(0.0), vertex(2.0), vertex(4.0)]);
+    }
+
+    #[test]
+    fn star_alternates_radii() {
+        let star = Polygon::new_star(4, Point::new(0.0, 0.0), 2.0, 1.0, 0.0);
+        let diagonal = 0.5_f32.sqrt();
+
+        assert_points(
+            &star.vertices,
+            &[(2.0, 0.0), (diagonal, diagonal), (0.0, 2.0), (- diagonal, diagonal), (-2.0, 0.0), (- diagonal, - diagonal), (0.0, -2.0), (diagonal, - diagonal)]
+        );
+    }
+
+    #[test]
+    fn stars_with_many_vertices_do_not_overflow() {
+        assert_eq!(Polygon::new_star(u16::MAX, Point::new(0.0, 0.0), 2.0, 1.0, 0.0).vertices.len(), 2 * usize::from(u16::MAX));
+        assert_eq!(Polygon::new_star_polygon(u16::MAX, 40000, Point::new(0.0, 0.0), 1.0, 0.0).vertices.len(), 13107);
+        assert_eq!(Polygon::new_star_polygon(40000, 39999, Point::new(0.0, 0.0), 1.0, 0.0).vertices.len(), 40000);
+    }
+
+    #[test]
+    fn arc_endpoints_and_domain() {
+        let arc = Function::new_arc(2.0, Point::new(1.0, 1.0), 0.0, PI / 2.0);
+
+        assert_eq!(arc.domain, (0.0, PI / 2.0));
+        assert_points(&arc.sample(3), &[(3.0, 1.0), (1.0 + 2.0_f32.sqrt(), 1.0 + 2.0_f32.sqrt()), (1.0, 3.0)]);
+    }
+
+    #[test]
+    fn elliptical_arc_ends_on_its_rays() {
+        let arc = Function::new_elliptical_arc(2.0, 1.0, Point::new(0.0, 0.0), 0.0, PI / 4.0);
+        let end = (2.0 / 5.0_f32.sqrt(), 2.0 / 5.0_f32.sqrt());
+
+        assert_eq!(arc.domain, (0.0, PI / 4.0));
+        assert_points(&arc.sample(2), &[(2.0, 0.0), end]);
+    }
+
+    #[test]
+    fn annular_sector_vertices() {
+        let sector = Polygon::new_annular_sector(Point::new(0.0, 0.0), 1.0, 2.0, 0.0, PI / 2.0, 2);
+
+        assert_points(&sector.vertices, &[(2.0, 0.0), (0.0, 2.0), (0.0, 1.0), (1.0, 0.0)]);
+
+        let sector = Polygon::new_annular_sector(Point::new(0.0, 0.0), 0.0, 2.0, 0.0, PI / 2.0, 2);
+
+        assert_points(&sector.vertices, &[(2.0, 0.0), (0.0, 2.0), (0.0, 0.0)]);
+    }
 }
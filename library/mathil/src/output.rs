@@ -97,9 +97,8 @@ pub (in crate) mod bitmap {
 
         let padding = screen.horizontal_resolution % 4;
 
-        for i in 0..screen.vertical_resolution {
-            for j in 0..screen.horizontal_resolution {
-                let colour = &(screen.pixels)[j as usize][i as usize];
+        for i in 0..usize::from(screen.vertical_resolution) {
+            for colour in screen.row(i) {
                 bitmap_bytes.push(colour.blue);
                 bitmap_bytes.push(colour.green);
                 bitmap_bytes.push(colour.red);
@@ -112,30 +111,1656 @@ pub (in crate) mod bitmap {
 
         bitmap_bytes
     }
+
+    /// Creates a 32-bit BitMap file with an alpha mask from the screen, using a BITMAPV4HEADER so that the masks and sRGB colour space are stored.
+    pub (in crate) fn create_bitmap_32_bytes(screen : &Screen) -> Vec<u8> {
+        let header_size = 14 + 108;
+        let file_size =
+            header_size + 4 * u32::from(screen.horizontal_resolution) * u32::from(screen.vertical_resolution);
+
+        let mut bitmap_bytes : Vec<u8> = Vec::with_capacity(file_size as usize);
+
+        bitmap_bytes.push(b'B');
+        bitmap_bytes.push(b'M');
+        add_bytes_of_u32(&mut bitmap_bytes, file_size);
+        add_bytes_of_u32(&mut bitmap_bytes, 0);
+        add_bytes_of_u32(&mut bitmap_bytes, header_size);
+
+        add_bytes_of_u32(&mut bitmap_bytes, 108);
+        add_bytes_of_u32(&mut bitmap_bytes, u32::from(screen.horizontal_resolution));
+        add_bytes_of_u32(&mut bitmap_bytes, u32::from(screen.vertical_resolution));
+
+        for item in &[
+                1, 0, // Colour Planes
+                32, 0, // Bits Per Pixel
+                3, 0, 0, 0, // Bit Field Compression
+            ] {
+            bitmap_bytes.push(*item);
+        }
+
+        add_bytes_of_u32(&mut bitmap_bytes, file_size - header_size);
+        add_bytes_of_u32(&mut bitmap_bytes, 4_000);
+        add_bytes_of_u32(&mut bitmap_bytes, 4_000);
+        add_bytes_of_u32(&mut bitmap_bytes, 0);
+        add_bytes_of_u32(&mut bitmap_bytes, 0);
+
+        // Red, green, blue and alpha masks
+        for mask in [0x00FF_0000, 0x0000_FF00, 0x0000_00FF, 0xFF00_0000] {
+            add_bytes_of_u32(&mut bitmap_bytes, mask);
+        }
+
+        // sRGB colour space, with the unused endpoints and gamma
+        bitmap_bytes.extend_from_slice(b"BGRs");
+        bitmap_bytes.extend_from_slice(&[0; 48]);
+
+        for colour in screen.pixels() {
+            bitmap_bytes.push(colour.blue);
+            bitmap_bytes.push(colour.green);
+            bitmap_bytes.push(colour.red);
+            bitmap_bytes.push(255);
+        }
+
+        bitmap_bytes
+    }
+}
+
+
+pub (in crate) mod netpbm {
+    use crate::rendering::*;
+    use crate::colours::linear_to_byte;
+
+    /// Creates a binary PPM file from the screen.
+    pub (in crate) fn create_ppm_bytes(screen : &Screen) -> Vec<u8> {
+        let mut bytes =
+            format!("P6\n{} {}\n255\n", screen.horizontal_resolution, screen.vertical_resolution).into_bytes();
+
+        for colour in screen.rows_from_top().flatten() {
+            bytes.push(colour.red);
+            bytes.push(colour.green);
+            bytes.push(colour.blue);
+        }
+
+        bytes
+    }
+
+    /// Creates a binary PGM file from the screen, with the relative luminance of each pixel.
+    pub (in crate) fn create_pgm_bytes(screen : &Screen) -> Vec<u8> {
+        let mut bytes =
+            format!("P5\n{} {}\n255\n", screen.horizontal_resolution, screen.vertical_resolution).into_bytes();
+
+        for colour in screen.rows_from_top().flatten() {
+            bytes.push(linear_to_byte(colour.luminance()));
+        }
+
+        bytes
+    }
+}
+
+
+pub (in crate) mod tga {
+    use crate::rendering::*;
+
+    /// Creates a run length encoded 24-bit TGA file from the screen, with rows stored from the bottom up.
+    pub (in crate) fn create_tga_bytes(screen : &Screen) -> Vec<u8> {
+        let mut bytes = vec![
+            0, // No Image ID
+            0, // No Colour Map
+            10, // Run Length Encoded True Colour
+            0, 0, 0, 0, 0, // Colour Map Specification
+            0, 0, 0, 0, // Origin
+        ];
+
+        bytes.extend_from_slice(&screen.horizontal_resolution.to_le_bytes());
+        bytes.extend_from_slice(&screen.vertical_resolution.to_le_bytes());
+        bytes.push(24);
+        bytes.push(0); // Bottom left origin, no alpha bits
+
+        // Packets may not cross rows.
+        for y in 0..usize::from(screen.vertical_resolution) {
+            let row = screen.row(y);
+
+            let mut x = 0;
+
+            while x < row.len() {
+                let run =
+                    row[x..]
+                    .iter()
+                    .take(128)
+                    .take_while(|colour| **colour == row[x])
+                    .count();
+
+                if run > 1 {
+                    bytes.push(0x80 | (run - 1) as u8);
+                    bytes.extend_from_slice(&[row[x].blue, row[x].green, row[x].red]);
+                    x += run;
+                }
+                else {
+                    // Gather literal pixels until the next run of at least two.
+                    let mut count = 1;
+                    while x + count < row.len() && count < 128 && row[x + count] != row[x + count - 1] {
+                        count += 1;
+                    }
+                    if x + count < row.len() && count > 1 && row[x + count] == row[x + count - 1] {
+                        count -= 1;
+                    }
+
+                    bytes.push((count - 1) as u8);
+                    for colour in &row[x..x + count] {
+                        bytes.extend_from_slice(&[colour.blue, colour.green, colour.red]);
+                    }
+                    x += count;
+                }
+            }
+        }
+
+        // TGA 2.0 footer with no extension or developer areas
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(b"TRUEVISION-XFILE.\0");
+
+        bytes
+    }
+}
+
+
+pub (in crate) mod qoi {
+    use crate::rendering::*;
+    use crate::colours::Colour;
+
+    /// Creates a QOI file from the screen, with 3 channels in the sRGB colour space.
+    pub (in crate) fn create_qoi_bytes(screen : &Screen) -> Vec<u8> {
+        let mut bytes = Vec::from(*b"qoif");
+        bytes.extend_from_slice(&u32::from(screen.horizontal_resolution).to_be_bytes());
+        bytes.extend_from_slice(&u32::from(screen.vertical_resolution).to_be_bytes());
+        bytes.push(3);
+        bytes.push(0);
+
+        let mut seen = [None; 64];
+        let mut previous = Colour::from_rgb(0, 0, 0);
+        let mut run = 0;
+
+        for colour in screen.rows_from_top().flatten().copied() {
+            if colour == previous {
+                run += 1;
+                if run == 62 {
+                    bytes.push(0xC0 | (run - 1));
+                    run = 0;
+                }
+                continue;
+            }
+
+            if run > 0 {
+                bytes.push(0xC0 | (run - 1));
+                run = 0;
+            }
+
+            // Alpha is always 255, which is included in the hash.
+            let hash =
+                (usize::from(colour.red) * 3 + usize::from(colour.green) * 5 + usize::from(colour.blue) * 7 + 255 * 11) % 64;
+
+            if seen[hash] == Some(colour) {
+                bytes.push(hash as u8);
+            }
+            else {
+                seen[hash] = Some(colour);
+
+                let red = colour.red.wrapping_sub(previous.red) as i8;
+                let green = colour.green.wrapping_sub(previous.green) as i8;
+                let blue = colour.blue.wrapping_sub(previous.blue) as i8;
+
+                let red_green = red.wrapping_sub(green);
+                let blue_green = blue.wrapping_sub(green);
+
+                if (-2..2).contains(&red) && (-2..2).contains(&green) && (-2..2).contains(&blue) {
+                    bytes.push(0x40 | ((red + 2) as u8) << 4 | ((green + 2) as u8) << 2 | (blue + 2) as u8);
+                }
+                else if (-32..32).contains(&green) && (-8..8).contains(&red_green) && (-8..8).contains(&blue_green) {
+                    bytes.push(0x80 | (green + 32) as u8);
+                    bytes.push(((red_green + 8) as u8) << 4 | (blue_green + 8) as u8);
+                }
+                else {
+                    bytes.extend_from_slice(&[0xFE, colour.red, colour.green, colour.blue]);
+                }
+            }
+
+            previous = colour;
+        }
+
+        if run > 0 {
+            bytes.push(0xC0 | (run - 1));
+        }
+
+        bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1]);
+
+        bytes
+    }
 }
 
 
 pub (in crate) mod png {
+    use std::collections::HashMap;
+
     use crate::rendering::*;
+    use crate::colours::Colour;
+
+    #[cfg(feature = "native-png")]
+    pub (in crate) use super::png_encoder::write_png_natively;
+
+    /// Possible colour types of encoded PNG data.
+    #[derive(Copy, Clone, PartialEq)]
+    pub (in crate) enum PngColourType {
+        Rgb,
+        Rgba,
+        Indexed,
+    }
+
+    /// Represents the image data of a PNG, with rows from top to bottom, ready to be encoded.
+    pub (in crate) struct PngImage {
+        pub (in crate) width : u32,
+        pub (in crate) height : u32,
+        pub (in crate) colour_type : PngColourType,
+        pub (in crate) bit_depth : u8,
+        pub (in crate) palette : Vec<u8>,
+        pub (in crate) data : Vec<u8>,
+    }
+
+    /// Creates the PNG image data for the screen with the provided colour type.
+    pub (in crate) fn create_png_image(screen : &Screen, colour : PngColour) -> PngImage {
+        let (colour_type, bit_depth, palette, data) =
+            match colour {
+                PngColour::Rgb => (PngColourType::Rgb, 8, Vec::new(), create_rgb_byte_array(screen)),
+                PngColour::Rgba => (PngColourType::Rgba, 8, Vec::new(), create_rgba_byte_array(screen)),
+                PngColour::Indexed(max_colours) => {
+                    let indexed = create_indexed_image(screen, max_colours);
+                    let palette = indexed.palette_bytes();
+
+                    (PngColourType::Indexed, indexed.bit_depth, palette, indexed.data)
+                }
+            };
+
+        PngImage {
+            width : u32::from(screen.horizontal_resolution),
+            height : u32::from(screen.vertical_resolution),
+            colour_type,
+            bit_depth,
+            palette,
+            data,
+        }
+    }
+
+    /// Writes the PNG image using the PNG crate.
+    #[cfg(not(feature = "native-png"))]
+    pub (in crate) fn write_png_with_crate<W : std::io::Write>(writer : W, image : &PngImage, settings : PngSettings) -> Result<(), ::png::EncodingError> {
+        let mut encoder =
+            ::png::Encoder::new(writer, image.width, image.height);
+
+        encoder.set_depth(
+            match image.bit_depth {
+                1 => ::png::BitDepth::One,
+                2 => ::png::BitDepth::Two,
+                4 => ::png::BitDepth::Four,
+                _ => ::png::BitDepth::Eight,
+            }
+        );
+
+        encoder.set_color(
+            match image.colour_type {
+                PngColourType::Rgb => ::png::ColorType::Rgb,
+                PngColourType::Rgba => ::png::ColorType::Rgba,
+                PngColourType::Indexed => ::png::ColorType::Indexed,
+            }
+        );
+
+        if image.colour_type == PngColourType::Indexed {
+            encoder.set_palette(image.palette.clone());
+        }
+
+        encoder.set_compression(
+            match settings.compression {
+                PngCompression::Fast => ::png::Compression::Fast,
+                PngCompression::Default => ::png::Compression::Default,
+                PngCompression::Best => ::png::Compression::Best,
+            }
+        );
+
+        match settings.filter {
+            PngFilter::Adaptive => {
+                encoder.set_adaptive_filter(::png::AdaptiveFilterType::Adaptive);
+            }
+            filter => {
+                encoder.set_filter(
+                    match filter {
+                        PngFilter::Sub => ::png::FilterType::Sub,
+                        PngFilter::Up => ::png::FilterType::Up,
+                        PngFilter::Average => ::png::FilterType::Avg,
+                        PngFilter::Paeth => ::png::FilterType::Paeth,
+                        _ => ::png::FilterType::NoFilter,
+                    }
+                );
+            }
+        }
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&image.data)?;
+        writer.finish()
+    }
+
+    /// Represents an image as a palette and the packed palette indices of each row from top to bottom.
+    pub (in crate) struct IndexedImage {
+        pub (in crate) palette : Vec<Colour>,
+        pub (in crate) data : Vec<u8>,
+        pub (in crate) bit_depth : u8,
+    }
+
+    impl IndexedImage {
+        /// Gets the palette as consecutive RGB bytes.
+        pub (in crate) fn palette_bytes(&self) -> Vec<u8> {
+            self.palette
+            .iter()
+            .flat_map(|colour| [colour.red, colour.green, colour.blue])
+            .collect()
+        }
+    }
+
+    /// Creates an indexed image from the screen with at most the specified number of colours, using an exact palette if possible, and otherwise a median cut palette with Floyd-Steinberg dithering.
+    pub (in crate) fn create_indexed_image(screen : &Screen, max_colours : u16) -> IndexedImage {
+        let max_colours = usize::from(max_colours.clamp(2, 256));
+
+        let width = usize::from(screen.horizontal_resolution);
+
+        // Pixels from the top row to the bottom row.
+        let pixels : Vec<Colour> =
+            screen
+            .rows_from_top()
+            .flatten()
+            .copied()
+            .collect();
+
+        let mut histogram : HashMap<(u8, u8, u8), u32> = HashMap::new();
+        for colour in &pixels {
+            *histogram.entry((colour.red, colour.green, colour.blue)).or_insert(0) += 1;
+        }
+
+        let (palette, indices) =
+            if histogram.len() <= max_colours {
+                let mut palette : Vec<(u8, u8, u8)> = histogram.keys().copied().collect();
+                palette.sort_unstable();
+
+                let lookup : HashMap<(u8, u8, u8), u8> =
+                    palette
+                    .iter()
+                    .enumerate()
+                    .map(|(index, colour)| (*colour, u8::try_from(index).unwrap()))
+                    .collect();
+
+                let indices =
+                    pixels
+                    .iter()
+                    .map(|colour| lookup[&(colour.red, colour.green, colour.blue)])
+                    .collect();
+
+                (palette.into_iter().map(|(r, g, b)| Colour::from_rgb(r, g, b)).collect(), indices)
+            }
+            else {
+                let palette = median_cut(&histogram, max_colours);
+                let indices = floyd_steinberg(&pixels, width, &palette);
+
+                (palette, indices)
+            };
+
+        let bit_depth =
+            match palette.len() {
+                0..=2 => 1,
+                3..=4 => 2,
+                5..=16 => 4,
+                _ => 8,
+            };
+
+        IndexedImage {
+            palette,
+            data : pack_rows(&indices, width, bit_depth),
+            bit_depth,
+        }
+    }
+
+    /// Chooses a palette by repeatedly splitting the box of colours with the widest channel range at the weighted median of that channel.
+    fn median_cut(histogram : &HashMap<(u8, u8, u8), u32>, max_colours : usize) -> Vec<Colour> {
+
+        fn channel(colour : &(u8, u8, u8), index : usize) -> u8 {
+            match index {
+                0 => colour.0,
+                1 => colour.1,
+                _ => colour.2,
+            }
+        }
+
+        // Finds the channel with the widest range in a box, and that range.
+        fn widest_channel(colours : &[((u8, u8, u8), u32)]) -> (usize, u8) {
+            (0..3)
+            .map(|index| {
+                let min = colours.iter().map(|(colour, _)| channel(colour, index)).min().unwrap();
+                let max = colours.iter().map(|(colour, _)| channel(colour, index)).max().unwrap();
+                (index, max - min)
+            })
+            .max_by_key(|(_, range)| *range)
+            .unwrap()
+        }
+
+        type ColourBox = Vec<((u8, u8, u8), u32)>;
+
+        let mut colours : ColourBox = histogram.iter().map(|(colour, count)| (*colour, *count)).collect();
+        colours.sort_unstable();
+
+        let mut boxes = vec![colours];
+
+        while boxes.len() < max_colours {
+            let splittable =
+                boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, colours)| colours.len() > 1)
+                .max_by_key(|(_, colours)| {
+                    let (_, range) = widest_channel(colours);
+                    u64::from(range) * colours.iter().map(|(_, count)| u64::from(*count)).sum::<u64>()
+                })
+                .map(|(index, _)| index);
+
+            let index =
+                match splittable {
+                    Some(index) => index,
+                    None => break,
+                };
+
+            let mut colours = boxes.swap_remove(index);
+            let (widest, _) = widest_channel(&colours);
+
+            colours.sort_unstable_by_key(|(colour, _)| channel(colour, widest));
+
+            let total : u64 = colours.iter().map(|(_, count)| u64::from(*count)).sum();
+            let mut running = 0;
+            let mut split = 1;
+
+            for (i, (_, count)) in colours.iter().enumerate() {
+                running += u64::from(*count);
+                if 2 * running >= total {
+                    split = (i + 1).clamp(1, colours.len() - 1);
+                    break;
+                }
+            }
+
+            let upper = colours.split_off(split);
+            boxes.push(colours);
+            boxes.push(upper);
+        }
+
+        boxes
+        .iter()
+        .map(|colours| {
+            let total : u64 = colours.iter().map(|(_, count)| u64::from(*count)).sum();
+            let mean = |index : usize| {
+                let sum : u64 = colours.iter().map(|(colour, count)| u64::from(channel(colour, index)) * u64::from(*count)).sum();
+                u8::try_from((sum + total / 2) / total).unwrap()
+            };
+
+            Colour::from_rgb(mean(0), mean(1), mean(2))
+        })
+        .collect()
+    }
+
+    /// Maps each pixel to its nearest palette entry, diffusing the error to neighbouring pixels with Floyd-Steinberg dithering.
+    fn floyd_steinberg(pixels : &[Colour], width : usize, palette : &[Colour]) -> Vec<u8> {
+        let mut errors = vec![[0.0_f32; 3]; pixels.len()];
+        let mut indices = Vec::with_capacity(pixels.len());
+
+        let mut nearest : HashMap<(u8, u8, u8), u8> = HashMap::new();
+
+        for (i, colour) in pixels.iter().enumerate() {
+            let target = [
+                (f32::from(colour.red) + errors[i][0]).clamp(0.0, 255.0),
+                (f32::from(colour.green) + errors[i][1]).clamp(0.0, 255.0),
+                (f32::from(colour.blue) + errors[i][2]).clamp(0.0, 255.0),
+            ];
+
+            let key = (target[0].round() as u8, target[1].round() as u8, target[2].round() as u8);
+
+            let index =
+                *nearest
+                .entry(key)
+                .or_insert_with(|| {
+                    let distance = |entry : &Colour| {
+                        let dr = i32::from(entry.red) - i32::from(key.0);
+                        let dg = i32::from(entry.green) - i32::from(key.1);
+                        let db = i32::from(entry.blue) - i32::from(key.2);
+                        2 * dr * dr + 4 * dg * dg + 3 * db * db
+                    };
+
+                    let (index, _) =
+                        palette
+                        .iter()
+                        .enumerate()
+                        .min_by_key(|(_, entry)| distance(entry))
+                        .unwrap();
+
+                    u8::try_from(index).unwrap()
+                });
+
+            let chosen = palette[usize::from(index)];
+
+            let error = [
+                target[0] - f32::from(chosen.red),
+                target[1] - f32::from(chosen.green),
+                target[2] - f32::from(chosen.blue),
+            ];
+
+            let x = i % width;
+            let mut diffuse = |offset : usize, weight : f32| {
+                if let Some(entry) = errors.get_mut(offset) {
+                    for channel in 0..3 {
+                        entry[channel] += error[channel] * weight;
+                    }
+                }
+            };
+
+            if x + 1 < width {
+                diffuse(i + 1, 7.0 / 16.0);
+                diffuse(i + width + 1, 1.0 / 16.0);
+            }
+            if x > 0 {
+                diffuse(i + width - 1, 3.0 / 16.0);
+            }
+            diffuse(i + width, 5.0 / 16.0);
+
+            indices.push(index);
+        }
+
+        indices
+    }
+
+    /// Packs rows of palette indices into the specified number of bits per pixel, with each row starting on a new byte.
+    fn pack_rows(indices : &[u8], width : usize, bit_depth : u8) -> Vec<u8> {
+        if bit_depth == 8 {
+            return indices.to_vec();
+        }
+
+        let pixels_per_byte = usize::from(8 / bit_depth);
+        let mut data = Vec::with_capacity(indices.len() / pixels_per_byte + indices.len() / width.max(1));
+
+        for row in indices.chunks(width) {
+            for group in row.chunks(pixels_per_byte) {
+                let mut byte = 0_u8;
+                for (i, index) in group.iter().enumerate() {
+                    byte |= index << (8 - bit_depth * (u8::try_from(i).unwrap() + 1));
+                }
+                data.push(byte);
+            }
+        }
+
+        data
+    }
 
     /// Creates an array of RGB values from the image.
     pub (in crate) fn create_rgb_byte_array(screen : &Screen) -> Vec<u8> {
 
         let mut bytes =
-            Vec::with_capacity((u32::from(screen.horizontal_resolution) * u32::from(screen.vertical_resolution)) as usize);
-        
-        for y in (0..screen.vertical_resolution).rev() {
-            for x in 0..screen.horizontal_resolution {
-                let current_colour = screen.pixels[x as usize][y as usize];
+            Vec::with_capacity(3 * screen.pixels().len());
+
+        for current_colour in screen.rows_from_top().flatten() {
+            bytes.push(current_colour.red);
+            bytes.push(current_colour.green);
+            bytes.push(current_colour.blue);
+        }
+
+        bytes
+    }
+
+    pub (in crate) fn create_rgba_byte_array(screen : &Screen) -> Vec<u8> {
+
+        let mut bytes =
+            Vec::with_capacity(4 * screen.pixels().len());
+
+        for current_colour in screen.rows_from_top().flatten() {
+            bytes.push(current_colour.red);
+            bytes.push(current_colour.green);
+            bytes.push(current_colour.blue);
+            bytes.push(255);
+        }
+
+        bytes
+    }
+
+}
+
 
-                bytes.push(current_colour.red);
-                bytes.push(current_colour.green);
-                bytes.push(current_colour.blue);
+#[cfg(feature = "native-png")]
+pub (in crate) mod png_encoder {
+    use std::io::{self, Write};
+    use std::sync::OnceLock;
+
+    use crate::rendering::{PngCompression, PngFilter};
+    use super::png::{PngImage, PngColourType};
+
+    const SIGNATURE : [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+    /// Encodes the PNG image and writes it in full to the writer.
+    pub (in crate) fn write_png_natively<W : Write>(writer : &mut W, image : &PngImage, compression : PngCompression, filter : PngFilter) -> io::Result<()> {
+        writer.write_all(&SIGNATURE)?;
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&image.width.to_be_bytes());
+        header.extend_from_slice(&image.height.to_be_bytes());
+        header.push(image.bit_depth);
+        header.push(
+            match image.colour_type {
+                PngColourType::Rgb => 2,
+                PngColourType::Indexed => 3,
+                PngColourType::Rgba => 6,
             }
+        );
+        // Compression method, filter method and interlace method.
+        header.extend_from_slice(&[0, 0, 0]);
+
+        write_chunk(writer, b"IHDR", &header)?;
+
+        if image.colour_type == PngColourType::Indexed {
+            write_chunk(writer, b"PLTE", &image.palette)?;
         }
 
+        let filtered = filter_scanlines(image, filter);
+        let compressed = zlib_compress(&filtered, compression);
+
+        for data in compressed.chunks(1 << 20) {
+            write_chunk(writer, b"IDAT", data)?;
+        }
+
+        write_chunk(writer, b"IEND", &[])
+    }
+
+    /// Writes a chunk with its length, type, data and CRC.
+    fn write_chunk<W : Write>(writer : &mut W, chunk_type : &[u8; 4], data : &[u8]) -> io::Result<()> {
+        writer.write_all(&u32::try_from(data.len()).unwrap().to_be_bytes())?;
+        writer.write_all(chunk_type)?;
+        writer.write_all(data)?;
+
+        let crc = crc32_update(crc32_update(0xFFFF_FFFF, chunk_type), data) ^ 0xFFFF_FFFF;
+        writer.write_all(&crc.to_be_bytes())
+    }
+
+    /// Gets the lookup table for the CRC-32 used by PNG chunks, with the polynomial 0xEDB88320.
+    fn crc32_table() -> &'static [u32; 256] {
+        static TABLE : OnceLock<[u32; 256]> = OnceLock::new();
+
+        TABLE.get_or_init(|| {
+            let mut table = [0; 256];
+
+            for (n, entry) in table.iter_mut().enumerate() {
+                let mut c = n as u32;
+                for _ in 0..8 {
+                    c = if c & 1 == 1 { 0xEDB8_8320 ^ (c >> 1) } else { c >> 1 };
+                }
+                *entry = c;
+            }
+
+            table
+        })
+    }
+
+    /// Continues a CRC-32 over more bytes, without the final inversion.
+    fn crc32_update(crc : u32, bytes : &[u8]) -> u32 {
+        let table = crc32_table();
+
         bytes
+        .iter()
+        .fold(crc, |crc, byte| table[((crc ^ u32::from(*byte)) & 0xFF) as usize] ^ (crc >> 8))
+    }
+
+    /// Calculates the Adler-32 checksum used by the zlib stream.
+    fn adler32(bytes : &[u8]) -> u32 {
+        const MODULUS : u32 = 65521;
+
+        let mut a = 1;
+        let mut b = 0;
+
+        // 5552 is the most bytes that can be summed before b could overflow.
+        for block in bytes.chunks(5552) {
+            for byte in block {
+                a += u32::from(*byte);
+                b += a;
+            }
+            a %= MODULUS;
+            b %= MODULUS;
+        }
+
+        (b << 16) | a
+    }
+
+    /// Prefixes each row with a filter type byte and applies that filter. Adaptive uses the filter giving the smallest sum of absolute differences for each row.
+    fn filter_scanlines(image : &PngImage, filter : PngFilter) -> Vec<u8> {
+        let bits_per_pixel =
+            match image.colour_type {
+                PngColourType::Rgb => 24,
+                PngColourType::Rgba => 32,
+                PngColourType::Indexed => usize::from(image.bit_depth),
+            };
+        let row_bytes = (image.width as usize * bits_per_pixel).div_ceil(8);
+        let bytes_per_pixel = bits_per_pixel.div_ceil(8);
+
+        let mut filtered = Vec::with_capacity((row_bytes + 1) * image.height as usize);
+        let empty_row = vec![0; row_bytes];
+        let mut candidate = vec![0; row_bytes];
+
+        for (y, row) in image.data.chunks(row_bytes).enumerate() {
+            let previous =
+                if y == 0 { &empty_row[..] }
+                else { &image.data[(y - 1) * row_bytes..y * row_bytes] };
+
+            let filter_type =
+                match filter {
+                    PngFilter::None => 0,
+                    PngFilter::Sub => 1,
+                    PngFilter::Up => 2,
+                    PngFilter::Average => 3,
+                    PngFilter::Paeth => 4,
+                    PngFilter::Adaptive => {
+                        (0..5)
+                        .min_by_key(|filter_type| {
+                            apply_filter(*filter_type, row, previous, bytes_per_pixel, &mut candidate);
+                            candidate.iter().map(|byte| u32::from((*byte as i8).unsigned_abs())).sum::<u32>()
+                        })
+                        .unwrap()
+                    }
+                };
+
+            apply_filter(filter_type, row, previous, bytes_per_pixel, &mut candidate);
+
+            filtered.push(filter_type);
+            filtered.extend_from_slice(&candidate);
+        }
+
+        filtered
+    }
+
+    /// Applies a single filter type to a row, given the unfiltered previous row.
+    fn apply_filter(filter_type : u8, row : &[u8], previous : &[u8], bytes_per_pixel : usize, output : &mut [u8]) {
+        for i in 0..row.len() {
+            let left = if i >= bytes_per_pixel { row[i - bytes_per_pixel] } else { 0 };
+            let above = previous[i];
+            let upper_left = if i >= bytes_per_pixel { previous[i - bytes_per_pixel] } else { 0 };
+
+            let predictor =
+                match filter_type {
+                    1 => left,
+                    2 => above,
+                    3 => ((u16::from(left) + u16::from(above)) / 2) as u8,
+                    4 => paeth_predictor(left, above, upper_left),
+                    _ => 0,
+                };
+
+            output[i] = row[i].wrapping_sub(predictor);
+        }
+    }
+
+    /// Chooses whichever of the left, above and upper left bytes is closest to their linear estimate.
+    fn paeth_predictor(left : u8, above : u8, upper_left : u8) -> u8 {
+        let estimate = i16::from(left) + i16::from(above) - i16::from(upper_left);
+
+        let distance_left = (estimate - i16::from(left)).abs();
+        let distance_above = (estimate - i16::from(above)).abs();
+        let distance_upper_left = (estimate - i16::from(upper_left)).abs();
+
+        if distance_left <= distance_above && distance_left <= distance_upper_left {
+            left
+        }
+        else if distance_above <= distance_upper_left {
+            above
+        }
+        else {
+            upper_left
+        }
+    }
+
+    /// Compresses the bytes into a zlib stream.
+    fn zlib_compress(bytes : &[u8], compression : PngCompression) -> Vec<u8> {
+        let level_flag =
+            match compression {
+                PngCompression::Fast => 1,
+                PngCompression::Default => 2,
+                PngCompression::Best => 3,
+            };
+
+        // Deflate with a 32 KiB window, and a check value making the header a multiple of 31.
+        let cmf = 0x78_u16;
+        let mut flg = level_flag << 6;
+        flg += (31 - ((cmf << 8) + flg) % 31) % 31;
+
+        let mut output = vec![cmf as u8, flg as u8];
+        output.extend(deflate::compress(bytes, compression));
+        output.extend_from_slice(&adler32(bytes).to_be_bytes());
+
+        output
+    }
+
+    /// A DEFLATE compressor, using LZ77 with hash chains, and choosing between stored, fixed Huffman and dynamic Huffman encoding for each block.
+    mod deflate {
+        use crate::rendering::PngCompression;
+
+        const WINDOW_SIZE : usize = 1 << 15;
+        const MIN_MATCH : usize = 3;
+        const MAX_MATCH : usize = 258;
+        const HASH_BITS : u32 = 15;
+        const BLOCK_TOKENS : usize = 1 << 14;
+
+        const LENGTH_BASES : [u16; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+        const LENGTH_EXTRA_BITS : [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+        const DISTANCE_BASES : [u16; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+        const DISTANCE_EXTRA_BITS : [u8; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+        const CODE_LENGTH_ORDER : [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+
+        /// A literal byte, or a back reference with a length and distance.
+        #[derive(Copy, Clone)]
+        enum Token {
+            Literal(u8),
+            Match(u16, u16),
+        }
+
+        impl Token {
+            /// Gets the literal/length symbol, along with the extra bits and their count.
+            fn length_symbol(&self) -> (usize, u16, u8) {
+                match *self {
+                    Token::Literal(byte) => (usize::from(byte), 0, 0),
+                    Token::Match(length, _) => {
+                        let code = LENGTH_BASES.partition_point(|base| *base <= length) - 1;
+                        (257 + code, length - LENGTH_BASES[code], LENGTH_EXTRA_BITS[code])
+                    }
+                }
+            }
+
+            /// Gets the distance symbol, along with the extra bits and their count, if this is a match.
+            fn distance_symbol(&self) -> Option<(usize, u16, u8)> {
+                match *self {
+                    Token::Literal(_) => None,
+                    Token::Match(_, distance) => {
+                        let code = DISTANCE_BASES.partition_point(|base| *base <= distance) - 1;
+                        Some((code, distance - DISTANCE_BASES[code], DISTANCE_EXTRA_BITS[code]))
+                    }
+                }
+            }
+        }
+
+        /// Writes bits least significant first, as DEFLATE requires.
+        struct BitWriter {
+            bytes : Vec<u8>,
+            buffer : u64,
+            count : u32,
+        }
+
+        impl BitWriter {
+            fn new() -> BitWriter {
+                BitWriter {
+                    bytes : Vec::new(),
+                    buffer : 0,
+                    count : 0,
+                }
+            }
+
+            fn write(&mut self, value : u32, bits : u8) {
+                self.buffer |= u64::from(value) << self.count;
+                self.count += u32::from(bits);
+
+                while self.count >= 8 {
+                    self.bytes.push(self.buffer as u8);
+                    self.buffer >>= 8;
+                    self.count -= 8;
+                }
+            }
+
+            /// Writes a Huffman code, which is stored most significant bit first.
+            fn write_code(&mut self, code : u16, length : u8) {
+                let reversed = code.reverse_bits() >> (16 - length);
+                self.write(u32::from(reversed), length);
+            }
+
+            fn align(&mut self) {
+                if self.count > 0 {
+                    self.write(0, (8 - self.count) as u8);
+                }
+            }
+
+            fn finish(mut self) -> Vec<u8> {
+                self.align();
+                self.bytes
+            }
+        }
+
+        /// Compresses the bytes into a raw DEFLATE stream.
+        pub (super) fn compress(bytes : &[u8], compression : PngCompression) -> Vec<u8> {
+            let tokens = find_matches(bytes, compression);
+
+            let mut writer = BitWriter::new();
+            let mut start = 0;
+
+            let blocks : Vec<&[Token]> =
+                if tokens.is_empty() { vec![&tokens[..]] }
+                else { tokens.chunks(BLOCK_TOKENS).collect() };
+
+            for (i, block) in blocks.iter().enumerate() {
+                let block_length : usize =
+                    block
+                    .iter()
+                    .map(|token| match token { Token::Literal(_) => 1, Token::Match(length, _) => usize::from(*length) })
+                    .sum();
+
+                write_block(&mut writer, block, &bytes[start..start + block_length], i + 1 == blocks.len(), compression);
+
+                start += block_length;
+            }
+
+            writer.finish()
+        }
+
+        /// Finds back references with hash chains, with longer chains and lazy matching at higher compression levels.
+        fn find_matches(bytes : &[u8], compression : PngCompression) -> Vec<Token> {
+            let (max_chain, nice_length, lazy) =
+                match compression {
+                    PngCompression::Fast => (8, 32, false),
+                    PngCompression::Default => (128, 128, true),
+                    PngCompression::Best => (2048, MAX_MATCH, true),
+                };
+
+            let mut head = vec![usize::MAX; 1 << HASH_BITS];
+            let mut previous = vec![usize::MAX; WINDOW_SIZE];
+            let mut tokens = Vec::with_capacity(bytes.len() / 2);
+
+            let hash = |position : usize| -> usize {
+                let value = u32::from(bytes[position]) << 16 | u32::from(bytes[position + 1]) << 8 | u32::from(bytes[position + 2]);
+                (value.wrapping_mul(0x9E37_79B1) >> (32 - HASH_BITS)) as usize
+            };
+
+            let insert = |position : usize, head : &mut Vec<usize>, previous : &mut Vec<usize>| {
+                if position + MIN_MATCH <= bytes.len() {
+                    let h = hash(position);
+                    previous[position % WINDOW_SIZE] = head[h];
+                    head[h] = position;
+                }
+            };
+
+            let longest_match = |position : usize, head : &Vec<usize>, previous : &Vec<usize>| -> (usize, usize) {
+                if position + MIN_MATCH > bytes.len() {
+                    return (0, 0);
+                }
+
+                let max_length = MAX_MATCH.min(bytes.len() - position);
+                let mut best = (0, 0);
+                let mut candidate = head[hash(position)];
+                let mut chain = 0;
+
+                while candidate != usize::MAX && position - candidate <= WINDOW_SIZE && chain < max_chain {
+                    if bytes[candidate + best.0] == bytes[position + best.0] {
+                        let length =
+                            bytes[candidate..candidate + max_length]
+                            .iter()
+                            .zip(&bytes[position..position + max_length])
+                            .take_while(|(a, b)| a == b)
+                            .count();
+
+                        if length > best.0 {
+                            best = (length, position - candidate);
+                            if length >= nice_length.min(max_length) {
+                                break;
+                            }
+                        }
+                    }
+
+                    let next = previous[candidate % WINDOW_SIZE];
+                    if next == usize::MAX || next >= candidate {
+                        break;
+                    }
+                    candidate = next;
+                    chain += 1;
+                }
+
+                if best.0 >= MIN_MATCH { best } else { (0, 0) }
+            };
+
+            let mut position = 0;
+
+            while position < bytes.len() {
+                let (mut length, mut distance) = longest_match(position, &head, &previous);
+
+                if length > 0 && lazy && length < nice_length && position + 1 < bytes.len() {
+                    insert(position, &mut head, &mut previous);
+                    let (next_length, next_distance) = longest_match(position + 1, &head, &previous);
+
+                    if next_length > length {
+                        tokens.push(Token::Literal(bytes[position]));
+                        position += 1;
+                        length = next_length;
+                        distance = next_distance;
+                    }
+                    else {
+                        // The current position is already in the hash chains.
+                        tokens.push(Token::Match(length as u16, distance as u16));
+                        for offset in 1..length {
+                            insert(position + offset, &mut head, &mut previous);
+                        }
+                        position += length;
+                        continue;
+                    }
+                }
+
+                if length > 0 {
+                    tokens.push(Token::Match(length as u16, distance as u16));
+                    for offset in 0..length {
+                        insert(position + offset, &mut head, &mut previous);
+                    }
+                    position += length;
+                }
+                else {
+                    tokens.push(Token::Literal(bytes[position]));
+                    insert(position, &mut head, &mut previous);
+                    position += 1;
+                }
+            }
+
+            tokens
+        }
+
+        /// Writes a block using whichever of stored, fixed Huffman or dynamic Huffman encoding is smallest.
+        fn write_block(writer : &mut BitWriter, tokens : &[Token], raw : &[u8], last : bool, compression : PngCompression) {
+            let mut length_frequencies = [0_u32; 286];
+            let mut distance_frequencies = [0_u32; 30];
+
+            for token in tokens {
+                length_frequencies[token.length_symbol().0] += 1;
+                if let Some((symbol, _, _)) = token.distance_symbol() {
+                    distance_frequencies[symbol] += 1;
+                }
+            }
+            length_frequencies[256] += 1;
+
+            let (fixed_lengths, fixed_distances) = fixed_code_lengths();
+            let fixed_cost = 3 + data_cost(tokens, &fixed_lengths, &fixed_distances);
+
+            let dynamic =
+                if let PngCompression::Fast = compression { None }
+                else {
+                    let lengths = huffman_code_lengths(&length_frequencies, 15);
+                    let distances = huffman_code_lengths(&distance_frequencies, 15);
+                    let header = DynamicHeader::new(&lengths, &distances);
+                    let cost = 3 + header.cost() + data_cost(tokens, &lengths, &distances);
+
+                    Some((cost, lengths, distances, header))
+                };
+
+            // Each stored block has up to 7 bits of padding, plus 3 header bits and 32 bits of lengths.
+            let stored_cost = raw.len().div_ceil(65535).max(1) * 42 + raw.len() * 8;
+
+            let last_bit = u32::from(last);
+
+            match dynamic {
+                Some((cost, lengths, distances, header)) if cost < fixed_cost && cost < stored_cost => {
+                    writer.write(last_bit | 2 << 1, 3);
+                    header.write(writer);
+                    write_data(writer, tokens, &lengths, &distances);
+                }
+                _ if stored_cost < fixed_cost => {
+                    let chunks : Vec<&[u8]> =
+                        if raw.is_empty() { vec![raw] }
+                        else { raw.chunks(65535).collect() };
+
+                    for (i, chunk) in chunks.iter().enumerate() {
+                        let final_chunk = last && i + 1 == chunks.len();
+                        writer.write(u32::from(final_chunk), 3);
+                        writer.align();
+
+                        let length = chunk.len() as u16;
+                        writer.write(u32::from(length), 16);
+                        writer.write(u32::from(!length), 16);
+                        for byte in *chunk {
+                            writer.write(u32::from(*byte), 8);
+                        }
+                    }
+                }
+                _ => {
+                    writer.write(last_bit | 1 << 1, 3);
+                    write_data(writer, tokens, &fixed_lengths, &fixed_distances);
+                }
+            }
+        }
+
+        /// Gets the code lengths of the fixed Huffman codes.
+        fn fixed_code_lengths() -> (Vec<u8>, Vec<u8>) {
+            let lengths =
+                (0..288)
+                .map(|symbol| match symbol { 0..=143 => 8, 144..=255 => 9, 256..=279 => 7, _ => 8 })
+                .collect();
+
+            (lengths, vec![5; 30])
+        }
+
+        /// Calculates the number of bits needed to encode the tokens and end of block with the provided code lengths.
+        fn data_cost(tokens : &[Token], lengths : &[u8], distances : &[u8]) -> usize {
+            let mut cost = usize::from(lengths[256]);
+
+            for token in tokens {
+                let (symbol, _, extra) = token.length_symbol();
+                cost += usize::from(lengths[symbol]) + usize::from(extra);
+
+                if let Some((symbol, _, extra)) = token.distance_symbol() {
+                    cost += usize::from(distances[symbol]) + usize::from(extra);
+                }
+            }
+
+            cost
+        }
+
+        /// Writes the tokens and end of block with the provided code lengths.
+        fn write_data(writer : &mut BitWriter, tokens : &[Token], lengths : &[u8], distances : &[u8]) {
+            let length_codes = canonical_codes(lengths);
+            let distance_codes = canonical_codes(distances);
+
+            for token in tokens {
+                let (symbol, extra_value, extra) = token.length_symbol();
+                writer.write_code(length_codes[symbol], lengths[symbol]);
+                writer.write(u32::from(extra_value), extra);
+
+                if let Some((symbol, extra_value, extra)) = token.distance_symbol() {
+                    writer.write_code(distance_codes[symbol], distances[symbol]);
+                    writer.write(u32::from(extra_value), extra);
+                }
+            }
+
+            writer.write_code(length_codes[256], lengths[256]);
+        }
+
+        /// Assigns canonical Huffman codes to the code lengths.
+        fn canonical_codes(lengths : &[u8]) -> Vec<u16> {
+            let mut counts = [0_u16; 16];
+            for length in lengths {
+                counts[usize::from(*length)] += 1;
+            }
+            counts[0] = 0;
+
+            let mut next = [0_u16; 16];
+            let mut code = 0;
+            for bits in 1..16 {
+                code = (code + counts[bits - 1]) << 1;
+                next[bits] = code;
+            }
+
+            lengths
+            .iter()
+            .map(|length| {
+                if *length == 0 { 0 }
+                else {
+                    let code = next[usize::from(*length)];
+                    next[usize::from(*length)] += 1;
+                    code
+                }
+            })
+            .collect()
+        }
+
+        /// Builds Huffman code lengths no longer than the limit, halving the frequencies until the tree is shallow enough. At least two symbols are always given codes, so that every code is complete.
+        fn huffman_code_lengths(frequencies : &[u32], limit : u8) -> Vec<u8> {
+            let mut weights : Vec<u32> = frequencies.to_vec();
+
+            let used = weights.iter().filter(|weight| **weight > 0).count();
+            for weight in weights.iter_mut().filter(|weight| **weight == 0).take(2_usize.saturating_sub(used)) {
+                *weight = 1;
+            }
+
+            loop {
+                let lengths = unlimited_code_lengths(&weights);
+
+                if lengths.iter().all(|length| *length <= limit) {
+                    return lengths;
+                }
+
+                for weight in weights.iter_mut().filter(|weight| **weight > 0) {
+                    *weight = (*weight >> 1) | 1;
+                }
+            }
+        }
+
+        /// Builds Huffman code lengths by repeatedly merging the two lightest nodes.
+        fn unlimited_code_lengths(weights : &[u32]) -> Vec<u8> {
+            use std::collections::BinaryHeap;
+            use std::cmp::Reverse;
+
+            // Each node stores its children, or its symbol if it is a leaf.
+            let mut children : Vec<Option<(usize, usize)>> = Vec::new();
+            let mut symbols : Vec<usize> = Vec::new();
+            let mut heap = BinaryHeap::new();
+
+            for (symbol, weight) in weights.iter().enumerate().filter(|(_, weight)| **weight > 0) {
+                heap.push(Reverse((u64::from(*weight), children.len())));
+                children.push(None);
+                symbols.push(symbol);
+            }
+
+            while heap.len() > 1 {
+                let Reverse((first_weight, first)) = heap.pop().unwrap();
+                let Reverse((second_weight, second)) = heap.pop().unwrap();
+
+                heap.push(Reverse((first_weight + second_weight, children.len())));
+                children.push(Some((first, second)));
+                symbols.push(usize::MAX);
+            }
+
+            let mut lengths = vec![0_u8; weights.len()];
+            let mut stack = vec![(children.len() - 1, 0_u8)];
+
+            while let Some((node, depth)) = stack.pop() {
+                match children[node] {
+                    Some((left, right)) => {
+                        stack.push((left, depth + 1));
+                        stack.push((right, depth + 1));
+                    }
+                    None => lengths[symbols[node]] = depth,
+                }
+            }
+
+            lengths
+        }
+
+        /// The header of a dynamic Huffman block, with the code lengths run length encoded.
+        struct DynamicHeader {
+            literal_count : usize,
+            distance_count : usize,
+            code_length_count : usize,
+            code_length_lengths : Vec<u8>,
+            runs : Vec<(u8, u8, u8)>,
+        }
+
+        impl DynamicHeader {
+            fn new(lengths : &[u8], distances : &[u8]) -> DynamicHeader {
+                let literal_count = lengths.iter().rposition(|length| *length > 0).map_or(257, |last| (last + 1).max(257));
+                let distance_count = distances.iter().rposition(|length| *length > 0).map_or(1, |last| last + 1);
+
+                let combined : Vec<u8> =
+                    lengths[..literal_count]
+                    .iter()
+                    .chain(&distances[..distance_count])
+                    .copied()
+                    .collect();
+
+                // Each run is a code length symbol, with its extra bits and their count.
+                let mut runs = Vec::new();
+                let mut i = 0;
+
+                while i < combined.len() {
+                    let length = combined[i];
+                    let run = combined[i..].iter().take_while(|other| **other == length).count();
+
+                    if length == 0 && run >= 11 {
+                        let count = run.min(138);
+                        runs.push((18, (count - 11) as u8, 7));
+                        i += count;
+                    }
+                    else if length == 0 && run >= 3 {
+                        runs.push((17, (run - 3) as u8, 3));
+                        i += run;
+                    }
+                    else if run >= 4 {
+                        runs.push((length, 0, 0));
+                        let count = (run - 1).min(6);
+                        runs.push((16, (count - 3) as u8, 2));
+                        i += count + 1;
+                    }
+                    else {
+                        runs.push((length, 0, 0));
+                        i += 1;
+                    }
+                }
+
+                let mut frequencies = [0_u32; 19];
+                for (symbol, _, _) in &runs {
+                    frequencies[usize::from(*symbol)] += 1;
+                }
+
+                let code_length_lengths = huffman_code_lengths(&frequencies, 7);
+                let code_length_count =
+                    CODE_LENGTH_ORDER
+                    .iter()
+                    .rposition(|symbol| code_length_lengths[*symbol] > 0)
+                    .map_or(4, |last| (last + 1).max(4));
+
+                DynamicHeader {
+                    literal_count,
+                    distance_count,
+                    code_length_count,
+                    code_length_lengths,
+                    runs,
+                }
+            }
+
+            fn cost(&self) -> usize {
+                14 + 3 * self.code_length_count
+                + self.runs
+                .iter()
+                .map(|(symbol, _, extra)| usize::from(self.code_length_lengths[usize::from(*symbol)]) + usize::from(*extra))
+                .sum::<usize>()
+            }
+
+            fn write(&self, writer : &mut BitWriter) {
+                writer.write((self.literal_count - 257) as u32, 5);
+                writer.write((self.distance_count - 1) as u32, 5);
+                writer.write((self.code_length_count - 4) as u32, 4);
+
+                for symbol in &CODE_LENGTH_ORDER[..self.code_length_count] {
+                    writer.write(u32::from(self.code_length_lengths[*symbol]), 3);
+                }
+
+                let codes = canonical_codes(&self.code_length_lengths);
+
+                for (symbol, extra_value, extra) in &self.runs {
+                    writer.write_code(codes[usize::from(*symbol)], self.code_length_lengths[usize::from(*symbol)]);
+                    writer.write(u32::from(*extra_value), *extra);
+                }
+            }
+        }
     }
 
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::colours::Colour;
+        use crate::maths_objects::Point;
+        use crate::rendering::{Screen, Image, PngColour};
+        use super::super::png::create_png_image;
+
+        const COMPRESSIONS : [PngCompression; 3] = [PngCompression::Fast, PngCompression::Default, PngCompression::Best];
+        const FILTERS : [PngFilter; 6] = [PngFilter::None, PngFilter::Sub, PngFilter::Up, PngFilter::Average, PngFilter::Paeth, PngFilter::Adaptive];
+
+        /// Creates a screen where each pixel is one of the provided number of colours, chosen pseudo-randomly but repeating often enough to give the compressor matches.
+        fn test_screen(width : u16, height : u16, colours : u32, grey : bool) -> Screen {
+            let mut screen = Screen::new(width, height, Point::new(0.0, 0.0), Point::new(1.0, 1.0), Colour::from_rgb(0, 0, 0));
+            let mut state = 12345_u32;
+
+            for y in 0..height {
+                for x in 0..width {
+                    if x % 5 != 0 {
+                        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                    }
+
+                    let value = (state >> 16) % colours;
+                    let level = (value * 255 / (colours - 1).max(1)) as u8;
+
+                    let colour =
+                        if grey { Colour::from_rgb(level, level, level) }
+                        else { Colour::from_rgb(level, (value * 97 % 256) as u8, 255 - level) };
+
+                    screen.set_pixel(x, y, colour);
+                }
+            }
+
+            screen
+        }
+
+        /// Encodes the screen with every compression level and filter, and checks that decoding gives back the same pixels.
+        fn assert_round_trip(screen : &Screen, colour : PngColour) {
+            let image = create_png_image(screen, colour);
+
+            for compression in COMPRESSIONS {
+                for filter in FILTERS {
+                    let mut bytes = Vec::new();
+                    write_png_natively(&mut bytes, &image, compression, filter).unwrap();
+
+                    let decoded = Image::decode(&bytes[..]).unwrap_or_else(|e| panic!("{}", e.to_string()));
+                    let decoded = Screen::from_image(&decoded, Point::new(0.0, 0.0), Point::new(1.0, 1.0), Colour::from_rgb(0, 0, 0)).unwrap();
+
+                    assert!(decoded.pixels() == screen.pixels());
+                }
+            }
+        }
+
+        #[test]
+        fn rgb_round_trips() {
+            assert_round_trip(&test_screen(37, 23, 256, false), PngColour::Rgb);
+            assert_round_trip(&test_screen(160, 80, 7, false), PngColour::Rgb);
+        }
+
+        #[test]
+        fn rgba_round_trips() {
+            assert_round_trip(&test_screen(37, 23, 256, false), PngColour::Rgba);
+            assert_round_trip(&test_screen(160, 80, 7, false), PngColour::Rgba);
+        }
+
+        #[test]
+        fn greyscale_round_trips() {
+            let screen = test_screen(41, 29, 256, true);
+
+            assert_round_trip(&screen, PngColour::Rgb);
+            assert_round_trip(&screen, PngColour::Rgba);
+            assert_round_trip(&screen, PngColour::Indexed(256));
+        }
+
+        #[test]
+        fn indexed_round_trips_at_every_bit_depth() {
+            for (colours, bit_depth) in [(2, 1), (4, 2), (16, 4), (200, 8)] {
+                let screen = test_screen(33, 17, colours, false);
+
+                assert_eq!(create_png_image(&screen, PngColour::Indexed(256)).bit_depth, bit_depth);
+                assert_round_trip(&screen, PngColour::Indexed(256));
+            }
+        }
+
+        #[test]
+        fn crc32_known_answer() {
+            assert_eq!(crc32_update(0xFFFF_FFFF, b"123456789") ^ 0xFFFF_FFFF, 0xCBF4_3926);
+            assert_eq!(crc32_update(0xFFFF_FFFF, b"IEND") ^ 0xFFFF_FFFF, 0xAE42_6082);
+        }
+
+        #[test]
+        fn adler32_known_answer() {
+            assert_eq!(adler32(b""), 1);
+            assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+            // Long enough for the sums to be reduced several times.
+            assert_eq!(adler32(&[0xFF; 100_000]), 0x149A_302C);
+        }
+    }
 }
 
+
+pub (in crate) mod terminal {
+    use crate::rendering::*;
+    use crate::colours::Colour;
+
+    /// Downsamples the screen to the specified resolution by averaging the pixels covered by each output pixel, returning rows from top to bottom.
+    fn downsample(screen : &Screen, width : u16, height : u16) -> Vec<Vec<Colour>> {
+        if screen.horizontal_resolution == 0 || screen.vertical_resolution == 0 {
+            return Vec::new();
+        }
+
+        let width = u32::from(width.min(screen.horizontal_resolution).max(1));
+        let height = u32::from(height.min(screen.vertical_resolution).max(1));
+
+        let horizontal_resolution = u32::from(screen.horizontal_resolution);
+        let vertical_resolution = u32::from(screen.vertical_resolution);
+
+        let mut rows = Vec::with_capacity(height as usize);
+
+        for row in 0..height {
+            let mut colours = Vec::with_capacity(width as usize);
+
+            // Rows are counted from the top of the image while pixels are stored from the bottom.
+            let y_start = vertical_resolution - (row + 1) * vertical_resolution / height;
+            let y_end = vertical_resolution - row * vertical_resolution / height;
+
+            for column in 0..width {
+                let x_start = column * horizontal_resolution / width;
+                let x_end = ((column + 1) * horizontal_resolution / width).max(x_start + 1);
+
+                let mut total = ColourTotal::new(screen.blending);
+
+                for y in y_start..y_end.max(y_start + 1) {
+                    for colour in &screen.row(y as usize)[x_start as usize..x_end as usize] {
+                        total.add(*colour, 1.0);
+                    }
+                }
+
+                colours.push(total.average());
+            }
+
+            rows.push(colours);
+        }
+
+        rows
+    }
+
+    /// Calculates the number of output rows which preserves the aspect ratio of the screen for the specified width.
+    fn proportional_height(screen : &Screen, width : u16) -> u16 {
+        let height =
+            (u32::from(width) * u32::from(screen.vertical_resolution))
+            .checked_div(u32::from(screen.horizontal_resolution))
+            .unwrap_or(0);
+
+        u16::try_from(height.max(1)).unwrap_or(u16::MAX)
+    }
+
+    /// Encodes the screen as rows of truecolour ANSI half block characters, each of which represents two vertically stacked pixels.
+    pub (in crate) fn create_half_block_string(screen : &Screen, columns : u16) -> String {
+        let height = proportional_height(screen, columns).saturating_add(1) / 2 * 2;
+        let rows = downsample(screen, columns, height.max(2));
+
+        let mut output = String::new();
+
+        for pair in rows.chunks(2) {
+            for (x, top) in pair[0].iter().enumerate() {
+                let bottom = pair.get(1).map_or(*top, |row| row[x]);
+
+                output.push_str(
+                    &format!(
+                        "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m\u{2580}",
+                        top.red, top.green, top.blue,
+                        bottom.red, bottom.green, bottom.blue
+                    )
+                );
+            }
+
+            output.push_str("\x1b[0m\n");
+        }
+
+        output
+    }
+
+    /// Encodes the screen as sixel graphics of the specified width in pixels, using a palette of 216 evenly spaced colours.
+    pub (in crate) fn create_sixel_string(screen : &Screen, width : u16) -> String {
+        let rows = downsample(screen, width, proportional_height(screen, width));
+
+        if rows.is_empty() {
+            return String::new();
+        }
+
+        let height = rows.len();
+        let width = rows[0].len();
+
+        // Index into the 6 by 6 by 6 colour cube.
+        let register = |colour : Colour| -> usize {
+            let level = |channel : u8| (usize::from(channel) * 5 + 127) / 255;
+            36 * level(colour.red) + 6 * level(colour.green) + level(colour.blue)
+        };
+
+        let mut output = format!("\x1bPq\"1;1;{};{}", width, height);
+
+        for index in 0..216 {
+            output.push_str(
+                &format!("#{};2;{};{};{}", index, (index / 36) * 20, ((index / 6) % 6) * 20, (index % 6) * 20)
+            );
+        }
+
+        for band in rows.chunks(6) {
+            let registers : Vec<Vec<usize>> =
+                band
+                .iter()
+                .map(|row| row.iter().map(|colour| register(*colour)).collect())
+                .collect();
+
+            let mut used = vec![false; 216];
+            for row in &registers {
+                for index in row {
+                    used[*index] = true;
+                }
+            }
+
+            for (index, _) in used.iter().enumerate().filter(|(_, used)| **used) {
+                output.push_str(&format!("#{}", index));
+
+                let mut previous : Option<(char, u32)> = None;
+
+                for x in 0..width {
+                    let mut bits = 0_u8;
+                    for (offset, row) in registers.iter().enumerate() {
+                        if row[x] == index {
+                            bits |= 1 << offset;
+                        }
+                    }
+
+                    let character = char::from(63 + bits);
+
+                    previous =
+                        match previous {
+                            Some((last, count)) if last == character => Some((last, count + 1)),
+                            Some((last, count)) => {
+                                push_sixel_run(&mut output, last, count);
+                                Some((character, 1))
+                            }
+                            None => Some((character, 1)),
+                        };
+                }
+
+                if let Some((last, count)) = previous {
+                    push_sixel_run(&mut output, last, count);
+                }
+
+                output.push('$');
+            }
+
+            output.push('-');
+        }
+
+        output.push_str("\x1b\\\n");
+
+        output
+    }
+
+    /// Adds a run of a repeated sixel character, using run length encoding where it is shorter.
+    fn push_sixel_run(output : &mut String, character : char, count : u32) {
+        if count > 3 {
+            output.push_str(&format!("!{}{}", count, character));
+        }
+        else {
+            for _ in 0..count {
+                output.push(character);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::maths_objects::Point;
+
+        #[test]
+        fn downsample_averages_blocks_from_the_top() {
+            let mut screen = Screen::new(4, 2, Point::new(0.0, 0.0), Point::new(1.0, 1.0), Colour::from_rgb(0, 0, 0)).with_blending(Blending::Legacy);
+            screen.set_pixel(2, 1, Colour::from_rgb(200, 100, 40));
+            screen.set_pixel(3, 1, Colour::from_rgb(100, 0, 40));
+
+            let rows = downsample(&screen, 2, 2);
+
+            assert!(rows[0] == [Colour::from_rgb(0, 0, 0), Colour::from_rgb(150, 50, 40)]);
+            assert!(rows[1] == [Colour::from_rgb(0, 0, 0), Colour::from_rgb(0, 0, 0)]);
+        }
+
+        #[test]
+        fn empty_screens_give_empty_previews() {
+            for (width, height) in [(0, 0), (0, 5), (5, 0)] {
+                let screen = Screen::new(width, height, Point::new(0.0, 0.0), Point::new(1.0, 1.0), Colour::from_rgb(0, 0, 0));
+
+                assert!(downsample(&screen, 10, 10).is_empty());
+                assert!(create_half_block_string(&screen, 10).is_empty());
+                assert!(create_sixel_string(&screen, 10).is_empty());
+            }
+        }
+    }
+}
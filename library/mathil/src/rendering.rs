@@ -1,30 +1,51 @@
-use std::io::Write;
+use std::io::{Read, Write};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut, Range};
+use std::thread;
+use std::f32::consts::TAU;
 use std::fs::File;
 use std::io::BufWriter;
 
-use png;
-
 use crate::{
     primitive_conversions::*,
-    colours::Colour,
+    colours::{Colour, ColourScale, ColourVisionDeficiency, byte_to_linear, linear_to_byte},
     maths_objects::*,
     output::{
         bitmap::*,
+        netpbm::*,
+        tga::*,
+        qoi::*,
         png::*,
+        terminal::*,
         generate_file_path
     },
+    input::decode_image,
     errors,
+    animation::{Trail, Decay, TrailStyle},
+    helpers::{get_bounds, lerp_scalar},
     rendering::utilities::*
 };
 
 /// Represents an image.
 #[derive(Clone)]
 pub struct Screen {
-    pub (in crate) pixels : Vec<Vec<Colour>>,
+    // Pixels in rows from the bottom of the screen to the top.
+    pub (in crate) pixels : Vec<Colour>,
     pub (in crate) horizontal_resolution : u16,
     pub (in crate) vertical_resolution : u16,
     pub (in crate) bottom_left_bound : Point,
-    pub (in crate) top_right_bound : Point
+    pub (in crate) top_right_bound : Point,
+    // Number of pixels on this screen for each pixel of the intended output, used to keep absolute thicknesses consistent.
+    pub (in crate) pixel_scale : f32,
+    pub (in crate) blending : Blending,
+    pub (in crate) supersampling : Option<Supersampling>,
+    // The band of rows held by the screen when it is one tile of a larger screen, in which case the pixels only cover those rows.
+    pub (in crate) tile : Option<Tile>,
+    // Layers above the pixels from the bottom to the top, composited when the screen is output.
+    pub (in crate) layers : Vec<Layer>,
+    // How opaque each pixel is while the screen is being drawn onto a layer, which starts out transparent, in the same order as the pixels.
+    pub (in crate) alpha : Option<Vec<f32>>,
 }
 
 impl Screen {
@@ -32,9 +53,8 @@ impl Screen {
     /// Creates a blank screen based on the resolution, bounds and colour.
     pub fn new(horizontal_resolution : u16, vertical_resolution : u16, bottom_left_bound : Point, top_right_bound : Point, default_colour : Colour) -> Screen {
 
-        let pixels = vec![
-            vec![default_colour; vertical_resolution.into()]; horizontal_resolution.into()
-        ];
+        let pixels =
+            vec![default_colour; usize::from(horizontal_resolution) * usize::from(vertical_resolution)];
 
         Screen {
             pixels,
@@ -42,414 +62,2348 @@ impl Screen {
             vertical_resolution,
             bottom_left_bound,
             top_right_bound,
+            pixel_scale : 1.0,
+            blending : Blending::Linear,
+            supersampling : None,
+            tile : None,
+            layers : Vec::new(),
+            alpha : None,
         }
     }
 
-    /// Writes the current screen to a 24-bit uncompressed BitMap at the specified location.
-    pub fn write_to_bitmap(&self, output_folder : &str, filename : &str) -> Result<(), errors::Error> {
-        let file_path =
-            generate_file_path(output_folder, filename, "bmp")?;
+    /// Creates a screen from an image, with one pixel for each pixel of the image and the provided bounds. Transparent parts of the image show the background colour.
+    pub fn from_image(image : &Image, bottom_left_bound : Point, top_right_bound : Point, background : Colour) -> Result<Screen, errors::Error> {
+        let (horizontal_resolution, vertical_resolution) =
+            match (u16::try_from(image.width), u16::try_from(image.height)) {
+                (Ok(width), Ok(height)) => (width, height),
+                _ => return Err(errors::Error::InvalidImage(format!("Image of {} by {} pixels is too large for a screen.", image.width, image.height))),
+            };
 
-        let bitmap_bytes =
-            create_bitmap_bytes(self);
+        let mut screen =
+            Screen::new(horizontal_resolution, vertical_resolution, bottom_left_bound, top_right_bound, background);
 
-        match File::create(&file_path) {
-            Ok(mut file) => {
-                match file.write_all(&bitmap_bytes) {
-                    Ok(()) => (), 
-                    Err(e) => return Err(errors::Error::FileWrite(file_path, e)),
-                }
-            }
-            Err(e) => {
-                return Err(errors::Error::FileCreation(file_path, e))
-            }
+        for (index, (colour, alpha)) in image.pixels.iter().enumerate() {
+            let x = index % image.width as usize;
+            let y = image.height as usize - 1 - index / image.width as usize;
+
+            screen[(x, y)] = screen.blend(background, *colour, f32::from(*alpha) / 255.0);
         }
 
-        //File::create(&file_path)
-        //.map_err(|e| errors::Error::FileCreation(file_path, e))?
-        //.write_all(&bitmap_bytes)
-        //.map_err(|e| errors::Error::FileWrite(file_path, e))?;
+        Ok(screen)
+    }
 
-        Ok(())
+    /// Sets how colours are blended when rendering, such as for anti-aliasing and averaging frames.
+    pub fn with_blending(mut self, blending : Blending) -> Screen {
+        self.blending = blending;
+        self
     }
 
-    /// Writes the current screen to a PNG using the PNG crate.
-    pub fn write_to_png(&self, output_folder : &str, filename : &str) -> Result<(), errors::Error> {
-        let file_path =
-            generate_file_path(output_folder, filename, "png")?;
+    /// Renders the screen internally at the provided multiple of its resolution, which is downsampled with the filter whenever the screen is written or encoded, smoothing the edges of every kind of object. Pixels that have already been rendered are enlarged after compositing any layers, and relative and absolute thicknesses keep their size in the output.
+    pub fn with_supersampling(self, factor : u16, filter : SupersamplingFilter) -> Screen {
+        let mut screen = self.output_screen().into_owned();
 
-        //let png_file =
-        //    File::create(&file_path)
-        //    .map_err(|e| errors::Error::FileCreation(file_path, e))?;
+        // The internal resolution must still fit in a u16.
+        let largest = screen.horizontal_resolution.max(screen.vertical_resolution).max(1);
+        let factor = factor.clamp(1, u16::MAX / largest);
 
-        let png_file =
-            match File::create(&file_path) {
-                Ok(file) => file,
-                Err(e) => return Err(errors::Error::FileCreation(file_path, e)),
-            };
-        
-        let buf_writer =
-            &mut BufWriter::new(png_file);
-
-        let mut encoder =
-            png::Encoder::new(
-                buf_writer,
-                u32::from(self.horizontal_resolution),
-                u32::from(self.vertical_resolution)
-            );
+        if factor == 1 {
+            return screen;
+        }
 
-        encoder.set_depth(png::BitDepth::Eight);
-        encoder.set_color(png::ColorType::Rgb);
-        
-        encoder.set_compression(png::Compression::Fast);
-        
-        let mut writer =
-            match encoder.write_header() {
-                Ok(writer) => writer,
-                Err(e) => return Err(errors::Error::PngError(file_path, e)),
-            };
+        let horizontal_resolution = screen.horizontal_resolution * factor;
+        let vertical_resolution = screen.vertical_resolution * factor;
 
-        //let mut writer =
-        //    encoder
-        //    .write_header()
-        //    .map_err(|e| errors::Error::PngError(file_path, e))?;
+        let mut pixels = Vec::with_capacity(usize::from(horizontal_resolution) * usize::from(vertical_resolution));
 
-        let data = create_rgb_byte_array(self);
+        for y in 0..usize::from(vertical_resolution) {
+            for colour in screen.row(y / usize::from(factor)) {
+                for _ in 0..factor {
+                    pixels.push(*colour);
+                }
+            }
+        }
 
-        writer
-        .write_image_data(&data)
-        .map_err(|e| errors::Error::PngError(file_path, e))?;
+        screen.pixels = pixels;
+        screen.horizontal_resolution = horizontal_resolution;
+        screen.vertical_resolution = vertical_resolution;
+        screen.pixel_scale *= f32::from(factor);
+        screen.supersampling = Some(Supersampling { factor, filter });
 
-        Ok(())
+        screen
     }
 
+    /// Gets the screen as it will be output, compositing its layers and downsampling it if it is supersampled.
+    pub (in crate) fn output_screen(&self) -> Cow<'_, Screen> {
+        let flattened =
+            match self.layers.is_empty() {
+                true => Cow::Borrowed(self),
+                false => Cow::Owned(self.clone().flatten()),
+            };
 
-    /// Renders the provided renderable type to the screen.
-    pub fn render<R>(mut self, renderable : R, settings : <R as Renderable>::RenderSettings) -> Self
-        where R: Renderable {
-        renderable.render(&settings, &mut self);
+        let supersampling =
+            match flattened.supersampling {
+                Some(supersampling) => supersampling,
+                None => return flattened,
+            };
 
-        self
+        let mut output =
+            match supersampling.filter {
+                SupersamplingFilter::Box => flattened.downscale(supersampling.factor),
+                SupersamplingFilter::Lanczos => flattened.lanczos_downscale(supersampling.factor),
+            };
+
+        output.supersampling = None;
+
+        Cow::Owned(output)
     }
 
-    /// Renders many of the provided  renderable type to the screen.
-    pub fn render_many<R>(mut self, renderables : Vec<Box<R>>, settings : <R as Renderable>::RenderSettings) -> Self
-        where R: Renderable {
+    /// Creates a lower resolution copy of the screen with the same bounds, dividing the resolution by the specified divisor with a separable Lanczos filter of 2 lobes, which is sharper than averaging.
+    fn lanczos_downscale(&self, divisor : u16) -> Screen {
+        const LOBES : f32 = 2.0;
 
-        Renderable::render_many(renderables, &settings, &mut self);
+        let lanczos = |x : f32| -> f32 {
+            if x.abs() < 1e-6 {
+                1.0
+            }
+            else if x.abs() >= LOBES {
+                0.0
+            }
+            else {
+                let pi_x = std::f32::consts::PI * x;
+                LOBES * pi_x.sin() * (pi_x / LOBES).sin() / (pi_x * pi_x)
+            }
+        };
 
-        self
-    }
+        let to_channel = |value : u8| -> f32 {
+            match self.blending {
+                Blending::Linear => byte_to_linear(value),
+                Blending::Legacy => f32::from(value) / 255.0,
+            }
+        };
 
-    /// Fills a region of a screen which has a solid colour with another solid colour.
-    pub fn fill(mut self, starting_point : Point, desired_colour : Colour) -> Self {
-        
-        let starting_location =
-            point_to_pixel_coordinates(&self, starting_point);
+        let from_channel = |value : f32| -> u8 {
+            match self.blending {
+                Blending::Linear => linear_to_byte(value.clamp(0.0, 1.0)),
+                Blending::Legacy => f32_to_u8((255.0 * value).clamp(0.0, 255.0).round()),
+            }
+        };
 
-        let initial_colour =
-            self.pixels
-            [usize::try_from(starting_location.x).unwrap()]
-            [usize::try_from(starting_location.y).unwrap()];
+        // Weights of the input pixels for each output pixel along one axis, normalised to sum to one.
+        let weights = |input : usize, output : usize| -> Vec<Vec<(usize, f32)>> {
+            let scale = f32::from(divisor);
 
-        if initial_colour != desired_colour {
+            (0..output)
+            .map(|o| {
+                let centre = (o as f32 + 0.5) * scale;
+                let first = (centre - LOBES * scale).floor().max(0.0) as usize;
+                let last = ((centre + LOBES * scale).ceil() as usize).min(input);
 
-            let mut current_checks = vec![starting_location];
+                let taps : Vec<(usize, f32)> =
+                    (first..last)
+                    .map(|i| (i, lanczos((i as f32 + 0.5 - centre) / scale)))
+                    .collect();
 
-            while !current_checks.is_empty() {
-                let current = current_checks[current_checks.len() - 1];
+                let total : f32 = taps.iter().map(|(_, weight)| weight).sum();
 
-                let is_on_screen =
-                    within_screen(current, &self);
+                taps.into_iter().map(|(i, weight)| (i, weight / total)).collect()
+            })
+            .collect()
+        };
 
-                let is_original_colour =
-                    self.pixels
-                    [usize::try_from(current.x).unwrap()]
-                    [usize::try_from(current.y).unwrap()] == initial_colour;
+        let input_width = usize::from(self.horizontal_resolution);
+        let input_height = usize::from(self.vertical_resolution);
+        let width = usize::from((self.horizontal_resolution / divisor).max(1));
+        let height = usize::from((self.vertical_resolution / divisor).max(1));
 
-                if is_on_screen && is_original_colour {
-                    
-                    self.pixels
-                    [usize::try_from(current.x).unwrap()]
-                    [usize::try_from(current.y).unwrap()] = desired_colour;
+        let horizontal_weights = weights(input_width, width);
+        let vertical_weights = weights(input_height, height);
 
-                    current_checks.pop();
+        // Filter each row horizontally, then each column of the result vertically.
+        let mut rows = vec![[0.0_f32; 3]; width * input_height];
 
-                    current_checks.push(PixelCoordinate::new(current.x, current.y + 1));
-                    current_checks.push(PixelCoordinate::new(current.x + 1, current.y));
-                    current_checks.push(PixelCoordinate::new(current.x, current.y - 1));
-                    current_checks.push(PixelCoordinate::new(current.x - 1, current.y));
-                }
-                else {
-                    current_checks.pop();
+        for y in 0..input_height {
+            let row = self.row(y);
+
+            for (x, taps) in horizontal_weights.iter().enumerate() {
+                let mut sum = [0.0; 3];
+
+                for (i, weight) in taps {
+                    sum[0] += weight * to_channel(row[*i].red);
+                    sum[1] += weight * to_channel(row[*i].green);
+                    sum[2] += weight * to_channel(row[*i].blue);
                 }
+
+                rows[y * width + x] = sum;
             }
         }
 
-        self
-    }
-}
-
-mod utilities {
-    use crate::rendering::*;
+        let mut pixels = Vec::with_capacity(width * height);
 
-    /// Gets the least upper bounding rectangle from a series of points.
-    pub (in crate) fn get_bounds(vertices : &Vec<Point>) -> (Point, Point) {
+        for taps in &vertical_weights {
+            for x in 0..width {
+                let mut sum = [0.0; 3];
 
-        let mut min_x = vertices[0].x;
-        let mut min_y = vertices[0].y;
-        let mut max_x = vertices[0].x;
-        let mut max_y = vertices[0].y;
+                for (j, weight) in taps {
+                    for channel in 0..3 {
+                        sum[channel] += weight * rows[j * width + x][channel];
+                    }
+                }
 
-        for point in vertices {
-            if point.x < min_x {
-                min_x = point.x;
-            }
-            if point.y < min_y {
-                min_y = point.y;
-            }
-            if point.x > max_x {
-                max_x = point.x;
-            }
-            if point.y > max_y {
-                max_y = point.y;
+                pixels.push(Colour::from_rgb(from_channel(sum[0]), from_channel(sum[1]), from_channel(sum[2])));
             }
         }
 
-        (Point::new(min_x, min_y), Point::new(max_x, max_y))
+        Screen {
+            pixels,
+            horizontal_resolution : width as u16,
+            vertical_resolution : height as u16,
+            bottom_left_bound : self.bottom_left_bound,
+            top_right_bound : self.top_right_bound,
+            pixel_scale : self.pixel_scale / f32::from(divisor),
+            blending : self.blending,
+            supersampling : None,
+            tile : None,
+            layers : Vec::new(),
+            alpha : None,
+        }
     }
 
-    /// Determines if the provided point lies within the provided polygon.
-    pub (in crate) fn is_inside_polygon(point : Point, vertices : &Vec<Point>) -> bool {
+    /// Gets the colour of a pixel, with the bottom left pixel at (0, 0), or None if it is outside the screen.
+    pub fn pixel(&self, x : u16, y : u16) -> Option<Colour> {
+        if within_screen(PixelCoordinate::new(i32::from(x), i32::from(y)), self) {
+            Some(self[(usize::from(x), usize::from(y))])
+        }
+        else {
+            None
+        }
+    }
 
-        let mut sides =
-            Vec::with_capacity(vertices.len());
+    /// Sets the colour of a pixel, with the bottom left pixel at (0, 0). Pixels outside the screen are ignored.
+    pub fn set_pixel(&mut self, x : u16, y : u16, colour : Colour) {
+        if within_screen(PixelCoordinate::new(i32::from(x), i32::from(y)), self) {
+            self.cover((usize::from(x), usize::from(y)), colour, 1.0);
+        }
+    }
 
-        sides.push(
-            (vertices[vertices.len() - 1], vertices[0])
-        );
+    /// Sets a pixel to a colour that a rasteriser has blended in by the proportion of the pixel it covers, which also makes the pixel more opaque when drawing onto a layer.
+    pub (in crate) fn cover(&mut self, (x, y) : (usize, usize), colour : Colour, coverage : f32) {
+        self[(x, y)] = colour;
 
-        for i in 0..(vertices.len() - 1) {
-            sides.push(
-                (vertices[i], vertices[i + 1])
-            )
+        let index = self.index_of(x, y);
+
+        if let Some(alpha) = self.alpha.as_mut() {
+            alpha[index] += (1.0 - alpha[index]) * coverage.clamp(0.0, 1.0);
         }
+    }
 
+    /// Gets the index of the pixel at (x, y) in the pixels held by the screen. Panics if it is outside the screen.
+    fn index_of(&self, x : usize, y : usize) -> usize {
+        assert!(x < usize::from(self.horizontal_resolution), "Pixel ({}, {}) is outside the screen.", x, y);
 
-        let mut is_inside = false;
+        (y - self.tile.map_or(0, |tile| usize::from(tile.bottom))) * usize::from(self.horizontal_resolution) + x
+    }
 
-        for (endpoint_1, endpoint_2) in sides {
-            if
-                (endpoint_1.y > point.y) != (endpoint_2.y > point.y)
-                &&
-                point.x < (endpoint_2.x - endpoint_1.x) * (point.y - endpoint_1.y) / (endpoint_2.y - endpoint_1.y) + endpoint_1.x {
+    /// Gets every pixel in rows from the bottom of the screen to the top.
+    pub fn pixels(&self) -> &[Colour] {
+        &self.pixels
+    }
 
-                is_inside = !is_inside;
-            }
-        }
+    /// Gets a row of pixels, with the bottom row at 0.
+    pub (in crate) fn row(&self, y : usize) -> &[Colour] {
+        let width = usize::from(self.horizontal_resolution);
 
-        is_inside
+        &self.pixels[y * width..(y + 1) * width]
     }
 
-    /// Linear interpolation of floats.
-    pub (in crate) fn lerp_scalar(start : f32, end : f32, parameter : f32) -> f32 {
-        (1.0 - parameter) * start + parameter * end
+    /// Gets the rows of pixels from the top of the screen to the bottom, as most image formats store them.
+    pub (in crate) fn rows_from_top(&self) -> impl Iterator<Item = &[Colour]> {
+        self.pixels.chunks(usize::from(self.horizontal_resolution).max(1)).rev()
     }
 
-    /// Converts a Point to a PixelCoordinate depending on the screen.
-    pub (in crate) fn point_to_pixel_coordinates(screen : &Screen, point : Point) -> PixelCoordinate {
-        let horizontal_parameter = (point.x - screen.bottom_left_bound.x) / (screen.top_right_bound.x - screen.bottom_left_bound.x);
+    /// Blends two colours according to the blending of the screen.
+    pub (in crate) fn blend(&self, start : Colour, finish : Colour, t : f32) -> Colour {
+        match self.blending {
+            Blending::Linear => Colour::lerp_linear_light(start, finish, t),
+            Blending::Legacy => Colour::lerp(start, finish, t),
+        }
+    }
 
-        let vertical_parameter = (point.y - screen.bottom_left_bound.y) / (screen.top_right_bound.y - screen.bottom_left_bound.y);
+    /// Writes the current screen to a 24-bit uncompressed BitMap at the specified location.
+    pub fn write_to_bitmap(&self, output_folder : &str, filename : &str) -> Result<(), errors::Error> {
+        let file_path =
+            generate_file_path(output_folder, filename, "bmp")?;
 
-        PixelCoordinate::new(
-            f32_to_i32(lerp_scalar(0.0, f32::from(screen.horizontal_resolution), horizontal_parameter)),
-            f32_to_i32(lerp_scalar(0.0, f32::from(screen.vertical_resolution), vertical_parameter)),
+        match File::create(&file_path) {
+            Ok(file) => {
+                self.encode_bmp(file)
+                .map_err(|e| e.at_path(file_path))
+            }
+            Err(e) => {
+                Err(errors::Error::FileCreation(file_path, e))
+            }
+        }
+    }
+
+    /// Writes the current screen to a PNG.
+    pub fn write_to_png(&self, output_folder : &str, filename : &str) -> Result<(), errors::Error> {
+        self.write_to_png_with_settings(
+            output_folder,
+            filename,
+            PngSettings::default()
         )
     }
 
-    /// Converts a PixelCoordinate to a Point depending on the screen.
-    pub (in crate) fn pixel_coordinates_to_point(screen : &Screen, coordinates : PixelCoordinate) -> Point {
-        let horizontal_parameter =
-            i32_to_f32(coordinates.x) / f32::from(screen.horizontal_resolution);
-        let vertical_parameter =
-            i32_to_f32(coordinates.y) / f32::from(screen.vertical_resolution);
+    /// Writes the current screen to a PNG with the provided colour type, compression and filtering. This uses the PNG crate by default, or the built in encoder with the `native-png` feature.
+    pub fn write_to_png_with_settings(&self, output_folder : &str, filename : &str, settings : PngSettings) -> Result<(), errors::Error> {
+        let file_path =
+            generate_file_path(output_folder, filename, "png")?;
 
-        Point::new(
-            lerp_scalar(screen.bottom_left_bound.x, screen.top_right_bound.x, horizontal_parameter),
-            lerp_scalar(screen.bottom_left_bound.y, screen.top_right_bound.y, vertical_parameter),
-        )
+        //let png_file =
+        //    File::create(&file_path)
+        //    .map_err(|e| errors::Error::FileCreation(file_path, e))?;
+
+        let png_file =
+            match File::create(&file_path) {
+                Ok(file) => file,
+                Err(e) => return Err(errors::Error::FileCreation(file_path, e)),
+            };
+
+        self.encode_png_with_settings(BufWriter::new(png_file), settings)
+        .map_err(|e| e.at_path(file_path))
     }
 
-    /// Determines if the provided PixelCoordinate lies on the screen.
-    pub (in crate) fn within_screen (coordinates : PixelCoordinate, screen : &Screen) -> bool {
-        coordinates.x >= 0
-        && coordinates.y >= 0
-        && coordinates.x < i32::from(screen.horizontal_resolution) 
-        && coordinates.y < i32::from(screen.vertical_resolution)
+    /// Encodes the current screen as a 24-bit uncompressed BitMap to the provided writer.
+    pub fn encode_bmp<W : Write>(&self, mut writer : W) -> Result<(), errors::Error> {
+        writer
+        .write_all(&create_bitmap_bytes(&self.output_screen()))
+        .and_then(|_| writer.flush())
+        .map_err(errors::Error::Write)
     }
-}
 
-/// Represents a point on the screen.
-#[derive(Copy, Clone, Debug)]
-pub (in crate) struct PixelCoordinate {
-    pub (in crate) x : i32,
-    pub (in crate) y : i32,
-}
+    /// Encodes the current screen as a PNG to the provided writer.
+    pub fn encode_png<W : Write>(&self, writer : W) -> Result<(), errors::Error> {
+        self.encode_png_with_settings(writer, PngSettings::default())
+    }
 
-impl PixelCoordinate {    
-    /// Creates a new PixelCoordinate.
-    fn new(x : i32, y : i32) -> PixelCoordinate {
-        PixelCoordinate {
-            x,
-            y,
+    /// Encodes the current screen as a PNG to the provided writer, with the provided colour type, compression and filtering.
+    pub fn encode_png_with_settings<W : Write>(&self, mut writer : W, settings : PngSettings) -> Result<(), errors::Error> {
+        let image =
+            create_png_image(&self.output_screen(), settings.colour);
+
+        #[cfg(feature = "native-png")]
+        {
+            write_png_natively(&mut writer, &image, settings.compression, settings.filter)
+            .and_then(|_| writer.flush())
+            .map_err(errors::Error::Write)
+        }
+
+        #[cfg(not(feature = "native-png"))]
+        {
+            write_png_with_crate(&mut writer, &image, settings)
+            .map_err(errors::Error::PngEncoding)
         }
     }
-}
 
-/// Possible rendering types.
-#[derive(Copy, Clone)]
-pub enum RenderingType {
-    Square,
-    RoundAntiAliased(f32),
-    RoundAliased,
-}
+    /// Writes the current screen to the specified path in the provided format, or in the format matching the extension of the path.
+    pub fn write(&self, path : &str, format : Format) -> Result<(), errors::Error> {
+        let file_path = std::path::PathBuf::from(path);
+
+        let format =
+            match format {
+                Format::FromExtension => {
+                    file_path
+                    .extension()
+                    .and_then(|extension| Format::from_extension(&extension.to_string_lossy()))
+                    .ok_or_else(|| errors::Error::UnknownFormat(String::from(path)))?
+                }
+                format => format,
+            };
 
-/// Line thickness and point radius represented either as a relative length based on the coordinate system or as an absolute length as a number of pixels. When specified relatively, the actual number of pixels will be an average of the number of pixels across the horizontal and vertical directions.
-#[derive(Copy, Clone)]
-pub enum Thickness {
-    Absolute(u16),
-    Relative(f32),
-}
+        let output_folder =
+            match file_path.parent() {
+                Some(parent) if parent.as_os_str().is_empty() => std::path::Path::new("."),
+                Some(parent) => parent,
+                None => return Err(errors::Error::InvalidDirectory(String::from(path))),
+            };
 
-impl Thickness {
-    pub fn to_pixels(&self, screen : &Screen) -> u16 { // only temporarily public
-        match self {
-            Thickness::Absolute(length) => {
-                *length
-            },
-            Thickness::Relative(length) => {
-                let horizontal = (length / (screen.top_right_bound.x - screen.bottom_left_bound.x)) * f32::try_from(screen.horizontal_resolution).unwrap();
-                let vertical = (length / (screen.top_right_bound.y - screen.bottom_left_bound.y)) * f32::try_from(screen.vertical_resolution).unwrap();
+        if !output_folder.is_dir() {
+            return Err(errors::Error::InvalidDirectory(output_folder.to_string_lossy().into_owned()));
+        }
 
-                f32_to_u16((horizontal + vertical) / 2.0)
+        match File::create(&file_path) {
+            Ok(file) => {
+                self.encode(BufWriter::new(file), format)
+                .map_err(|e| e.at_path(file_path))
+            }
+            Err(e) => {
+                Err(errors::Error::FileCreation(file_path, e))
             }
         }
     }
-}
 
-pub trait Renderable {
-    type RenderSettings;
+    /// Encodes the current screen in the provided format to the provided writer. The format cannot be inferred, as there is no extension.
+    pub fn encode<W : Write>(&self, mut writer : W, format : Format) -> Result<(), errors::Error> {
+        let screen = self.output_screen();
+
+        let bytes =
+            match format {
+                Format::Png(settings) => return self.encode_png_with_settings(writer, settings),
+                Format::Bitmap => create_bitmap_bytes(&screen),
+                Format::Bitmap32 => create_bitmap_32_bytes(&screen),
+                Format::Ppm => create_ppm_bytes(&screen),
+                Format::Pgm => create_pgm_bytes(&screen),
+                Format::Tga => create_tga_bytes(&screen),
+                Format::Qoi => create_qoi_bytes(&screen),
+                Format::FromExtension => return Err(errors::Error::UnknownFormat(String::new())),
+            };
 
-    /// Renders the renderable item on the provided screen.
-    fn render(self, settings : &Self::RenderSettings, screen : &mut Screen);
+        writer
+        .write_all(&bytes)
+        .and_then(|_| writer.flush())
+        .map_err(errors::Error::Write)
+    }
 
-    /// Renders many of the renderable item on the provided screen.
-    fn render_many(selfs : Vec<Box<Self>>, settings : &Self::RenderSettings, screen : &mut Screen);
-}
+    /// Gets the bytes of the current screen encoded as a 24-bit uncompressed BitMap.
+    pub fn to_bmp_bytes(&self) -> Vec<u8> {
+        create_bitmap_bytes(&self.output_screen())
+    }
 
-/// Represents the rendering settings for a Point.
-pub struct PointRenderSettings {
-    colour : Colour,
-    radius : Thickness,
-    rendering_type : RenderingType,
-}
+    /// Gets the bytes of the current screen encoded as a PNG. Panics if the screen has no pixels.
+    pub fn to_png_bytes(&self) -> Vec<u8> {
+        self.to_png_bytes_with_settings(PngSettings::default())
+    }
 
-impl PointRenderSettings {
-    /// Creates a new PointRenderSettings.
-    pub fn new(colour : Colour, radius : Thickness, rendering_type : RenderingType) -> PointRenderSettings {
-        PointRenderSettings {
-            colour,
-            radius,
-            rendering_type,
+    /// Gets the bytes of the current screen encoded as a PNG, with the provided colour type, compression and filtering. Panics if the screen has no pixels.
+    pub fn to_png_bytes_with_settings(&self, settings : PngSettings) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        if let Err(e) = self.encode_png_with_settings(&mut bytes, settings) {
+            panic!("{}", e.to_string());
         }
+
+        bytes
     }
-}
 
-impl Renderable for Point {
-    type RenderSettings =
-        PointRenderSettings;
+    /// Prints a downsampled preview of the screen to the terminal.
+    pub fn preview_terminal(&self, preview : TerminalPreview) {
+        print!("{}", self.terminal_preview_string(preview));
+    }
 
-    /// Renders a Point.
-    fn render(self, settings : &Self::RenderSettings, screen : &mut Screen) {
-        let coordinate = point_to_pixel_coordinates(screen, self);
+    /// Encodes a downsampled preview of the screen for printing to the terminal.
+    pub (in crate) fn terminal_preview_string(&self, preview : TerminalPreview) -> String {
+        match preview {
+            TerminalPreview::HalfBlock(columns) => create_half_block_string(&self.output_screen(), columns),
+            TerminalPreview::Sixel(width) => create_sixel_string(&self.output_screen(), width),
+        }
+    }
 
-        // this radius should be in terms of the coordinate system, and therefore needs to be converted properly using similar method to point to pixel coordinates
-        let radius = i32::from(
-                settings.radius.to_pixels(screen)
-            );
+    /// Creates a lower resolution copy of the screen with the same bounds, dividing the resolution by the specified divisor and averaging the pixels covered by each new pixel.
+    pub (in crate) fn downscale(&self, divisor : u16) -> Screen {
+        let divisor = divisor.max(1);
 
-        for i in (coordinate.x - radius)..(coordinate.x + radius) {
-            for j in (coordinate.y - radius)..(coordinate.y + radius) {
+        let horizontal_resolution = (self.horizontal_resolution / divisor).max(1);
+        let vertical_resolution = (self.vertical_resolution / divisor).max(1);
 
-                let is_on_screen =
-                    within_screen(PixelCoordinate::new(i, j), screen);
+        let mut pixels = Vec::with_capacity(usize::from(horizontal_resolution) * usize::from(vertical_resolution));
 
-                let squared_distance =
-                    (i - coordinate.x) * (i - coordinate.x) + (j - coordinate.y) * (j - coordinate.y);
+        for y in 0..usize::from(vertical_resolution) {
+            for x in 0..usize::from(horizontal_resolution) {
+                let mut total = ColourTotal::new(self.blending);
 
-                let within_radius =
-                    squared_distance < radius * radius;
+                for j in (y * usize::from(divisor))..((y + 1) * usize::from(divisor)).min(self.vertical_resolution.into()) {
+                    let row = self.row(j);
+
+                    for colour in &row[(x * usize::from(divisor))..((x + 1) * usize::from(divisor)).min(row.len())] {
+                        total.add(*colour, 1.0);
+                    }
+                }
+
+                pixels.push(total.average());
+            }
+        }
+
+        // Premultiplied layers can be averaged directly.
+        let width = usize::from(self.horizontal_resolution);
+
+        let layers =
+            self.layers
+            .iter()
+            .map(|layer| {
+                let mut layer_pixels = Vec::with_capacity(pixels.len());
+
+                for y in 0..usize::from(vertical_resolution) {
+                    for x in 0..usize::from(horizontal_resolution) {
+                        let mut total = ([0.0; 3], 0.0);
+                        let mut count = 0.0;
+
+                        for j in (y * usize::from(divisor))..((y + 1) * usize::from(divisor)).min(self.vertical_resolution.into()) {
+                            for i in (x * usize::from(divisor))..((x + 1) * usize::from(divisor)).min(width) {
+                                let (channels, alpha) = layer.pixels[j * width + i];
+
+                                for (sum, channel) in total.0.iter_mut().zip(channels) {
+                                    *sum += channel;
+                                }
+                                total.1 += alpha;
+                                count += 1.0;
+                            }
+                        }
+
+                        layer_pixels.push((total.0.map(|channel| channel / count), total.1 / count));
+                    }
+                }
+
+                Layer {
+                    name : layer.name.clone(),
+                    pixels : layer_pixels,
+                    settings : layer.settings,
+                }
+            })
+            .collect();
+
+        Screen {
+            pixels,
+            horizontal_resolution,
+            vertical_resolution,
+            bottom_left_bound : self.bottom_left_bound,
+            top_right_bound : self.top_right_bound,
+            pixel_scale : self.pixel_scale / f32::from(divisor),
+            blending : self.blending,
+            supersampling : self.supersampling,
+            tile : None,
+            layers,
+            alpha : None,
+        }
+    }
+
+    /// Averages the colour of each pixel across many screens of the same resolution and bounds.
+    pub (in crate) fn average(screens : Vec<Screen>) -> Screen {
+        let screens : Vec<Screen> =
+            screens
+            .into_iter()
+            .map(Screen::flatten)
+            .collect();
+
+        let blending = screens[0].blending;
+
+        let mut totals =
+            vec![ColourTotal::new(blending); screens[0].pixels.len()];
+
+        for screen in &screens {
+            for (total, colour) in totals.iter_mut().zip(&screen.pixels) {
+                total.add(*colour, 1.0);
+            }
+        }
+
+        let mut averaged = screens.into_iter().next().unwrap();
+
+        for (pixel, total) in averaged.pixels.iter_mut().zip(totals) {
+            *pixel = total.average();
+        }
+
+        averaged
+    }
+
+    /// Transforms the screen to simulate how it appears to someone with the specified colour vision deficiency, compositing its layers first.
+    pub fn simulate_colour_vision(self, deficiency : ColourVisionDeficiency) -> Screen {
+        let mut screen = self.flatten();
+        let mut simulated : HashMap<(u8, u8, u8), Colour> = HashMap::new();
+
+        for pixel in screen.pixels.iter_mut() {
+            *pixel =
+                *simulated
+                .entry((pixel.red, pixel.green, pixel.blue))
+                .or_insert_with(|| {
+                    let (r, g, b) =
+                        deficiency.simulate_linear((byte_to_linear(pixel.red), byte_to_linear(pixel.green), byte_to_linear(pixel.blue)));
+
+                    Colour::from_rgb(linear_to_byte(r), linear_to_byte(g), linear_to_byte(b))
+                });
+        }
+
+        screen
+    }
+
+    /// Renders the provided renderable type to the screen.
+    pub fn render<R>(mut self, renderable : R, settings : <R as Renderable>::RenderSettings) -> Self
+        where R: Renderable {
+        if self.touches(renderable.bounding_box(&settings, &self)) {
+            renderable.render(&settings, &mut self);
+        }
+
+        self
+    }
+
+    /// Renders many of the provided  renderable type to the screen.
+    pub fn render_many<R>(mut self, renderables : Vec<Box<R>>, settings : <R as Renderable>::RenderSettings) -> Self
+        where R: Renderable {
+
+        let renderables =
+            match self.tile {
+                Some(_) => renderables.into_iter().filter(|renderable| self.touches(renderable.bounding_box(&settings, &self))).collect(),
+                None => renderables,
+            };
+
+        Renderable::render_many(renderables, &settings, &mut self);
+
+        self
+    }
+
+    /// Renders a frame across many threads by splitting the screen into horizontal tiles, each of which is drawn by the provided function and only rasterises the renderables whose bounding box touches it. The output is identical to drawing the whole screen at once.
+    ///
+    /// # Panics
+    ///
+    /// The function must only render, clip and add layers. Fills spread across tiles, so calling fill or fill_with_paint from it panics, as does changing the resolution of a tile.
+    pub fn render_tiled<F>(self, tiles : u16, draw : F) -> Screen
+        where F: Fn(Screen) -> Screen + Sync {
+
+        let tiles = tiles.clamp(1, self.vertical_resolution.max(1));
+
+        if tiles == 1 || self.tile.is_some() {
+            return draw(self);
+        }
+
+        let width = usize::from(self.horizontal_resolution);
+        let rows = u32::from(self.vertical_resolution);
+
+        let bands : Vec<Screen> =
+            (0..u32::from(tiles))
+            .map(|tile| {
+                let bottom = u16::try_from(rows * tile / u32::from(tiles)).unwrap();
+                let top = u16::try_from(rows * (tile + 1) / u32::from(tiles)).unwrap();
+
+                Screen {
+                    pixels : self.pixels[usize::from(bottom) * width..usize::from(top) * width].to_vec(),
+                    horizontal_resolution : self.horizontal_resolution,
+                    vertical_resolution : self.vertical_resolution,
+                    bottom_left_bound : self.bottom_left_bound,
+                    top_right_bound : self.top_right_bound,
+                    pixel_scale : self.pixel_scale,
+                    blending : self.blending,
+                    supersampling : self.supersampling,
+                    tile : Some(Tile { bottom, top }),
+                    layers : self.layers.iter().map(|layer| Layer {
+                        name : layer.name.clone(),
+                        pixels : layer.pixels[usize::from(bottom) * width..usize::from(top) * width].to_vec(),
+                        settings : layer.settings,
+                    }).collect(),
+                    alpha : self.alpha.as_ref().map(|alpha| alpha[usize::from(bottom) * width..usize::from(top) * width].to_vec()),
+                }
+            })
+            .collect();
+
+        let draw = &draw;
+
+        let drawn : Vec<Screen> = thread::scope(|scope| {
+            let handles : Vec<_> =
+                bands
+                .into_iter()
+                .map(|band| scope.spawn(move || draw(band)))
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+                .collect()
+        });
+
+        let mut pixels = Vec::with_capacity(self.pixels.len());
+        let mut layers : Vec<Layer> = Vec::new();
+        let mut alpha = self.alpha.as_ref().map(|alpha| Vec::with_capacity(alpha.len()));
+
+        for (index, band) in drawn.into_iter().enumerate() {
+            assert!(band.tile.is_some() && band.horizontal_resolution == self.horizontal_resolution, "Tiles must only be rendered onto.");
+
+            pixels.extend(band.pixels);
+
+            if let (Some(alpha), Some(band_alpha)) = (alpha.as_mut(), band.alpha) {
+                alpha.extend(band_alpha);
+            }
+
+            // Every tile adds the same layers, since they are drawn by the same function.
+            if index == 0 {
+                layers = band.layers;
+            }
+            else {
+                for (layer, band_layer) in layers.iter_mut().zip(band.layers) {
+                    layer.pixels.extend(band_layer.pixels);
+                }
+            }
+        }
+
+        assert!(pixels.len() == self.pixels.len(), "Tiles must only be rendered onto.");
+
+        Screen {
+            pixels,
+            layers,
+            alpha,
+            ..self
+        }
+    }
+
+    /// Gets the rows of pixels held by the screen, which is every row unless it is a tile.
+    pub (in crate) fn stored_rows(&self) -> Range<i32> {
+        match self.tile {
+            Some(tile) => i32::from(tile.bottom)..i32::from(tile.top),
+            None => 0..i32::from(self.vertical_resolution),
+        }
+    }
+
+    /// Determines if a bounding box could cover any of the rows held by the screen, where no bounding box covers the whole screen.
+    fn touches(&self, bounding_box : Option<(Point, Point)>) -> bool {
+        let (bottom_left, top_right) =
+            match (self.tile, bounding_box) {
+                (Some(_), Some(bounding_box)) => bounding_box,
+                _ => return true,
+            };
+
+        // Bounding boxes are already padded by the thickness of what is drawn, and the rasterisers round to the nearest row in the same way, so the rows of the corners bound every row drawn to.
+        let bottom = point_to_pixel_coordinates(self, bottom_left).y;
+        let top = point_to_pixel_coordinates(self, top_right).y;
+
+        let rows = self.stored_rows();
+
+        bottom.min(top) < rows.end && bottom.max(top) >= rows.start
+    }
+
+    /// Fills a region of a screen which has a solid colour with another solid colour.
+    pub fn fill(self, starting_point : Point, desired_colour : Colour) -> Self {
+        self.fill_with_paint(starting_point, Paint::Solid(desired_colour))
+    }
+
+    /// Fills a region of a screen which has a solid colour with the provided paint, such as a gradient.
+    pub fn fill_with_paint(mut self, starting_point : Point, paint : Paint) -> Self {
+        assert!(self.tile.is_none(), "Fills cannot be used while rendering in tiles, since they spread across the whole screen.");
+
+        let starting_location =
+            point_to_pixel_coordinates(&self, starting_point);
+
+        if !within_screen(starting_location, &self) {
+            return self;
+        }
+
+        let initial_colour =
+            self[(usize::try_from(starting_location.x).unwrap(), usize::try_from(starting_location.y).unwrap())];
+
+        // Pixels are only filled once, since the paint may give them the same colour they started with.
+        let width = usize::from(self.horizontal_resolution);
+        let mut visited =
+            vec![false; self.pixels.len()];
+
+        let mut current_checks = vec![starting_location];
+
+        while let Some(current) = current_checks.pop() {
+
+            if !within_screen(current, &self) {
+                continue;
+            }
+
+            let x = usize::try_from(current.x).unwrap();
+            let y = usize::try_from(current.y).unwrap();
+
+            if !visited[y * width + x] && self[(x, y)] == initial_colour {
+                visited[y * width + x] = true;
+
+                let colour = paint.colour_at(pixel_coordinates_to_point(&self, current));
+
+                self.cover((x, y), colour, 1.0);
+
+                current_checks.push(PixelCoordinate::new(current.x, current.y + 1));
+                current_checks.push(PixelCoordinate::new(current.x + 1, current.y));
+                current_checks.push(PixelCoordinate::new(current.x, current.y - 1));
+                current_checks.push(PixelCoordinate::new(current.x - 1, current.y));
+            }
+        }
+
+        self
+    }
+
+    /// Renders onto the screen through a clip, so that whatever the provided function draws only shows inside the shape, with anti-aliased edges. Clips can be nested, in which case only the intersection of them is drawn to.
+    pub fn with_clip<F>(self, clip : Clip, draw : F) -> Screen
+        where F: FnOnce(Screen) -> Screen {
+
+        let coverage = clip.coverage(&self);
+        let before = self.pixels.clone();
+        let layers_before = self.layers.clone();
+        let alpha_before = self.alpha.clone();
+
+        let mut screen = draw(self);
+
+        assert!(screen.pixels.len() == before.len(), "Clipped screens must only be rendered onto.");
+
+        for (index, (previous, covered)) in before.into_iter().zip(coverage.iter()).enumerate() {
+            if *covered < 1.0 {
+                screen.pixels[index] = screen.blend(previous, screen.pixels[index], *covered);
+            }
+        }
+
+        if let (Some(alpha), Some(alpha_before)) = (screen.alpha.as_mut(), alpha_before) {
+            for ((alpha, previous), covered) in alpha.iter_mut().zip(alpha_before).zip(&coverage) {
+                *alpha = previous + (*alpha - previous) * covered;
+            }
+        }
+
+        // Layers are clipped in the same way, with new layers starting out transparent.
+        for (index, layer) in screen.layers.iter_mut().enumerate() {
+            for (pixel_index, (pixel, covered)) in layer.pixels.iter_mut().zip(coverage.iter()).enumerate() {
+                if *covered < 1.0 {
+                    let (previous_channels, previous_alpha) =
+                        layers_before.get(index).map_or(([0.0; 3], 0.0), |layer| layer.pixels[pixel_index]);
+
+                    for (channel, previous) in pixel.0.iter_mut().zip(previous_channels) {
+                        *channel = previous + (*channel - previous) * covered;
+                    }
+                    pixel.1 = previous_alpha + (pixel.1 - previous_alpha) * covered;
+                }
+            }
+        }
+
+        screen
+    }
+
+    /// Renders onto a named layer above the screen, which is created with the provided settings if it does not exist yet and is otherwise given them. Layers keep what is drawn on them separate from the rest of the screen until it is output, where they are composited from the bottom to the top with their blend modes and opacities. The function draws onto the layer alone, which starts out transparent black, so it can also fill the transparent parts of it.
+    pub fn with_layer<F>(mut self, name : &str, settings : LayerSettings, draw : F) -> Screen
+        where F: FnOnce(Screen) -> Screen {
+
+        let index =
+            match self.layers.iter().position(|layer| layer.name == name) {
+                Some(index) => index,
+                None => {
+                    self.layers.push(Layer {
+                        name : name.to_string(),
+                        pixels : vec![([0.0; 3], 0.0); self.pixels.len()],
+                        settings,
+                    });
+
+                    self.layers.len() - 1
+                }
+            };
+
+        self.layers[index].settings = settings;
+
+        // Blending a colour over a premultiplied pixel by the proportion covered is compositing it over the layer, so the rasterisers draw onto the layer over black while keeping track of its alpha.
+        let layer = Screen {
+            pixels : self.layers[index].pixels.iter().map(|(channels, _)| self.blending.colour(*channels)).collect(),
+            horizontal_resolution : self.horizontal_resolution,
+            vertical_resolution : self.vertical_resolution,
+            bottom_left_bound : self.bottom_left_bound,
+            top_right_bound : self.top_right_bound,
+            pixel_scale : self.pixel_scale,
+            blending : self.blending,
+            supersampling : self.supersampling,
+            tile : self.tile,
+            layers : Vec::new(),
+            alpha : Some(self.layers[index].pixels.iter().map(|(_, alpha)| *alpha).collect()),
+        };
+
+        let drawn = draw(layer).flatten();
+
+        let alpha =
+            match drawn.alpha {
+                Some(alpha) if drawn.pixels.len() == self.pixels.len() => alpha,
+                _ => panic!("Layers must only be rendered onto."),
+            };
+
+        for (pixel, (colour, alpha)) in self.layers[index].pixels.iter_mut().zip(drawn.pixels.into_iter().zip(alpha)) {
+            *pixel = (self.blending.channels(colour).map(|channel| channel.min(alpha)), alpha);
+        }
+
+        self
+    }
+
+    /// Composites the layers of the screen onto it from the bottom to the top, leaving a screen without layers.
+    pub fn flatten(mut self) -> Screen {
+        let layers = std::mem::take(&mut self.layers);
+
+        for layer in layers {
+            let opacity = layer.settings.opacity.clamp(0.0, 1.0);
+
+            // Layers within a layer also make it more opaque where they cover it.
+            if let Some(screen_alpha) = self.alpha.as_mut() {
+                for (screen_alpha, (_, alpha)) in screen_alpha.iter_mut().zip(&layer.pixels) {
+                    *screen_alpha += (1.0 - *screen_alpha) * alpha * opacity;
+                }
+            }
+
+            for (pixel, (channels, alpha)) in self.pixels.iter_mut().zip(layer.pixels) {
+                if alpha <= 0.0 {
+                    continue;
+                }
+
+                let base = self.blending.channels(*pixel);
+                let source = channels.map(|channel| (channel / alpha).clamp(0.0, 1.0));
+
+                let mut composited = [0.0; 3];
+
+                for channel in 0..3 {
+                    let blended = layer.settings.blend_mode.blend(base[channel], source[channel]);
+
+                    composited[channel] = base[channel] + (blended - base[channel]) * alpha * opacity;
+                }
+
+                *pixel = self.blending.colour(composited);
+            }
+        }
+
+        self
+    }
+}
+
+impl Index<(usize, usize)> for Screen {
+    type Output = Colour;
+
+    /// Gets the pixel at (x, y), with the bottom left pixel at (0, 0). Panics if it is outside the screen.
+    fn index(&self, (x, y) : (usize, usize)) -> &Colour {
+        &self.pixels[self.index_of(x, y)]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Screen {
+    /// Gets the pixel at (x, y) mutably, with the bottom left pixel at (0, 0). Panics if it is outside the screen.
+    fn index_mut(&mut self, (x, y) : (usize, usize)) -> &mut Colour {
+        let index = self.index_of(x, y);
+
+        &mut self.pixels[index]
+    }
+}
+
+// Shared with the terminal preview, which averages blocks of pixels in the same way.
+pub (in crate) use utilities::ColourTotal;
+
+mod utilities {
+    use crate::rendering::*;
+    use crate::colours::{byte_to_linear, linear_to_byte};
+
+    /// Determines if the provided point lies within the provided polygon.
+    pub (in crate) fn is_inside_polygon(point : Point, vertices : &Vec<Point>) -> bool {
+
+        let mut sides =
+            Vec::with_capacity(vertices.len());
+
+        sides.push(
+            (vertices[vertices.len() - 1], vertices[0])
+        );
+
+        for i in 0..(vertices.len() - 1) {
+            sides.push(
+                (vertices[i], vertices[i + 1])
+            )
+        }
+
+
+        let mut is_inside = false;
+
+        for (endpoint_1, endpoint_2) in sides {
+            if
+                (endpoint_1.y > point.y) != (endpoint_2.y > point.y)
+                &&
+                point.x < (endpoint_2.x - endpoint_1.x) * (point.y - endpoint_1.y) / (endpoint_2.y - endpoint_1.y) + endpoint_1.x {
+
+                is_inside = !is_inside;
+            }
+        }
+
+        is_inside
+    }
+
+    /// Converts a Point to a PixelCoordinate depending on the screen.
+    pub (in crate) fn point_to_pixel_coordinates(screen : &Screen, point : Point) -> PixelCoordinate {
+        let horizontal_parameter = (point.x - screen.bottom_left_bound.x) / (screen.top_right_bound.x - screen.bottom_left_bound.x);
+
+        let vertical_parameter = (point.y - screen.bottom_left_bound.y) / (screen.top_right_bound.y - screen.bottom_left_bound.y);
+
+        PixelCoordinate::new(
+            f32_to_i32(lerp_scalar(0.0, f32::from(screen.horizontal_resolution), horizontal_parameter)),
+            f32_to_i32(lerp_scalar(0.0, f32::from(screen.vertical_resolution), vertical_parameter)),
+        )
+    }
+
+    /// Converts a PixelCoordinate to a Point depending on the screen.
+    pub (in crate) fn pixel_coordinates_to_point(screen : &Screen, coordinates : PixelCoordinate) -> Point {
+        let horizontal_parameter =
+            i32_to_f32(coordinates.x) / f32::from(screen.horizontal_resolution);
+        let vertical_parameter =
+            i32_to_f32(coordinates.y) / f32::from(screen.vertical_resolution);
+
+        Point::new(
+            lerp_scalar(screen.bottom_left_bound.x, screen.top_right_bound.x, horizontal_parameter),
+            lerp_scalar(screen.bottom_left_bound.y, screen.top_right_bound.y, vertical_parameter),
+        )
+    }
+
+    /// Accumulates weighted colours to be averaged, in linear light or directly on the sRGB values depending on the blending.
+    #[derive(Copy, Clone)]
+    pub (in crate) struct ColourTotal {
+        blending : Blending,
+        red : f32,
+        green : f32,
+        blue : f32,
+        weight : f32,
+    }
+
+    impl ColourTotal {
+        /// Creates an empty ColourTotal.
+        pub (in crate) fn new(blending : Blending) -> ColourTotal {
+            ColourTotal {
+                blending,
+                red : 0.0,
+                green : 0.0,
+                blue : 0.0,
+                weight : 0.0,
+            }
+        }
+
+        /// Adds a colour with the provided weight.
+        pub (in crate) fn add(&mut self, colour : Colour, weight : f32) {
+            let channel = |value : u8| {
+                match self.blending {
+                    Blending::Linear => byte_to_linear(value),
+                    Blending::Legacy => f32::from(value) / 255.0,
+                }
+            };
+
+            self.red += weight * channel(colour.red);
+            self.green += weight * channel(colour.green);
+            self.blue += weight * channel(colour.blue);
+            self.weight += weight;
+        }
+
+        /// Calculates the weighted average of the added colours.
+        pub (in crate) fn average(&self) -> Colour {
+            let weight = if self.weight == 0.0 { 1.0 } else { self.weight };
+
+            let channel = |value : f32| {
+                match self.blending {
+                    Blending::Linear => linear_to_byte(value / weight),
+                    Blending::Legacy => f32_to_u8((255.0 * value / weight).clamp(0.0, 255.0).round()),
+                }
+            };
+
+            Colour::from_rgb(
+                channel(self.red),
+                channel(self.green),
+                channel(self.blue)
+            )
+        }
+    }
+
+    /// Determines if the provided PixelCoordinate lies on the screen.
+    pub (in crate) fn within_screen (coordinates : PixelCoordinate, screen : &Screen) -> bool {
+        coordinates.x >= 0
+        && coordinates.y >= 0
+        && coordinates.x < i32::from(screen.horizontal_resolution) 
+        && coordinates.y < i32::from(screen.vertical_resolution)
+        && screen.stored_rows().contains(&coordinates.y)
+    }
+
+    /// Extends a bounding box by a number of pixels in every direction.
+    pub (in crate) fn pad_bounds(screen : &Screen, (bottom_left, top_right) : (Point, Point), pixels : u16) -> (Point, Point) {
+        let horizontal = (screen.top_right_bound.x - screen.bottom_left_bound.x).abs() / f32::from(screen.horizontal_resolution) * f32::from(pixels);
+        let vertical = (screen.top_right_bound.y - screen.bottom_left_bound.y).abs() / f32::from(screen.vertical_resolution) * f32::from(pixels);
+
+        (
+            Point::new(bottom_left.x - horizontal, bottom_left.y - vertical),
+            Point::new(top_right.x + horizontal, top_right.y + vertical),
+        )
+    }
+}
+
+/// Represents a point on the screen.
+#[derive(Copy, Clone, Debug)]
+pub (in crate) struct PixelCoordinate {
+    pub (in crate) x : i32,
+    pub (in crate) y : i32,
+}
+
+impl PixelCoordinate {    
+    /// Creates a new PixelCoordinate.
+    fn new(x : i32, y : i32) -> PixelCoordinate {
+        PixelCoordinate {
+            x,
+            y,
+        }
+    }
+}
+
+/// Represents the settings for encoding a PNG.
+#[derive(Copy, Clone)]
+pub struct PngSettings {
+    pub (in crate) colour : PngColour,
+    pub (in crate) compression : PngCompression,
+    pub (in crate) filter : PngFilter,
+}
+
+impl Default for PngSettings {
+    /// The settings used by write_to_png, which are 24-bit RGB with fast compression and the sub filter.
+    fn default() -> PngSettings {
+        PngSettings::new(PngColour::Rgb, PngCompression::Fast, PngFilter::Sub)
+    }
+}
+
+impl PngSettings {
+    /// Creates a new PngSettings.
+    pub fn new(colour : PngColour, compression : PngCompression, filter : PngFilter) -> PngSettings {
+        PngSettings {
+            colour,
+            compression,
+            filter,
+        }
+    }
+}
+
+/// Possible image formats to write a screen in. FromExtension chooses the format from the extension of the path, using the default PNG settings for `.png`.
+#[derive(Copy, Clone)]
+pub enum Format {
+    FromExtension,
+    Png(PngSettings),
+    Bitmap,
+    Bitmap32,
+    Ppm,
+    Pgm,
+    Tga,
+    Qoi,
+}
+
+impl Format {
+    /// Gets the format matching a file extension, ignoring case.
+    pub fn from_extension(extension : &str) -> Option<Format> {
+        match extension.to_ascii_lowercase().as_str() {
+            "png" => Some(Format::Png(PngSettings::default())),
+            "bmp" | "dib" => Some(Format::Bitmap),
+            "ppm" => Some(Format::Ppm),
+            "pgm" => Some(Format::Pgm),
+            "tga" => Some(Format::Tga),
+            "qoi" => Some(Format::Qoi),
+            _ => None,
+        }
+    }
+}
+
+/// Possible colour types for a PNG, either 24-bit RGB, 32-bit RGBA with an opaque alpha channel, or indexed colour with a palette of at most the specified number of colours (up to 256). Indexed images use an exact palette when the screen has few enough colours, and otherwise a median cut palette with Floyd-Steinberg dithering. Smaller palettes are written with fewer bits per pixel.
+#[derive(Copy, Clone)]
+pub enum PngColour {
+    Rgb,
+    Rgba,
+    Indexed(u16),
+}
+
+/// Possible compression levels for a PNG, trading encoding time for file size.
+#[derive(Copy, Clone)]
+pub enum PngCompression {
+    Fast,
+    Default,
+    Best,
+}
+
+/// Possible filter strategies for a PNG, which transform each row before compression. Adaptive chooses the best filter for each row, and usually gives the smallest files for RGB images, while indexed images usually compress best with no filter.
+#[derive(Copy, Clone, PartialEq)]
+pub enum PngFilter {
+    None,
+    Sub,
+    Up,
+    Average,
+    Paeth,
+    Adaptive,
+}
+
+/// Represents a decoded PNG or BitMap, which can be rendered into a rectangle or used to create a screen.
+#[derive(Clone)]
+pub struct Image {
+    width : u32,
+    height : u32,
+    // Colours and alphas of each pixel, from the top row to the bottom row.
+    pixels : Vec<(Colour, u8)>,
+}
+
+impl Image {
+    /// Creates a new Image.
+    pub (in crate) fn new(width : u32, height : u32, pixels : Vec<(Colour, u8)>) -> Image {
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Loads a PNG or uncompressed BitMap from the specified path.
+    pub fn load(path : &str) -> Result<Image, errors::Error> {
+        let bytes =
+            std::fs::read(path)
+            .map_err(|e| errors::Error::FileRead(std::path::PathBuf::from(path), e))?;
+
+        decode_image(&bytes)
+    }
+
+    /// Decodes a PNG or uncompressed BitMap from the provided reader.
+    pub fn decode<R : Read>(mut reader : R) -> Result<Image, errors::Error> {
+        let mut bytes = Vec::new();
+
+        reader
+        .read_to_end(&mut bytes)
+        .map_err(errors::Error::Read)?;
+
+        decode_image(&bytes)
+    }
+
+    /// Gets the width of the image in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Gets the height of the image in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Gets the colour and alpha of a pixel, with the bottom left pixel at (0, 0).
+    fn pixel(&self, x : u32, y : u32) -> (Colour, u8) {
+        self.pixels[((self.height - 1 - y) * self.width + x) as usize]
+    }
+
+    /// Samples the colour and opacity at the provided fractions of the width and height from the bottom left.
+    pub (in crate) fn sample(&self, u : f32, v : f32, sampling : ImageSampling, blending : Blending) -> (Colour, f32) {
+        let x = u * u32_to_f32(self.width);
+        let y = v * u32_to_f32(self.height);
+
+        match sampling {
+            ImageSampling::Nearest => {
+                let (colour, alpha) =
+                    self.pixel(
+                        f32_to_u32(x.floor().clamp(0.0, u32_to_f32(self.width - 1))),
+                        f32_to_u32(y.floor().clamp(0.0, u32_to_f32(self.height - 1)))
+                    );
+
+                (colour, f32::from(alpha) / 255.0)
+            }
+            ImageSampling::Bilinear => {
+                // Interpolate between the centres of the four nearest pixels.
+                let x = (x - 0.5).clamp(0.0, u32_to_f32(self.width - 1));
+                let y = (y - 0.5).clamp(0.0, u32_to_f32(self.height - 1));
+
+                let (left, bottom) = (f32_to_u32(x.floor()), f32_to_u32(y.floor()));
+                let (right, top) = ((left + 1).min(self.width - 1), (bottom + 1).min(self.height - 1));
+                let (horizontal, vertical) = (x - x.floor(), y - y.floor());
+
+                let mut total = ColourTotal::new(blending);
+                let mut opacity = 0.0;
+
+                for (i, j, weight) in [
+                    (left, bottom, (1.0 - horizontal) * (1.0 - vertical)),
+                    (right, bottom, horizontal * (1.0 - vertical)),
+                    (left, top, (1.0 - horizontal) * vertical),
+                    (right, top, horizontal * vertical),
+                ] {
+                    let (colour, alpha) = self.pixel(i, j);
+                    let alpha = f32::from(alpha) / 255.0;
+
+                    // Weighting by alpha stops the colour of transparent pixels bleeding into their neighbours.
+                    total.add(colour, weight * alpha);
+                    opacity += weight * alpha;
+                }
+
+                (total.average(), opacity)
+            }
+        }
+    }
+}
+
+/// Possible ways of sampling an image when it is rendered at a different size, either using the nearest pixel, or interpolating between the four nearest pixels.
+#[derive(Copy, Clone)]
+pub enum ImageSampling {
+    Nearest,
+    Bilinear,
+}
+
+/// Represents the band of rows from the bottom row up to but not including the top row held by one tile of a screen.
+#[derive(Copy, Clone)]
+pub (in crate) struct Tile {
+    bottom : u16,
+    top : u16,
+}
+
+/// Represents how a screen is supersampled, with the multiple of the output resolution it is rendered at and the filter used to downsample it.
+#[derive(Copy, Clone)]
+pub (in crate) struct Supersampling {
+    factor : u16,
+    filter : SupersamplingFilter,
+}
+
+/// Possible filters for downsampling a supersampled screen, either averaging each block of pixels, or a Lanczos filter with 2 lobes, which keeps more detail but can ring slightly around hard edges.
+#[derive(Copy, Clone)]
+pub enum SupersamplingFilter {
+    Box,
+    Lanczos,
+}
+
+/// Possible formats for previewing a screen in the terminal, either as truecolour ANSI half block characters with the width in columns, or as sixel graphics with the width in pixels.
+#[derive(Copy, Clone)]
+pub enum TerminalPreview {
+    HalfBlock(u16),
+    Sixel(u16),
+}
+
+/// Represents how an area or line is coloured, either with a solid colour or with a gradient from a colour scale in the coordinate system of the screen. Linear gradients run from the start point to the end point, radial gradients from the centre out to the radius, and conic gradients anticlockwise around the centre from the starting angle in radians.
+#[derive(Clone)]
+pub enum Paint {
+    Solid(Colour),
+    Linear(Point, Point, ColourScale),
+    Radial(Point, f32, ColourScale),
+    Conic(Point, f32, ColourScale),
+}
+
+impl Paint {
+    /// Gets the colour of the paint at the provided point.
+    pub (in crate) fn colour_at(&self, point : Point) -> Colour {
+        match self {
+            Paint::Solid(colour) => *colour,
+            Paint::Linear(start, end, scale) => {
+                let direction = *end - *start;
+                let offset = point - *start;
+
+                let squared_length = direction.x * direction.x + direction.y * direction.y;
+
+                let t =
+                    if squared_length == 0.0 { 0.0 } else { (offset.x * direction.x + offset.y * direction.y) / squared_length };
+
+                scale.colour_at_fraction(t)
+            }
+            Paint::Radial(centre, radius, scale) => {
+                scale.colour_at_fraction((point - *centre).distance() / radius)
+            }
+            Paint::Conic(centre, start_angle, scale) => {
+                let offset = point - *centre;
+
+                scale.colour_at_fraction((offset.y.atan2(offset.x) - start_angle).rem_euclid(TAU) / TAU)
+            }
+        }
+    }
+}
+
+/// Number of samples taken along each axis of a pixel to find how much of it is covered by a clip.
+const CLIP_SAMPLES : u16 = 4;
+
+/// Represents a shape to clip rendering to, either a rectangle between two corners, a circle with a centre and radius, a closed polygon, or the region of points for which a function is true.
+pub enum Clip {
+    Rectangle(Point, Point),
+    Circle(Point, f32),
+    Polygon(Polygon),
+    Region(Box<dyn Fn(Point) -> bool>),
+}
+
+impl Clip {
+    /// Gets the bottom left and top right corners of a box containing the clip, or None if it is unbounded.
+    fn bounds(&self) -> Option<(Point, Point)> {
+        match self {
+            Clip::Rectangle(corner, opposite_corner) => Some(get_bounds(&vec![*corner, *opposite_corner])),
+            Clip::Circle(centre, radius) => Some((*centre - Point::new(radius.abs(), radius.abs()), *centre + Point::new(radius.abs(), radius.abs()))),
+            Clip::Polygon(polygon) => Some(get_bounds(&polygon.vertices)),
+            Clip::Region(_) => None,
+        }
+    }
+
+    /// Calculates the proportion of each pixel held by the screen that is inside the clip, in the same order as its pixels.
+    fn coverage(&self, screen : &Screen) -> Vec<f32> {
+        let width = i32::from(screen.horizontal_resolution);
+        let rows = screen.stored_rows();
+
+        let mut coverage = vec![0.0; screen.pixels.len()];
+
+        let (columns, checked_rows) =
+            match self.bounds() {
+                Some((bottom_left, top_right)) => {
+                    let bottom_left = point_to_pixel_coordinates(screen, bottom_left);
+                    let top_right = point_to_pixel_coordinates(screen, top_right);
+
+                    (
+                        (bottom_left.x.min(top_right.x) - 1).max(0)..(bottom_left.x.max(top_right.x) + 2).min(width),
+                        (bottom_left.y.min(top_right.y) - 1).max(rows.start)..(bottom_left.y.max(top_right.y) + 2).min(rows.end),
+                    )
+                }
+                None => (0..width, rows.clone()),
+            };
+
+        let samples = f32::from(CLIP_SAMPLES);
+
+        // Samples are spread around the point of each pixel, so hard clips line up with polygon fills.
+        let offset = |sample : u16| (f32::from(sample) + 0.5) / samples - 0.5;
+
+        for j in checked_rows {
+            for vertical_sample in 0..CLIP_SAMPLES {
+                let y = lerp_scalar(screen.bottom_left_bound.y, screen.top_right_bound.y, (i32_to_f32(j) + offset(vertical_sample)) / f32::from(screen.vertical_resolution));
+
+                // Crossings of the polygon's sides along the row, where points are inside if there are an odd number to their right.
+                let crossings =
+                    match self {
+                        Clip::Polygon(polygon) => Clip::crossings(&polygon.vertices, y),
+                        _ => Vec::new(),
+                    };
+
+                for i in columns.clone() {
+                    let index = usize::try_from((j - rows.start) * width + i).unwrap();
+
+                    for horizontal_sample in 0..CLIP_SAMPLES {
+                        let x = lerp_scalar(screen.bottom_left_bound.x, screen.top_right_bound.x, (i32_to_f32(i) + offset(horizontal_sample)) / f32::from(screen.horizontal_resolution));
+
+                        let inside =
+                            match self {
+                                Clip::Rectangle(corner, opposite_corner) => {
+                                    x >= corner.x.min(opposite_corner.x) && x <= corner.x.max(opposite_corner.x)
+                                    && y >= corner.y.min(opposite_corner.y) && y <= corner.y.max(opposite_corner.y)
+                                }
+                                Clip::Circle(centre, radius) => {
+                                    (Point::new(x, y) - *centre).distance() <= radius.abs()
+                                }
+                                Clip::Polygon(_) => {
+                                    (crossings.len() - crossings.partition_point(|crossing| *crossing <= x)) % 2 == 1
+                                }
+                                Clip::Region(rule) => rule(Point::new(x, y)),
+                            };
+
+                        if inside {
+                            coverage[index] += 1.0;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Counts are divided at the end so fully covered pixels are exactly 1.
+        coverage
+            .into_iter()
+            .map(|count| count / (samples * samples))
+            .collect()
+    }
+
+    /// Finds the sorted horizontal positions where the sides of a polygon cross a horizontal line.
+    fn crossings(vertices : &[Point], y : f32) -> Vec<f32> {
+        let mut crossings = Vec::new();
+
+        for (index, endpoint_2) in vertices.iter().enumerate() {
+            let endpoint_1 = vertices[(index + vertices.len() - 1) % vertices.len()];
+
+            if (endpoint_1.y > y) != (endpoint_2.y > y) {
+                crossings.push((endpoint_2.x - endpoint_1.x) * (y - endpoint_1.y) / (endpoint_2.y - endpoint_1.y) + endpoint_1.x);
+            }
+        }
+
+        crossings.sort_by(f32::total_cmp);
+
+        crossings
+    }
+}
+
+/// Represents a named layer of a screen, with the colour of each pixel premultiplied by its alpha in the space the screen blends in.
+#[derive(Clone)]
+pub (in crate) struct Layer {
+    name : String,
+    pixels : Vec<([f32; 3], f32)>,
+    settings : LayerSettings,
+}
+
+/// Represents how a layer is composited onto the layers below it.
+#[derive(Copy, Clone)]
+pub struct LayerSettings {
+    blend_mode : BlendMode,
+    opacity : f32,
+}
+
+impl LayerSettings {
+    /// Creates a new LayerSettings.
+    pub fn new(blend_mode : BlendMode, opacity : f32) -> LayerSettings {
+        LayerSettings {
+            blend_mode,
+            opacity,
+        }
+    }
+}
+
+/// Possible ways of blending a layer with the layers below it. Normal covers them, multiply darkens them, screen lightens them, overlay multiplies dark areas and screens light ones, add sums the colours, and difference subtracts the darker from the lighter.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Add,
+    Difference,
+}
+
+impl BlendMode {
+    /// Blends a channel from 0 to 1 of a layer with the same channel below it.
+    fn blend(self, base : f32, source : f32) -> f32 {
+        match self {
+            BlendMode::Normal => source,
+            BlendMode::Multiply => base * source,
+            BlendMode::Screen => 1.0 - (1.0 - base) * (1.0 - source),
+            BlendMode::Overlay => {
+                if base < 0.5 {
+                    2.0 * base * source
+                }
+                else {
+                    1.0 - 2.0 * (1.0 - base) * (1.0 - source)
+                }
+            }
+            BlendMode::Add => (base + source).min(1.0),
+            BlendMode::Difference => (base - source).abs(),
+        }
+    }
+}
+
+/// Possible ways of blending colours when rendering. Linear blends in linear light, which avoids edges looking too dark and thin lines changing brightness with the background, while Legacy blends the sRGB values directly to match the output of earlier versions.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Blending {
+    Linear,
+    Legacy,
+}
+
+impl Blending {
+    /// Gets the channels of a colour from 0 to 1 in the space colours are blended in.
+    pub (in crate) fn channels(self, colour : Colour) -> [f32; 3] {
+        [colour.red, colour.green, colour.blue].map(|value| {
+            match self {
+                Blending::Linear => byte_to_linear(value),
+                Blending::Legacy => f32::from(value) / 255.0,
+            }
+        })
+    }
+
+    /// Creates a colour from channels from 0 to 1 in the space colours are blended in.
+    pub (in crate) fn colour(self, channels : [f32; 3]) -> Colour {
+        let [red, green, blue] = channels.map(|value| {
+            match self {
+                Blending::Linear => linear_to_byte(value),
+                Blending::Legacy => f32_to_u8((255.0 * value).clamp(0.0, 255.0).round()),
+            }
+        });
+
+        Colour::from_rgb(red, green, blue)
+    }
+}
+
+/// Possible rendering types.
+#[derive(Copy, Clone)]
+pub enum RenderingType {
+    Square,
+    RoundAntiAliased(f32),
+    RoundAliased,
+}
+
+/// Line thickness and point radius represented either as a relative length based on the coordinate system or as an absolute length as a number of pixels. When specified relatively, the actual number of pixels will be an average of the number of pixels across the horizontal and vertical directions. Absolute lengths are scaled along with the screen when it is rendered at a different resolution to the output, such as in a draft, but never below a single pixel.
+#[derive(Copy, Clone)]
+pub enum Thickness {
+    Absolute(u16),
+    Relative(f32),
+}
+
+impl Thickness {
+    pub fn to_pixels(&self, screen : &Screen) -> u16 { // only temporarily public
+        match self {
+            Thickness::Absolute(length) => {
+                // A scaled down stroke should stay visible rather than vanish, such as a one pixel line in a draft.
+                if *length == 0 {
+                    0
+                } else {
+                    f32_to_u16(f32::from(*length) * screen.pixel_scale).max(1)
+                }
+            },
+            Thickness::Relative(length) => {
+                let horizontal = (length / (screen.top_right_bound.x - screen.bottom_left_bound.x)) * f32::try_from(screen.horizontal_resolution).unwrap();
+                let vertical = (length / (screen.top_right_bound.y - screen.bottom_left_bound.y)) * f32::try_from(screen.vertical_resolution).unwrap();
+
+                f32_to_u16((horizontal + vertical) / 2.0)
+            }
+        }
+    }
+}
+
+pub trait Renderable {
+    type RenderSettings;
+
+    /// Renders the renderable item on the provided screen.
+    fn render(self, settings : &Self::RenderSettings, screen : &mut Screen);
+
+    /// Renders many of the renderable item on the provided screen.
+    fn render_many(selfs : Vec<Box<Self>>, settings : &Self::RenderSettings, screen : &mut Screen);
+
+    /// Gets the bottom left and top right corners of a box containing everything the item renders, used to skip tiles it cannot touch, or None if it is unknown.
+    fn bounding_box(&self, _settings : &Self::RenderSettings, _screen : &Screen) -> Option<(Point, Point)> {
+        None
+    }
+}
+
+/// Represents the rendering settings for a Point.
+pub struct PointRenderSettings {
+    colour : Colour,
+    radius : Thickness,
+    rendering_type : RenderingType,
+}
+
+impl PointRenderSettings {
+    /// Creates a new PointRenderSettings.
+    pub fn new(colour : Colour, radius : Thickness, rendering_type : RenderingType) -> PointRenderSettings {
+        PointRenderSettings {
+            colour,
+            radius,
+            rendering_type,
+        }
+    }
+}
+
+impl Renderable for Point {
+    type RenderSettings =
+        PointRenderSettings;
+
+    /// Renders a Point.
+    fn render(self, settings : &Self::RenderSettings, screen : &mut Screen) {
+        let coordinate = point_to_pixel_coordinates(screen, self);
+
+        // this radius should be in terms of the coordinate system, and therefore needs to be converted properly using similar method to point to pixel coordinates
+        let radius = i32::from(
+                settings.radius.to_pixels(screen)
+            );
+
+        let rows = screen.stored_rows();
+
+        for i in (coordinate.x - radius)..(coordinate.x + radius) {
+            for j in (coordinate.y - radius).max(rows.start)..(coordinate.y + radius).min(rows.end) {
+
+                let is_on_screen =
+                    within_screen(PixelCoordinate::new(i, j), screen);
+
+                let squared_distance =
+                    (i - coordinate.x) * (i - coordinate.x) + (j - coordinate.y) * (j - coordinate.y);
+
+                let within_radius =
+                    squared_distance < radius * radius;
 
                 if is_on_screen {
                     let i_usize = usize::try_from(i).unwrap();
                     let j_usize = usize::try_from(j).unwrap();
 
-                    match settings.rendering_type {
-                        RenderingType::Square => {
-                            screen.pixels[i_usize][j_usize] = settings.colour;
-                        }
-                        RenderingType::RoundAliased => {
-                            if within_radius {
-                                screen.pixels[i_usize][j_usize] = settings.colour;
-                            }
-                        }
-                        RenderingType::RoundAntiAliased(anti_aliasing_factor) => {
-                            if within_radius {
-                                let previous_colour = screen.pixels[i_usize][j_usize];
-        
-                                let colour_lerp_parameter =
-                                    (i32_to_f32(squared_distance) / i32_to_f32(radius * radius)).powf(anti_aliasing_factor);
-                                
-                                let new_colour = Colour::lerp(
-                                    settings.colour,
-                                    previous_colour,
-                                    colour_lerp_parameter
-                                );
-        
-                                screen.pixels[i_usize][j_usize] = new_colour;
-                            }
+                    match settings.rendering_type {
+                        RenderingType::Square => {
+                            screen.cover((i_usize, j_usize), settings.colour, 1.0);
+                        }
+                        RenderingType::RoundAliased => {
+                            if within_radius {
+                                screen.cover((i_usize, j_usize), settings.colour, 1.0);
+                            }
+                        }
+                        RenderingType::RoundAntiAliased(anti_aliasing_factor) => {
+                            if within_radius {
+                                let previous_colour = screen[(i_usize, j_usize)];
+        
+                                let colour_lerp_parameter =
+                                    (i32_to_f32(squared_distance) / i32_to_f32(radius * radius)).powf(anti_aliasing_factor);
+                                
+                                let new_colour = screen.blend(
+                                    settings.colour,
+                                    previous_colour,
+                                    colour_lerp_parameter
+                                );
+        
+                                screen.cover((i_usize, j_usize), new_colour, 1.0 - colour_lerp_parameter);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Renders many Points.
+    fn render_many(selfs : Vec<Box<Self>>, settings : &Self::RenderSettings, screen : &mut Screen) {
+        for point in selfs {
+            Self::render(*point, settings, screen);
+        }
+    }
+
+    /// Gets the box covered by a Point and its radius.
+    fn bounding_box(&self, settings : &Self::RenderSettings, screen : &Screen) -> Option<(Point, Point)> {
+        Some(pad_bounds(screen, (*self, *self), settings.radius.to_pixels(screen)))
+    }
+}
+
+/// Represents the rendering settings for a Function.
+pub struct FunctionRenderSettings {
+    paint : Paint,
+    thickness : Thickness,
+    samples : u16,
+    rendering_type : RenderingType,
+    variation : Option<StrokeVariation>,
+    stroke : Option<StrokeStyle>,
+    arrow_head : Option<ArrowHead>,
+}
+
+impl FunctionRenderSettings {
+    /// Creates a new FunctionRenderSettings.
+    pub fn new(colour : Colour, thickness : Thickness, samples : u16, rendering_type : RenderingType) -> FunctionRenderSettings {
+        FunctionRenderSettings::from_paint(Paint::Solid(colour), thickness, samples, rendering_type)
+    }
+
+    /// Creates a new FunctionRenderSettings where each sample takes its colour from the paint at that point.
+    pub fn from_paint(paint : Paint, thickness : Thickness, samples : u16, rendering_type : RenderingType) -> FunctionRenderSettings {
+        FunctionRenderSettings {
+            paint,
+            thickness,
+            samples,
+            rendering_type,
+            variation : None,
+            stroke : None,
+            arrow_head : None,
+        }
+    }
+
+    /// Varies the colour and thickness along the Function, replacing the paint and thickness wherever the variation specifies them.
+    pub fn with_variation(mut self, variation : StrokeVariation) -> FunctionRenderSettings {
+        self.variation = Some(variation);
+        self
+    }
+
+    /// Draws the Function as a continuous stroke through its samples with the provided caps, joins and dashes, instead of as a point at each sample.
+    pub fn with_stroke(mut self, stroke : StrokeStyle) -> FunctionRenderSettings {
+        self.stroke = Some(stroke);
+        self
+    }
+
+    /// Draws arrowheads on the Function, pointing along it, with the line stopping where it meets them.
+    pub fn with_arrow_head(mut self, arrow_head : ArrowHead) -> FunctionRenderSettings {
+        self.arrow_head = Some(arrow_head);
+        self
+    }
+}
+
+/// Possible ways of measuring how far along a Function a sample is, from 0 to 1, either through its domain or through its total arc length.
+#[derive(Copy, Clone)]
+pub enum StrokeParameter {
+    Domain,
+    ArcLength,
+}
+
+/// Represents a stroke colour and thickness which each optionally vary along a Function, as functions of how far along it they are.
+pub struct StrokeVariation {
+    parameter : StrokeParameter,
+    colour : Option<Box<dyn Fn(f32) -> Colour>>,
+    thickness : Option<Box<dyn Fn(f32) -> Thickness>>,
+}
+
+impl StrokeVariation {
+    /// Creates a new StrokeVariation.
+    pub fn new(parameter : StrokeParameter, colour : Option<Box<dyn Fn(f32) -> Colour>>, thickness : Option<Box<dyn Fn(f32) -> Thickness>>) -> StrokeVariation {
+        StrokeVariation {
+            parameter,
+            colour,
+            thickness,
+        }
+    }
+}
+
+/// Number of samples taken along each axis of a pixel to find how much of it is covered by a stroke.
+const STROKE_SAMPLES : u16 = 4;
+
+/// Represents how a stroke is drawn, with the shape of the ends of each dash and of the corners between its segments, and optionally a dash pattern.
+#[derive(Clone)]
+pub struct StrokeStyle {
+    cap : LineCap,
+    join : LineJoin,
+    dashes : Option<DashPattern>,
+}
+
+impl StrokeStyle {
+    /// Creates a new solid StrokeStyle.
+    pub fn new(cap : LineCap, join : LineJoin) -> StrokeStyle {
+        StrokeStyle {
+            cap,
+            join,
+            dashes : None,
+        }
+    }
+
+    /// Breaks the stroke into dashes with the provided pattern.
+    pub fn with_dashes(mut self, dashes : DashPattern) -> StrokeStyle {
+        self.dashes = Some(dashes);
+        self
+    }
+
+    /// Gets how far the stroke can reach from its path as a multiple of its thickness, through miters and the corners of square caps.
+    pub (in crate) fn reach(&self) -> f32 {
+        match self.join {
+            LineJoin::Miter(limit) => limit.max(2.0_f32.sqrt()),
+            _ => 2.0_f32.sqrt(),
+        }
+    }
+
+    /// Renders a stroke along a path of points with their colours and thicknesses in pixels. If a paint is provided, each pixel takes its colour from it instead.
+    pub (in crate) fn render_path(&self, mut path : Vec<(Point, Colour, f32)>, closed : bool, paint : Option<&Paint>, rendering_type : RenderingType, screen : &mut Screen) {
+        if path.is_empty() {
+            return;
+        }
+
+        if closed {
+            path.push(path[0]);
+        }
+
+        // Distance along the path in the coordinate system to each point.
+        let mut distances = Vec::with_capacity(path.len());
+        let mut total = 0.0;
+
+        for (index, (point, _, _)) in path.iter().enumerate() {
+            if index > 0 {
+                total += (*point - path[index - 1].0).distance();
+            }
+
+            distances.push(total);
+        }
+
+        let runs =
+            match &self.dashes {
+                Some(dashes) if dashes.period() > 0.0 => {
+                    dashes
+                    .intervals(total)
+                    .into_iter()
+                    .map(|(start, end)| {
+                        let (sub_path, direction) = StrokeStyle::sub_path(&path, &distances, start, end);
+
+                        (sub_path, direction, false)
+                    })
+                    .collect()
+                }
+                _ => {
+                    let direction =
+                        match path.as_slice() {
+                            [(start, _, _), (end, _, _), ..] => *end - *start,
+                            _ => Point::new(1.0, 0.0),
+                        };
+
+                    vec![(path, direction, closed)]
+                }
+            };
+
+        let to_pixels = |point : Point| {
+            Point::new(
+                (point.x - screen.bottom_left_bound.x) / (screen.top_right_bound.x - screen.bottom_left_bound.x) * f32::from(screen.horizontal_resolution),
+                (point.y - screen.bottom_left_bound.y) / (screen.top_right_bound.y - screen.bottom_left_bound.y) * f32::from(screen.vertical_resolution),
+            )
+        };
+
+        let mut pieces = Vec::new();
+
+        for (run, direction, run_closed) in runs {
+            // The direction of the path is kept for dots, which have no length to take it from.
+            let direction = to_pixels(direction) - to_pixels(Point::new(0.0, 0.0));
+
+            let mut run_pixels : Vec<(Point, Colour, f32)> = Vec::with_capacity(run.len());
+
+            for (point, colour, width) in run {
+                let point = to_pixels(point);
+
+                if run_pixels.last().is_none_or(|(last, _, _)| (point - *last).distance() > 1e-4) {
+                    run_pixels.push((point, colour, width));
+                }
+            }
+
+            self.add_pieces(&run_pixels, run_closed, direction, &mut pieces);
+        }
+
+        // Each pixel records which of its samples are covered, so overlapping pieces are only counted once.
+        let rows = screen.stored_rows();
+        let width = i32::from(screen.horizontal_resolution);
+        let samples = f32::from(STROKE_SAMPLES);
+
+        let offset = |sample : u16| (f32::from(sample) + 0.5) / samples - 0.5;
+
+        let mut coverage : HashMap<(i32, i32), (u16, Colour)> = HashMap::new();
+
+        for piece in pieces {
+            let (bottom_left, top_right) = piece.bounds();
+
+            let columns = f32_to_i32(bottom_left.x.clamp(-1.0, i32_to_f32(width)).floor())..=f32_to_i32(top_right.x.clamp(-1.0, i32_to_f32(width)).ceil());
+            let checked_rows = f32_to_i32(bottom_left.y.clamp(i32_to_f32(rows.start) - 1.0, i32_to_f32(rows.end)).floor())..=f32_to_i32(top_right.y.clamp(i32_to_f32(rows.start) - 1.0, i32_to_f32(rows.end)).ceil());
+
+            for j in checked_rows {
+                for i in columns.clone() {
+                    if !within_screen(PixelCoordinate::new(i, j), screen) {
+                        continue;
+                    }
+
+                    let mut covered = 0_u16;
+
+                    for sample in 0..(STROKE_SAMPLES * STROKE_SAMPLES) {
+                        let point = Point::new(i32_to_f32(i) + offset(sample % STROKE_SAMPLES), i32_to_f32(j) + offset(sample / STROKE_SAMPLES));
+
+                        if piece.contains(point) {
+                            covered |= 1 << sample;
                         }
                     }
+
+                    if covered != 0 {
+                        let entry = coverage.entry((i, j)).or_insert((0, piece.colour()));
+
+                        entry.0 |= covered;
+                        entry.1 = piece.colour();
+                    }
+                }
+            }
+        }
+
+        for ((i, j), (covered, colour)) in coverage {
+            let colour =
+                match paint {
+                    Some(paint) => paint.colour_at(pixel_coordinates_to_point(screen, PixelCoordinate::new(i, j))),
+                    None => colour,
+                };
+
+            let proportion = u32_to_f32(covered.count_ones()) / (samples * samples);
+            let (i, j) = (usize::try_from(i).unwrap(), usize::try_from(j).unwrap());
+
+            match rendering_type {
+                RenderingType::RoundAntiAliased(_) => {
+                    screen.cover((i, j), screen.blend(screen[(i, j)], colour, proportion), proportion);
+                }
+                _ => {
+                    if proportion >= 0.5 {
+                        screen.cover((i, j), colour, 1.0);
+                    }
                 }
             }
         }
     }
 
-    /// Renders many Points.
-    fn render_many(selfs : Vec<Box<Self>>, settings : &Self::RenderSettings, screen : &mut Screen) {
-        for point in selfs {
-            Self::render(*point, settings, screen);
+    /// Gets the part of a path between two distances along it, interpolating the points at either end, along with the direction of the path at the start.
+    fn sub_path(path : &[(Point, Colour, f32)], distances : &[f32], start : f32, end : f32) -> (Vec<(Point, Colour, f32)>, Point) {
+        if path.len() < 2 {
+            return (path.to_vec(), Point::new(1.0, 0.0));
+        }
+
+        let segment = |distance : f32| distances.partition_point(|d| *d <= distance).clamp(1, path.len() - 1);
+
+        let at = |distance : f32| {
+            let index = segment(distance);
+            let (previous, colour, previous_width) = path[index - 1];
+            let (next, _, next_width) = path[index];
+
+            let span = distances[index] - distances[index - 1];
+            let t = if span > 0.0 { ((distance - distances[index - 1]) / span).clamp(0.0, 1.0) } else { 0.0 };
+
+            (Point::lerp(previous, next, t), colour, lerp_scalar(previous_width, next_width, t))
+        };
+
+        let mut sub_path = vec![at(start)];
+
+        for (point, distance) in path.iter().zip(distances) {
+            if *distance > start && *distance < end {
+                sub_path.push(*point);
+            }
+        }
+
+        if end > start {
+            sub_path.push(at(end));
+        }
+
+        (sub_path, path[segment(start)].0 - path[segment(start) - 1].0)
+    }
+
+    /// Adds the pieces of one continuous run of a stroke in pixel coordinates, with its segments, joins and caps.
+    fn add_pieces(&self, run : &[(Point, Colour, f32)], closed : bool, direction : Point, pieces : &mut Vec<StrokePiece>) {
+        let normal = |direction : Point| {
+            let length = direction.distance();
+
+            if length == 0.0 {
+                Point::new(0.0, 0.0)
+            }
+            else {
+                Point::new(-direction.y / length, direction.x / length)
+            }
+        };
+
+        if run.len() == 1 {
+            let (point, colour, width) = run[0];
+            let along = -1.0 * normal(normal(direction));
+            let across = normal(direction);
+
+            match self.cap {
+                LineCap::Butt => {}
+                LineCap::Round => pieces.push(StrokePiece::Circle(point, width, colour)),
+                LineCap::Square => pieces.push(StrokePiece::Convex(
+                    vec![
+                        point + width * (along + across),
+                        point + width * (along - across),
+                        point - width * (along + across),
+                        point - width * (along - across),
+                    ],
+                    colour
+                )),
+            }
+
+            return;
+        }
+
+        for segment in run.windows(2) {
+            let (start, colour, start_width) = segment[0];
+            let (end, _, end_width) = segment[1];
+            let across = normal(end - start);
+
+            pieces.push(StrokePiece::Convex(
+                vec![
+                    start + start_width * across,
+                    end + end_width * across,
+                    end - end_width * across,
+                    start - start_width * across,
+                ],
+                colour
+            ));
+        }
+
+        for index in 1..(run.len() - 1) {
+            self.add_join(run[index], run[index].0 - run[index - 1].0, run[index + 1].0 - run[index].0, pieces);
+        }
+
+        if closed && run.len() > 2 {
+            self.add_join(run[0], run[0].0 - run[run.len() - 2].0, run[1].0 - run[0].0, pieces);
+
+            return;
+        }
+
+        let last = run.len() - 1;
+
+        for (end, outwards) in [(run[0], run[0].0 - run[1].0), (run[last], run[last].0 - run[last - 1].0)] {
+            let (point, colour, width) = end;
+            let along = -1.0 * normal(normal(outwards));
+            let across = normal(outwards);
+
+            match self.cap {
+                LineCap::Butt => {}
+                LineCap::Round => pieces.push(StrokePiece::Circle(point, width, colour)),
+                LineCap::Square => pieces.push(StrokePiece::Convex(
+                    vec![
+                        point + width * across,
+                        point + width * (across + along),
+                        point + width * (along - across),
+                        point - width * across,
+                    ],
+                    colour
+                )),
+            }
+        }
+    }
+
+    /// Adds the piece joining two segments of a stroke at a point, on the outside of the corner.
+    fn add_join(&self, (point, colour, width) : (Point, Colour, f32), incoming : Point, outgoing : Point, pieces : &mut Vec<StrokePiece>) {
+        let cross = incoming.x * outgoing.y - incoming.y * outgoing.x;
+        let dot = incoming.x * outgoing.x + incoming.y * outgoing.y;
+
+        if cross.abs() <= 1e-6 * incoming.distance() * outgoing.distance() && dot > 0.0 {
+            return;
+        }
+
+        // Normals on the outside of the corner.
+        let side = if cross > 0.0 { -1.0 } else { 1.0 };
+        let outer = |direction : Point| side / direction.distance() * Point::new(-direction.y, direction.x);
+
+        let (incoming_normal, outgoing_normal) = (outer(incoming), outer(outgoing));
+
+        let bevel =
+            StrokePiece::Convex(vec![point, point + width * incoming_normal, point + width * outgoing_normal], colour);
+
+        match self.join {
+            LineJoin::Round => {
+                // Only the arc on the outside of the corner is added, since a whole circle would reach past the caps of strokes with segments shorter than their width.
+                let angle = (incoming_normal.x * outgoing_normal.y - incoming_normal.y * outgoing_normal.x).atan2(incoming_normal.x * outgoing_normal.x + incoming_normal.y * outgoing_normal.y);
+                let steps = f32_to_u16((angle.abs() * width).ceil().clamp(1.0, 64.0));
+
+                let mut vertices = vec![point];
+
+                for step in 0..=steps {
+                    let (sin, cos) = (angle * f32::from(step) / f32::from(steps)).sin_cos();
+
+                    vertices.push(point + width * Point::new(incoming_normal.x * cos - incoming_normal.y * sin, incoming_normal.x * sin + incoming_normal.y * cos));
+                }
+
+                pieces.push(StrokePiece::Convex(vertices, colour));
+            }
+            LineJoin::Bevel => pieces.push(bevel),
+            LineJoin::Miter(limit) => {
+                let bisector = incoming_normal + outgoing_normal;
+                let length = bisector.distance();
+
+                // The miter reaches the inverse of the cosine of half the angle between the normals along their bisector.
+                let ratio = if length > 1e-6 { 2.0 / length } else { f32::INFINITY };
+
+                if ratio > limit {
+                    pieces.push(bevel);
+                }
+                else {
+                    pieces.push(StrokePiece::Convex(
+                        vec![
+                            point,
+                            point + width * incoming_normal,
+                            point + (width * ratio / length) * bisector,
+                            point + width * outgoing_normal,
+                        ],
+                        colour
+                    ));
+                }
+            }
         }
     }
 }
 
-/// Represents the rendering settings for a Function.
-pub struct FunctionRenderSettings {
-    colour : Colour,
-    thickness : Thickness,
-    samples : u16,
-    rendering_type : RenderingType,
+/// Represents a repeating pattern of alternating dash and gap lengths, measured by arc length in the coordinate system, which starts the offset distance into the pattern. Patterns with an odd number of lengths are repeated to make them even, and moving the offset over time makes the dashes march along the stroke.
+#[derive(Clone)]
+pub struct DashPattern {
+    lengths : Vec<f32>,
+    offset : f32,
 }
 
-impl FunctionRenderSettings {
-    /// Creates a new FunctionRenderSettings.
-    pub fn new(colour : Colour, thickness : Thickness, samples : u16, rendering_type : RenderingType) -> FunctionRenderSettings {
-        FunctionRenderSettings {
-            colour,
-            thickness,
-            samples,
-            rendering_type,
+impl DashPattern {
+    /// Creates a new DashPattern.
+    pub fn new(lengths : Vec<f32>, offset : f32) -> DashPattern {
+        let mut lengths : Vec<f32> = lengths.into_iter().map(f32::abs).collect();
+
+        if lengths.len() % 2 == 1 {
+            lengths.extend(lengths.clone());
+        }
+
+        DashPattern {
+            lengths,
+            offset,
+        }
+    }
+
+    /// Creates a dotted DashPattern with the provided distance between dots, which are drawn by the caps of dashes with no length, so need round or square caps.
+    pub fn dotted(spacing : f32, offset : f32) -> DashPattern {
+        DashPattern::new(vec![0.0, spacing], offset)
+    }
+
+    /// Moves the start of the pattern along the stroke.
+    pub fn with_offset(mut self, offset : f32) -> DashPattern {
+        self.offset = offset;
+        self
+    }
+
+    /// Gets the length of one repeat of the pattern.
+    fn period(&self) -> f32 {
+        self.lengths.iter().sum()
+    }
+
+    /// Gets the start and end distances of each dash along a stroke of the provided length.
+    fn intervals(&self, total : f32) -> Vec<(f32, f32)> {
+        let mut intervals = Vec::new();
+        let mut start = -self.offset.rem_euclid(self.period());
+
+        while start <= total {
+            for (index, length) in self.lengths.iter().enumerate() {
+                let end = start + length;
+
+                if index % 2 == 0 && end >= 0.0 && start <= total {
+                    intervals.push((start.max(0.0), end.min(total)));
+                }
+
+                start = end;
+            }
+        }
+
+        intervals
+    }
+}
+
+/// Possible shapes for the ends of a stroke and each of its dashes, either stopping at the end, extended by a semicircle, or extended by half of a square.
+#[derive(Copy, Clone)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+/// Possible shapes for the corners between segments of a stroke, either a sharp point which becomes a bevel when it is longer than the limit times the thickness, a circular arc, or cut off flat.
+#[derive(Copy, Clone)]
+pub enum LineJoin {
+    Miter(f32),
+    Round,
+    Bevel,
+}
+
+/// Represents a part of a stroke in pixel coordinates, either a convex polygon or a circle with a centre and radius, along with its colour.
+enum StrokePiece {
+    Convex(Vec<Point>, Colour),
+    Circle(Point, f32, Colour),
+}
+
+impl StrokePiece {
+    /// Gets the bottom left and top right corners of a box containing the piece.
+    fn bounds(&self) -> (Point, Point) {
+        match self {
+            StrokePiece::Convex(vertices, _) => get_bounds(vertices),
+            StrokePiece::Circle(centre, radius, _) => (*centre - Point::new(*radius, *radius), *centre + Point::new(*radius, *radius)),
+        }
+    }
+
+    /// Determines if a point is inside the piece.
+    fn contains(&self, point : Point) -> bool {
+        match self {
+            StrokePiece::Convex(vertices, _) => {
+                let mut positive = false;
+                let mut negative = false;
+
+                for (index, vertex) in vertices.iter().enumerate() {
+                    let next = vertices[(index + 1) % vertices.len()];
+                    let cross = (next.x - vertex.x) * (point.y - vertex.y) - (next.y - vertex.y) * (point.x - vertex.x);
+
+                    positive |= cross > 0.0;
+                    negative |= cross < 0.0;
+                }
+
+                !(positive && negative)
+            }
+            StrokePiece::Circle(centre, radius, _) => (point - *centre).distance() <= *radius,
+        }
+    }
+
+    /// Gets the colour of the piece.
+    fn colour(&self) -> Colour {
+        match self {
+            StrokePiece::Convex(_, colour) | StrokePiece::Circle(_, _, colour) => *colour,
+        }
+    }
+}
+
+/// Represents an arrowhead placed along a Function, with its tip, a point on the Function the length of the arrowhead behind it, and how far along the Function it is.
+struct PlacedArrowHead {
+    arrow_head : ArrowHead,
+    tip : Point,
+    from : Point,
+    progress : f32,
+}
+
+impl PlacedArrowHead {
+    /// Creates a new PlacedArrowHead.
+    fn new(arrow_head : ArrowHead, tip : Point, from : Point, progress : f32) -> PlacedArrowHead {
+        PlacedArrowHead {
+            arrow_head,
+            tip,
+            from,
+            progress,
+        }
+    }
+
+    /// Creates the filled polygons and open lines of the arrowhead.
+    fn parts(&self) -> (Vec<Polygon>, Vec<Function>) {
+        self.arrow_head.parts(self.tip, self.from)
+    }
+}
+
+impl Function {
+    /// Finds where arrowheads go along sampled points of a Function. The samples where the line is covered by the arrowheads are removed, with new samples where it meets them.
+    fn place_arrow_heads(samples : Vec<Point>, progress : Vec<f32>, arrow_head : ArrowHead) -> (Vec<Point>, Vec<f32>, Vec<PlacedArrowHead>) {
+        if samples.len() < 2 {
+            return (samples, progress, Vec::new());
+        }
+
+        let mut distances = Vec::with_capacity(samples.len());
+        let mut total = 0.0;
+
+        for (index, sample) in samples.iter().enumerate() {
+            if index > 0 {
+                total += (*sample - samples[index - 1]).distance();
+            }
+
+            distances.push(total);
+        }
+
+        // Interpolates the sample and progress at a distance along the Function.
+        let at = |distance : f32| {
+            let index = distances.partition_point(|d| *d <= distance).clamp(1, samples.len() - 1);
+
+            let span = distances[index] - distances[index - 1];
+            let t = if span > 0.0 { ((distance - distances[index - 1]) / span).clamp(0.0, 1.0) } else { 0.0 };
+
+            (Point::lerp(samples[index - 1], samples[index], t), lerp_scalar(progress[index - 1], progress[index], t))
+        };
+
+        let mut arrow_heads = Vec::new();
+        let mut start = 0.0;
+        let mut end = total;
+
+        let last = samples.len() - 1;
+
+        match arrow_head.placement {
+            ArrowHeadPlacement::End | ArrowHeadPlacement::Both => {
+                arrow_heads.push(PlacedArrowHead::new(arrow_head, samples[last], at(total - arrow_head.length).0, progress[last]));
+                end = total - arrow_head.setback();
+            }
+            _ => {}
+        }
+
+        match arrow_head.placement {
+            ArrowHeadPlacement::Start | ArrowHeadPlacement::Both => {
+                arrow_heads.push(PlacedArrowHead::new(arrow_head, samples[0], at(arrow_head.length).0, progress[0]));
+                start = arrow_head.setback();
+            }
+            ArrowHeadPlacement::Middle => {
+                arrow_heads.push(PlacedArrowHead::new(arrow_head, at((total + arrow_head.length) / 2.0).0, at((total - arrow_head.length) / 2.0).0, at(total / 2.0).1));
+            }
+            _ => {}
+        }
+
+        if end < start {
+            return (Vec::new(), Vec::new(), arrow_heads);
+        }
+
+        let mut trimmed_samples = Vec::with_capacity(samples.len());
+        let mut trimmed_progress = Vec::with_capacity(samples.len());
+
+        if start > 0.0 {
+            let (sample, t) = at(start);
+
+            trimmed_samples.push(sample);
+            trimmed_progress.push(t);
+        }
+
+        for ((sample, t), distance) in samples.iter().zip(&progress).zip(&distances) {
+            if *distance >= start && *distance <= end {
+                trimmed_samples.push(*sample);
+                trimmed_progress.push(*t);
+            }
+        }
+
+        if end < total {
+            let (sample, t) = at(end);
+
+            trimmed_samples.push(sample);
+            trimmed_progress.push(t);
+        }
+
+        (trimmed_samples, trimmed_progress, arrow_heads)
+    }
+
+    /// Measures how far along the Function each sample is, from 0 to 1, as the stroke variation specifies.
+    fn progress(samples : &[Point], settings : &FunctionRenderSettings) -> Vec<f32> {
+        match &settings.variation {
+            Some(StrokeVariation { parameter : StrokeParameter::ArcLength, .. }) => {
+                Function::arc_length_fractions(samples)
+            }
+            _ => {
+                let last = (f32::from(settings.samples) - 1.0).max(1.0);
+                (0..settings.samples).map(|i| f32::from(i) / last).collect()
+            }
+        }
+    }
+
+    /// Renders the filled polygons and open lines of an arrowhead, with the lines drawn using the provided settings.
+    fn render_arrow_head((polygons, lines) : (Vec<Polygon>, Vec<Function>), colour : Colour, line_settings : &FunctionRenderSettings, screen : &mut Screen) {
+        for polygon in polygons {
+            polygon.render(
+                &PolygonRenderSettings::new(
+                    None,
+                    Some(PolygonFillRenderSettings::new(
+                        colour
+                    ))
+                ),
+                screen
+            );
+        }
+
+        for line in lines {
+            line.render(line_settings, screen);
         }
     }
 }
@@ -462,16 +2416,71 @@ impl Renderable for Function {
     fn render(self, settings : &Self::RenderSettings, screen : &mut Screen) {
 
         let samples = Function::sample(&self, settings.samples);
+        let progress = Function::progress(&samples, settings);
 
-        for sample in samples {
-            sample.render(
-                &PointRenderSettings::new(
-                    settings.colour,
-                    settings.thickness,
-                    settings.rendering_type
+        let style_at = |sample : Point, t : f32| {
+            match &settings.variation {
+                Some(variation) => (
+                    variation.colour.as_ref().map_or_else(|| settings.paint.colour_at(sample), |colour| colour(t)),
+                    variation.thickness.as_ref().map_or(settings.thickness, |thickness| thickness(t))
                 ),
-                screen
-            );
+                None => (settings.paint.colour_at(sample), settings.thickness),
+            }
+        };
+
+        let (samples, progress, arrow_heads) =
+            match settings.arrow_head {
+                Some(arrow_head) => Function::place_arrow_heads(samples, progress, arrow_head),
+                None => (samples, progress, Vec::new()),
+            };
+
+        if let Some(stroke) = &settings.stroke {
+            let path =
+                samples
+                .into_iter()
+                .zip(progress)
+                .map(|(sample, t)| {
+                    let (colour, thickness) = style_at(sample, t);
+
+                    (sample, colour, f32::from(thickness.to_pixels(screen)))
+                })
+                .collect();
+
+            // Varying colours are kept with each part of the stroke, while paints are applied to each pixel.
+            let paint =
+                match &settings.variation {
+                    Some(StrokeVariation { colour : Some(_), .. }) => None,
+                    _ => Some(&settings.paint),
+                };
+
+            stroke.render_path(path, false, paint, settings.rendering_type, screen);
+        }
+        else {
+            for (sample, t) in samples.into_iter().zip(progress) {
+                let (colour, thickness) = style_at(sample, t);
+
+                sample.render(
+                    &PointRenderSettings::new(
+                        colour,
+                        thickness,
+                        settings.rendering_type
+                    ),
+                    screen
+                );
+            }
+        }
+
+        for placed in arrow_heads {
+            let (colour, thickness) = style_at(placed.tip, placed.progress);
+
+            let mut line_settings =
+                FunctionRenderSettings::new(colour, thickness, settings.samples, settings.rendering_type);
+
+            if let Some(stroke) = &settings.stroke {
+                line_settings = line_settings.with_stroke(StrokeStyle::new(stroke.cap, stroke.join));
+            }
+
+            Function::render_arrow_head(placed.parts(), colour, &line_settings, screen);
         }
     }
 
@@ -481,38 +2490,91 @@ impl Renderable for Function {
             function.render(settings, screen);
         }
     }
+
+    /// Gets a box containing the samples and any arrowheads, padded by the thickest part of the stroke.
+    fn bounding_box(&self, settings : &Self::RenderSettings, screen : &Screen) -> Option<(Point, Point)> {
+        let samples = Function::sample(self, settings.samples);
+
+        if samples.is_empty() {
+            return None;
+        }
+
+        let progress = Function::progress(&samples, settings);
+
+        let thickness =
+            match settings.variation.as_ref().and_then(|variation| variation.thickness.as_ref()) {
+                Some(thickness) => progress.iter().map(|t| thickness(*t).to_pixels(screen)).max().unwrap_or(0),
+                None => settings.thickness.to_pixels(screen),
+            };
+
+        let mut points = samples.clone();
+
+        if let Some(arrow_head) = settings.arrow_head {
+            for placed in Function::place_arrow_heads(samples, progress, arrow_head).2 {
+                let (polygons, lines) = placed.parts();
+
+                points.extend(polygons.into_iter().flat_map(|polygon| polygon.vertices));
+
+                for line in lines {
+                    points.extend(line.sample(2));
+                }
+            }
+        }
+
+        let reach = settings.stroke.as_ref().map_or(1.0, StrokeStyle::reach);
+
+        Some(pad_bounds(screen, get_bounds(&points), f32_to_u16((f32::from(thickness) * reach).ceil())))
+    }
 }
 
 /// Represents the rendering settings for a Polygon's sides.
 pub struct PolygonSidesRenderSettings {
-    colour : Colour,
+    paint : Paint,
     thickness : Thickness,
     samples_per_side : u16,
     rendering_type : RenderingType,
+    stroke : Option<StrokeStyle>,
 }
 
 impl PolygonSidesRenderSettings {
     /// Creates a new PolygonSidesRenderSettings.
     pub fn new(colour : Colour, thickness : Thickness, samples_per_side : u16, rendering_type : RenderingType) -> PolygonSidesRenderSettings {
+        PolygonSidesRenderSettings::from_paint(Paint::Solid(colour), thickness, samples_per_side, rendering_type)
+    }
+
+    /// Creates a new PolygonSidesRenderSettings where the sides take their colour from the paint.
+    pub fn from_paint(paint : Paint, thickness : Thickness, samples_per_side : u16, rendering_type : RenderingType) -> PolygonSidesRenderSettings {
         PolygonSidesRenderSettings {
-            colour,
+            paint,
             thickness,
             samples_per_side,
             rendering_type,
+            stroke : None,
         }
     }
+
+    /// Draws the sides as one closed stroke around the vertices with the provided joins and dashes, instead of as points along each side.
+    pub fn with_stroke(mut self, stroke : StrokeStyle) -> PolygonSidesRenderSettings {
+        self.stroke = Some(stroke);
+        self
+    }
 }
 
 /// Represents the rendering settings for a Polygon's fill.
 pub struct PolygonFillRenderSettings {
-    colour : Colour,
+    paint : Paint,
 }
 
 impl PolygonFillRenderSettings {
     /// Creates a new PolygonFillRenderSettings.
     pub fn new(colour : Colour) -> PolygonFillRenderSettings {
+        PolygonFillRenderSettings::from_paint(Paint::Solid(colour))
+    }
+
+    /// Creates a new PolygonFillRenderSettings where the fill takes its colour from the paint.
+    pub fn from_paint(paint : Paint) -> PolygonFillRenderSettings {
         PolygonFillRenderSettings {
-            colour,
+            paint,
         }
     }
 }
@@ -543,8 +2605,10 @@ impl Polygon {
         let bottom_left = point_to_pixel_coordinates(screen, bottom_left_point);
         let top_right = point_to_pixel_coordinates(screen, top_right_point);
 
+        let rows = screen.stored_rows();
+
         for i in (bottom_left.x)..=(top_right).x {
-            for j in (bottom_left.y)..=(top_right.y) {
+            for j in (bottom_left.y).max(rows.start)..=(top_right.y).min(rows.end - 1) {
                 let current_coordinates =
                     PixelCoordinate::new(i, j);
                 
@@ -555,7 +2619,7 @@ impl Polygon {
                     within_screen(current_coordinates, screen);
 
                 if is_inside_polygon(current_point, &vertices) && on_screen {
-                    screen.pixels[usize::try_from(i).unwrap()][usize::try_from(j).unwrap()] = settings.colour;
+                    screen.cover((usize::try_from(i).unwrap(), usize::try_from(j).unwrap()), settings.paint.colour_at(current_point), 1.0);
                 }
             }
         }
@@ -575,7 +2639,7 @@ impl Polygon {
             )
         }
 
-        Function::render_many(boxed_edges, &FunctionRenderSettings::new(settings.colour, settings.thickness, settings.samples_per_side, settings.rendering_type), screen);
+        Function::render_many(boxed_edges, &FunctionRenderSettings::from_paint(settings.paint.clone(), settings.thickness, settings.samples_per_side, settings.rendering_type), screen);
     }
 }
 
@@ -590,14 +2654,27 @@ impl Renderable for Polygon {
 
         match &settings.fill {
             Some(edges_settings) => {
-                Polygon::render_fill(vertices, edges_settings, screen);
+                Polygon::render_fill(vertices.clone(), edges_settings, screen);
             }
             None => {}
         }
         
         match &settings.sides {
             Some(sides_settings) => {
-               Polygon::render_sides(edges, sides_settings, screen)
+                match &sides_settings.stroke {
+                    Some(stroke) => {
+                        let width = f32::from(sides_settings.thickness.to_pixels(screen));
+
+                        let path =
+                            vertices
+                            .into_iter()
+                            .map(|vertex| (vertex, sides_settings.paint.colour_at(vertex), width))
+                            .collect();
+
+                        stroke.render_path(path, true, Some(&sides_settings.paint), sides_settings.rendering_type, screen);
+                    }
+                    None => Polygon::render_sides(edges, sides_settings, screen),
+                }
             }
             None => {}
         }
@@ -609,6 +2686,18 @@ impl Renderable for Polygon {
             polygon.render(settings, screen);
         }
     }
+
+    /// Gets the box covered by a Polygon's vertices and the thickness of its sides.
+    fn bounding_box(&self, settings : &Self::RenderSettings, screen : &Screen) -> Option<(Point, Point)> {
+        let thickness =
+            settings.sides.as_ref().map_or(0, |sides| {
+                let reach = sides.stroke.as_ref().map_or(1.0, StrokeStyle::reach);
+
+                f32_to_u16((f32::from(sides.thickness.to_pixels(screen)) * reach).ceil())
+            });
+
+        Some(pad_bounds(screen, get_bounds(&self.vertices), thickness))
+    }
 }
 
 /// Represents the rendering settings for a Vector.
@@ -617,6 +2706,7 @@ pub struct VectorRenderSettings {
     thickness : Thickness,
     samples : u16,
     rendering_type : RenderingType,
+    stroke : Option<StrokeStyle>,
 }
 
 impl VectorRenderSettings {
@@ -627,8 +2717,15 @@ impl VectorRenderSettings {
             thickness,
             samples,
             rendering_type,
+            stroke : None,
         }
     }
+
+    /// Draws the shaft of the Vector as a stroke with the provided caps and dashes.
+    pub fn with_stroke(mut self, stroke : StrokeStyle) -> VectorRenderSettings {
+        self.stroke = Some(stroke);
+        self
+    }
 }
 
 impl Renderable for Vector {
@@ -637,26 +2734,38 @@ impl Renderable for Vector {
 
     /// Renders a vector.
     fn render(self, settings : &Self::RenderSettings, screen : &mut Screen) {
-        self.arrow_head.render(
-            &PolygonRenderSettings::new(
-                None,
-                Some(PolygonFillRenderSettings::new(
-                    settings.colour
-                ))
-            ),
-            screen
-        );
+        // Open arrowheads are drawn like the line, but never dashed.
+        let mut head_settings =
+            FunctionRenderSettings::new(
+                settings.colour,
+                settings.thickness,
+                settings.samples,
+                settings.rendering_type
+            );
+
+        if let Some(stroke) = &settings.stroke {
+            head_settings = head_settings.with_stroke(StrokeStyle::new(stroke.cap, stroke.join));
+        }
+
+        Function::render_arrow_head((self.arrow_heads, Vec::new()), settings.colour, &head_settings, screen);
+
         if let Some(line) = self.line {
-            line.render(
-                &FunctionRenderSettings::new(
+            let mut line_settings =
+                FunctionRenderSettings::new(
                     settings.colour,
                     settings.thickness,
                     settings.samples,
                     settings.rendering_type
-                ),
-                screen
-            );
+                );
+
+            if let Some(stroke) = &settings.stroke {
+                line_settings = line_settings.with_stroke(stroke.clone());
+            }
+
+            line.render(&line_settings, screen);
         }
+
+        Function::render_arrow_head((Vec::new(), self.arrow_lines), settings.colour, &head_settings, screen);
     }
 
     /// Renders many Vectors.
@@ -665,6 +2774,23 @@ impl Renderable for Vector {
             vector.render(settings, screen);
         }
     }
+
+    /// Gets the box covered by a Vector's arrow head and line.
+    fn bounding_box(&self, settings : &Self::RenderSettings, screen : &Screen) -> Option<(Point, Point)> {
+        let mut points : Vec<Point> =
+            self.arrow_heads
+            .iter()
+            .flat_map(|arrow_head| arrow_head.vertices.clone())
+            .collect();
+
+        for line in self.line.iter().chain(&self.arrow_lines) {
+            points.extend(line.sample(2));
+        }
+
+        let reach = settings.stroke.as_ref().map_or(1.0, StrokeStyle::reach);
+
+        Some(pad_bounds(screen, get_bounds(&points), f32_to_u16((f32::from(settings.thickness.to_pixels(screen)) * reach).ceil())))
+    }
 }
 
 /// Represents the rendering settings for a DashedLine.
@@ -717,6 +2843,26 @@ impl Renderable for DashedLine {
             dashed_line.render(settings, screen);
         }
     }
+
+    /// Gets a box containing every dash.
+    fn bounding_box(&self, settings : &Self::RenderSettings, screen : &Screen) -> Option<(Point, Point)> {
+        let function_settings =
+            FunctionRenderSettings::new(
+                settings.colour,
+                settings.thickness,
+                settings.samples_per_dash,
+                settings.rendering_type
+            );
+
+        let corners : Vec<Point> =
+            self.dashes
+            .iter()
+            .filter_map(|dash| dash.bounding_box(&function_settings, screen))
+            .flat_map(|(bottom_left, top_right)| [bottom_left, top_right])
+            .collect();
+
+        if corners.is_empty() { None } else { Some(get_bounds(&corners)) }
+    }
 }
 
 /// Represents the rendering settings for a CartesianPlane.
@@ -735,6 +2881,16 @@ impl CartesianPlaneRenderSettings {
             samples_per_axis,
         }
     }
+
+    /// Gets the settings each axis is rendered with.
+    fn vector_settings(&self) -> VectorRenderSettings {
+        VectorRenderSettings::new(
+            self.colour,
+            self.thickness,
+            self.samples_per_axis,
+            RenderingType::Square
+        )
+    }
 }
 
 /*
@@ -755,53 +2911,613 @@ impl Renderable for CartesianPlane {
 
     /// Renders a CartesianPlane.
     fn render(self, settings : &Self::RenderSettings, screen : &mut Screen) {
+        for vector in self.axes() {
+            vector.render(&settings.vector_settings(), screen);
+        }
+    }
+
+    /// Renders many CartesianPlanes.
+    fn render_many(selfs : Vec<Box<Self>>, settings : &Self::RenderSettings, screen : &mut Screen) {
+        for cartesian_plane in selfs {
+            cartesian_plane.render(
+                settings,
+                screen
+            );
+        }
+    }
+
+    /// Gets a box containing every axis and its arrowhead.
+    fn bounding_box(&self, settings : &Self::RenderSettings, screen : &Screen) -> Option<(Point, Point)> {
+        let corners : Vec<Point> =
+            self.axes()
+            .iter()
+            .filter_map(|vector| vector.bounding_box(&settings.vector_settings(), screen))
+            .flat_map(|(bottom_left, top_right)| [bottom_left, top_right])
+            .collect();
 
-        let mut axis = Vec::with_capacity(4);
+        if corners.is_empty() { None } else { Some(get_bounds(&corners)) }
+    }
+}
+
+impl CartesianPlane {
+    /// Creates a Vector from the origin to each edge of the bounds that the origin is not on.
+    fn axes(&self) -> Vec<Vector> {
+        let mut axes = Vec::with_capacity(4);
 
         if self.origin.y < self.top_right_bound.y {
-            axis.push(Vector::new(
-                Point::new(self.origin.x, self.top_right_bound.y), self.origin, self.arrow_width, self.arrow_height
+            axes.push(Vector::with_arrow_head(
+                Point::new(self.origin.x, self.top_right_bound.y), self.origin, self.arrow_head
             ));
         }
 
         if self.origin.y > self.bottom_left_bound.y {
-            axis.push(Vector::new(
-                Point::new(self.origin.x, self.bottom_left_bound.y), self.origin, self.arrow_width, self.arrow_height
+            axes.push(Vector::with_arrow_head(
+                Point::new(self.origin.x, self.bottom_left_bound.y), self.origin, self.arrow_head
             ));
         }
 
         if self.origin.x < self.top_right_bound.x {
-            axis.push(Vector::new(
-                Point::new(self.top_right_bound.x, self.origin.y), self.origin, self.arrow_width, self.arrow_height
+            axes.push(Vector::with_arrow_head(
+                Point::new(self.top_right_bound.x, self.origin.y), self.origin, self.arrow_head
             ));
         }
 
         if self.origin.x > self.bottom_left_bound.x {
-            axis.push(Vector::new(
-                Point::new(self.bottom_left_bound.x, self.origin.y), self.origin, self.arrow_width, self.arrow_height
+            axes.push(Vector::with_arrow_head(
+                Point::new(self.bottom_left_bound.x, self.origin.y), self.origin, self.arrow_head
             ));
         }
 
-        for vector in axis {
-            vector.render(
-                &VectorRenderSettings::new(
-                    settings.colour,
-                    settings.thickness,
-                    settings.samples_per_axis,
-                    RenderingType::Square
-                ),
-                screen
-            );
+        axes
+    }
+}
+
+/// Represents the rendering settings for a Trail, with the colour ramping from the head colour at the newest end to the tail colour at the oldest end.
+pub struct TrailRenderSettings {
+    head_colour : Colour,
+    tail_colour : Colour,
+    thickness : Thickness,
+    samples : u16,
+    decay : Decay,
+    style : TrailStyle,
+}
+
+impl TrailRenderSettings {
+    /// Creates a new TrailRenderSettings.
+    pub fn new(head_colour : Colour, tail_colour : Colour, thickness : Thickness, samples : u16, decay : Decay, style : TrailStyle) -> TrailRenderSettings {
+        TrailRenderSettings {
+            head_colour,
+            tail_colour,
+            thickness,
+            samples,
+            decay,
+            style,
         }
     }
+}
 
-    /// Renders many CartesianPlanes.
+impl Renderable for Trail {
+    type RenderSettings =
+        TrailRenderSettings;
+
+    /// Renders a Trail. Each pixel is blended once with the newest part of the trail covering it, so overlapping samples do not accumulate.
+    fn render(self, settings : &Self::RenderSettings, screen : &mut Screen) {
+        let samples =
+            match settings.style {
+                TrailStyle::Polyline => self.sample(settings.samples),
+                TrailStyle::Dots(dots) => self.sample(dots),
+            };
+
+        let radius = i32::from(settings.thickness.to_pixels(screen));
+
+        let mut coverage : HashMap<(i32, i32), (f32, Colour)> = HashMap::new();
+
+        for (point, age) in samples {
+            let opacity = settings.decay.opacity(age).clamp(0.0, 1.0);
+            let colour = Colour::lerp(settings.head_colour, settings.tail_colour, age);
+            let coordinate = point_to_pixel_coordinates(screen, point);
+
+            for i in (coordinate.x - radius)..(coordinate.x + radius) {
+                for j in (coordinate.y - radius)..(coordinate.y + radius) {
+                    let squared_distance =
+                        (i - coordinate.x) * (i - coordinate.x) + (j - coordinate.y) * (j - coordinate.y);
+
+                    if squared_distance < radius * radius && within_screen(PixelCoordinate::new(i, j), screen) {
+                        let entry = coverage.entry((i, j)).or_insert((opacity, colour));
+
+                        if opacity > entry.0 {
+                            *entry = (opacity, colour);
+                        }
+                    }
+                }
+            }
+        }
+
+        for ((i, j), (opacity, colour)) in coverage {
+            let (i, j) = (usize::try_from(i).unwrap(), usize::try_from(j).unwrap());
+
+            screen.cover((i, j), screen.blend(screen[(i, j)], colour, opacity), opacity);
+        }
+    }
+
+    /// Renders many Trails.
     fn render_many(selfs : Vec<Box<Self>>, settings : &Self::RenderSettings, screen : &mut Screen) {
-        for cartesian_plane in selfs {
-            cartesian_plane.render(
-                settings,
-                screen
-            );
+        for trail in selfs {
+            trail.render(settings, screen);
+        }
+    }
+}
+
+/// Represents the rendering settings for an Image, with the rectangle it fills and an opacity multiplied with the alpha of the image.
+pub struct ImageRenderSettings {
+    bottom_left : Point,
+    top_right : Point,
+    sampling : ImageSampling,
+    opacity : f32,
+}
+
+impl ImageRenderSettings {
+    /// Creates a new ImageRenderSettings.
+    pub fn new(bottom_left : Point, top_right : Point, sampling : ImageSampling, opacity : f32) -> ImageRenderSettings {
+        ImageRenderSettings {
+            bottom_left,
+            top_right,
+            sampling,
+            opacity,
+        }
+    }
+}
+
+impl Renderable for Image {
+    type RenderSettings =
+        ImageRenderSettings;
+
+    /// Renders an Image, stretched to fill the rectangle between the two corners.
+    fn render(self, settings : &Self::RenderSettings, screen : &mut Screen) {
+        if self.width == 0 || self.height == 0 {
+            return;
+        }
+
+        // Corners of the rectangle in fractional pixel coordinates.
+        let to_pixels = |point : Point| -> (f32, f32) {
+            (
+                (point.x - screen.bottom_left_bound.x) / (screen.top_right_bound.x - screen.bottom_left_bound.x) * f32::from(screen.horizontal_resolution),
+                (point.y - screen.bottom_left_bound.y) / (screen.top_right_bound.y - screen.bottom_left_bound.y) * f32::from(screen.vertical_resolution),
+            )
+        };
+
+        let (left, bottom) = to_pixels(settings.bottom_left);
+        let (right, top) = to_pixels(settings.top_right);
+
+        let horizontal_range =
+            f32_to_u32(left.min(right).round().max(0.0))..f32_to_u32(left.max(right).round().clamp(0.0, f32::from(screen.horizontal_resolution)));
+        let rows = screen.stored_rows();
+        let vertical_range =
+            f32_to_u32(bottom.min(top).round().max(i32_to_f32(rows.start)))..f32_to_u32(bottom.max(top).round().clamp(i32_to_f32(rows.start), i32_to_f32(rows.end)));
+
+        for i in horizontal_range {
+            let u = (u32_to_f32(i) + 0.5 - left) / (right - left);
+
+            for j in vertical_range.clone() {
+                let v = (u32_to_f32(j) + 0.5 - bottom) / (top - bottom);
+
+                let (colour, alpha) = self.sample(u, v, settings.sampling, screen.blending);
+                let opacity = (alpha * settings.opacity).clamp(0.0, 1.0);
+
+                if opacity > 0.0 {
+                    let (i, j) = (i as usize, j as usize);
+                    screen.cover((i, j), screen.blend(screen[(i, j)], colour, opacity), opacity);
+                }
+            }
+        }
+    }
+
+    /// Renders many Images.
+    fn render_many(selfs : Vec<Box<Self>>, settings : &Self::RenderSettings, screen : &mut Screen) {
+        for image in selfs {
+            image.render(settings, screen);
+        }
+    }
+
+    /// Gets the rectangle an Image is stretched to fill.
+    fn bounding_box(&self, settings : &Self::RenderSettings, _screen : &Screen) -> Option<(Point, Point)> {
+        Some(get_bounds(&vec![settings.bottom_left, settings.top_right]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK : Colour = Colour { red : 0, green : 0, blue : 0 };
+    const WHITE : Colour = Colour { red : 255, green : 255, blue : 255 };
+
+    /// Creates a blank screen with one unit for each pixel, so that points land in the middle of pixels.
+    fn blank_screen(width : u16, height : u16, colour : Colour) -> Screen {
+        Screen::new(width, height, Point::new(0.0, 0.0), Point::new(f32::from(width), f32::from(height)), colour)
+    }
+
+    /// Gets the colour of each channel of a grey.
+    fn grey(level : u8) -> Colour {
+        Colour::from_rgb(level, level, level)
+    }
+
+    #[test]
+    fn half_blend_of_black_and_white() {
+        let linear = blank_screen(1, 1, BLACK);
+        let legacy = blank_screen(1, 1, BLACK).with_blending(Blending::Legacy);
+
+        // Half of the light of white is brighter than half of its sRGB value.
+        assert!(linear.blend(BLACK, WHITE, 0.5) == grey(188));
+        // Legacy blending truncates, as colours were always blended before linear light.
+        assert!(legacy.blend(BLACK, WHITE, 0.5) == grey(127));
+    }
+
+    #[test]
+    fn anti_aliased_edges_use_the_screen_blending() {
+        for blending in [Blending::Linear, Blending::Legacy] {
+            let mut screen = blank_screen(20, 20, BLACK).with_blending(blending);
+            let centre = Point::new(10.0, 10.0);
+
+            centre.render(&PointRenderSettings::new(WHITE, Thickness::Absolute(4), RenderingType::RoundAntiAliased(1.0)), &mut screen);
+
+            // Two pixels across and two up is half of the radius squared from the centre, so it is blended half way.
+            let pixel = point_to_pixel_coordinates(&screen, centre);
+            let edge = screen.pixel((pixel.x + 2) as u16, (pixel.y + 2) as u16).unwrap();
+
+            assert!(edge == screen.blend(WHITE, BLACK, 0.5));
+            assert!(edge == grey(if blending == Blending::Linear { 188 } else { 127 }));
+        }
+    }
+
+    #[test]
+    fn box_supersampling_a_checkerboard_gives_uniform_grey() {
+        for (blending, level) in [(Blending::Linear, 188), (Blending::Legacy, 128)] {
+            let mut screen = blank_screen(4, 3, BLACK).with_blending(blending).with_supersampling(2, SupersamplingFilter::Box);
+
+            for y in 0..6 {
+                for x in 0..8 {
+                    if (x + y) % 2 == 0 {
+                        screen.set_pixel(x, y, WHITE);
+                    }
+                }
+            }
+
+            let output = screen.output_screen();
+
+            assert_eq!((output.horizontal_resolution, output.vertical_resolution), (4, 3));
+            assert!(output.pixels().iter().all(|pixel| *pixel == grey(level)));
+        }
+    }
+
+    /// Draws a scene which has objects crossing the boundaries of any tiles, so that it must look the same whether it is drawn in one go or in tiles.
+    fn draw_scene(screen : Screen) -> Screen {
+        let points : Vec<Box<Point>> = (0..40).map(|i| Box::new(Point::new(-5.5 + 0.27 * i as f32, 4.0 * (0.7 * i as f32).sin()))).collect();
+
+        let arrow_head = ArrowHead::new(ArrowHeadShape::Triangle, 0.6, 0.8, ArrowHeadPlacement::Both);
+        let wave = Function::new(Box::new(|t| Point::new(t, 3.0 * (1.3 * t).sin())), (-5.0, 5.0));
+        let wave_settings =
+            FunctionRenderSettings::new(Colour::from_rgb(40, 200, 90), Thickness::Absolute(5), 400, RenderingType::RoundAntiAliased(1.0))
+            .with_stroke(StrokeStyle::new(LineCap::Round, LineJoin::Round))
+            .with_arrow_head(arrow_head);
+
+        let gradient = Paint::Linear(Point::new(-4.0, -4.0), Point::new(4.0, 4.0), ColourScale::viridis());
+        let radial = Paint::Radial(Point::new(2.0, -1.0), 2.5, ColourScale::magma());
+        let sides =
+            PolygonSidesRenderSettings::new(WHITE, Thickness::Absolute(3), 30, RenderingType::RoundAntiAliased(1.0))
+            .with_stroke(StrokeStyle::new(LineCap::Round, LineJoin::Miter(4.0)));
+
+        let image_pixels = (0..12).map(|i| (Colour::from_rgb(20 * i, 255 - 20 * i, 128), 100 + 10 * i)).collect();
+
+        screen
+        .render(Polygon::new_regular(6, Point::new(-2.5, 0.5), 3.0, 0.3), PolygonRenderSettings::new(Some(sides), Some(PolygonFillRenderSettings::from_paint(gradient))))
+        .render(Polygon::new_star(5, Point::new(2.0, -1.0), 2.5, 1.0, 0.0), PolygonRenderSettings::new(None, Some(PolygonFillRenderSettings::from_paint(radial))))
+        .render(Image::new(4, 3, image_pixels), ImageRenderSettings::new(Point::new(0.5, 0.3), Point::new(5.1, 3.9), ImageSampling::Bilinear, 0.8))
+        .render(wave, wave_settings)
+        .render_many(points, PointRenderSettings::new(Colour::from_rgb(230, 60, 30), Thickness::Absolute(3), RenderingType::RoundAntiAliased(1.0)))
+    }
+
+    #[test]
+    fn tiled_rendering_matches_serial_rendering() {
+        let blank = Screen::new(120, 90, Point::new(-6.0, -4.5), Point::new(6.0, 4.5), BLACK);
+        let serial = draw_scene(blank.clone());
+
+        for tiles in [2, 3, 7, 16, 90] {
+            let tiled = blank.clone().render_tiled(tiles, draw_scene);
+
+            assert!(tiled.pixels() == serial.pixels(), "{} tiles differ from drawing in one go", tiles);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Fills cannot be used while rendering in tiles")]
+    fn fills_panic_while_rendering_in_tiles() {
+        blank_screen(4, 4, BLACK).render_tiled(2, |tile| tile.fill(Point::new(1.5, 1.5), WHITE));
+    }
+
+    /// Paints the whole of a screen white, for seeing which pixels a clip lets through.
+    fn paint_white(screen : Screen) -> Screen {
+        let (width, height) = (f32::from(screen.horizontal_resolution), f32::from(screen.vertical_resolution));
+
+        screen.render(Polygon::new_rectangle(Point::new(-1.0, -1.0), Point::new(width + 1.0, height + 1.0)), PolygonRenderSettings::new(None, Some(PolygonFillRenderSettings::new(WHITE))))
+    }
+
+    /// Checks that exactly the pixels with both coordinates in the range are white, and that every other pixel is untouched.
+    fn assert_only_square_is_white(screen : &Screen, range : Range<u16>, untouched : Colour) {
+        for y in 0..screen.vertical_resolution {
+            for x in 0..screen.horizontal_resolution {
+                let expected = if range.contains(&x) && range.contains(&y) { WHITE } else { untouched };
+
+                assert!(screen.pixel(x, y) == Some(expected), "pixel ({}, {}) is wrong", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn nothing_outside_a_clip_changes() {
+        let red = Colour::from_rgb(255, 0, 0);
+
+        // The edges lie half way between pixels, so every pixel is either fully inside or fully outside.
+        let screen = blank_screen(40, 40, red).with_clip(Clip::Rectangle(Point::new(9.5, 9.5), Point::new(29.5, 29.5)), paint_white);
+
+        assert_only_square_is_white(&screen, 10..30, red);
+    }
+
+    #[test]
+    fn nested_clips_intersect() {
+        let screen =
+            blank_screen(40, 40, BLACK)
+            .with_clip(Clip::Rectangle(Point::new(5.5, 5.5), Point::new(25.5, 25.5)), |screen| {
+                screen.with_clip(Clip::Rectangle(Point::new(15.5, 15.5), Point::new(35.5, 35.5)), paint_white)
+            });
+
+        assert_only_square_is_white(&screen, 16..26, BLACK);
+    }
+
+    /// Determines if two colours are within one step of each other in every channel.
+    fn nearly_equal(first : Colour, second : Colour) -> bool {
+        [(first.red, second.red), (first.green, second.green), (first.blue, second.blue)].iter().all(|(a, b)| a.abs_diff(*b) <= 1)
+    }
+
+    /// Renders a solid circle of whole pixels.
+    fn circle(screen : Screen, centre : Point, radius : u16, colour : Colour) -> Screen {
+        screen.render(centre, PointRenderSettings::new(colour, Thickness::Absolute(radius), RenderingType::RoundAliased))
+    }
+
+    #[test]
+    fn blend_modes_on_known_values() {
+        let blends = [
+            (BlendMode::Normal, 0.25, 0.5, 0.5),
+            (BlendMode::Multiply, 0.25, 0.5, 0.125),
+            (BlendMode::Screen, 0.25, 0.5, 0.625),
+            (BlendMode::Overlay, 0.25, 0.5, 0.25),
+            (BlendMode::Overlay, 0.75, 0.5, 0.75),
+            (BlendMode::Add, 0.25, 0.5, 0.75),
+            (BlendMode::Add, 0.75, 0.5, 1.0),
+            (BlendMode::Difference, 0.25, 0.5, 0.25),
+            (BlendMode::Difference, 0.75, 0.25, 0.5),
+        ];
+
+        for (blend_mode, base, source, expected) in blends {
+            assert!((blend_mode.blend(base, source) - expected).abs() < 1e-6, "{:?} of {} and {} is not {}", blend_mode, base, source, expected);
+        }
+    }
+
+    #[test]
+    fn multiplied_circles_give_the_product_where_they_overlap() {
+        let base = Colour::from_rgb(200, 100, 50);
+        let source = Colour::from_rgb(128, 255, 64);
+
+        for blending in [Blending::Linear, Blending::Legacy] {
+            let screen =
+                circle(blank_screen(40, 20, WHITE).with_blending(blending), Point::new(14.0, 10.0), 8, base)
+                .with_layer("multiply", LayerSettings::new(BlendMode::Multiply, 1.0), |layer| circle(layer, Point::new(26.0, 10.0), 8, source))
+                .flatten();
+
+            let (base_channels, source_channels) = (blending.channels(base), blending.channels(source));
+            let product = blending.colour([0, 1, 2].map(|channel| base_channels[channel] * source_channels[channel]));
+
+            assert!(screen.pixel(20, 10) == Some(product));
+            assert!(screen.pixel(8, 10) == Some(base));
+            assert!(screen.pixel(32, 10).is_some_and(|pixel| nearly_equal(pixel, source)));
+            assert!(screen.pixel(20, 1) == Some(WHITE));
+        }
+    }
+
+    #[test]
+    fn normal_layers_match_drawing_directly() {
+        let settings = || PointRenderSettings::new(Colour::from_rgb(220, 40, 10), Thickness::Absolute(6), RenderingType::RoundAntiAliased(1.0));
+        let background = Colour::from_rgb(30, 90, 160);
+
+        for blending in [Blending::Linear, Blending::Legacy] {
+            let blank = blank_screen(20, 20, background).with_blending(blending);
+
+            let direct = blank.clone().render(Point::new(10.0, 10.0), settings());
+            let layered =
+                blank
+                .with_layer("point", LayerSettings::new(BlendMode::Normal, 1.0), |layer| layer.render(Point::new(10.0, 10.0), settings()))
+                .flatten();
+
+            // Partially covered pixels at the edges keep their colour, rather than being treated as grey.
+            assert!(direct.pixels().iter().zip(layered.pixels()).all(|(direct, layered)| nearly_equal(*direct, *layered)));
+        }
+    }
+
+    #[test]
+    fn layer_opacity_scales_what_is_drawn() {
+        let screen =
+            blank_screen(8, 8, BLACK)
+            .with_layer("half", LayerSettings::new(BlendMode::Normal, 0.5), paint_white)
+            .flatten();
+
+        assert!(screen.pixels().iter().all(|pixel| *pixel == screen.blend(BLACK, WHITE, 0.5)));
+    }
+
+    #[test]
+    fn fills_spread_over_the_transparent_parts_of_layers() {
+        let red = Colour::from_rgb(255, 0, 0);
+
+        let screen =
+            circle(blank_screen(20, 20, Colour::from_rgb(0, 255, 255)), Point::new(10.0, 10.0), 4, WHITE)
+            .with_layer("fill", LayerSettings::new(BlendMode::Multiply, 1.0), |layer| layer.fill(Point::new(1.0, 1.0), red))
+            .flatten();
+
+        // The whole layer is transparent, so the fill covers the circle as well.
+        assert!(screen.pixel(1, 1) == Some(BLACK));
+        assert!(screen.pixel(10, 10) == Some(red));
+    }
+
+    #[test]
+    fn flattening_composites_layers_in_the_order_they_were_created() {
+        let (red, green, blue) = (Colour::from_rgb(255, 0, 0), Colour::from_rgb(0, 255, 0), Colour::from_rgb(0, 0, 255));
+        let normal = LayerSettings::new(BlendMode::Normal, 1.0);
+
+        let screen =
+            blank_screen(30, 10, BLACK)
+            .with_layer("lower", normal, |layer| circle(layer, Point::new(10.0, 5.0), 4, red))
+            .with_layer("upper", normal, |layer| circle(layer, Point::new(15.0, 5.0), 4, blue))
+            .with_layer("lower", normal, |layer| circle(layer, Point::new(20.0, 5.0), 4, green));
+
+        // Layers are kept apart from the pixels until they are flattened.
+        assert_eq!(screen.layers.len(), 2);
+        assert!(screen.pixels().iter().all(|pixel| *pixel == BLACK));
+
+        let output = screen.output_screen().into_owned();
+        let flattened = screen.flatten();
+
+        assert!(flattened.layers.is_empty());
+        assert!(flattened.pixels() == output.pixels());
+        assert!(flattened.pixel(7, 5) == Some(red));
+        assert!(flattened.pixel(17, 5) == Some(blue));
+        assert!(flattened.pixel(23, 5) == Some(green));
+        assert!(flattened.pixel(2, 5) == Some(BLACK));
+    }
+
+    #[test]
+    fn dash_intervals_start_the_offset_into_the_pattern() {
+        let intervals = |pattern : DashPattern, total : f32| pattern.intervals(total);
+
+        assert_eq!(intervals(DashPattern::new(vec![2.0, 1.0], 0.5), 7.0), vec![(0.0, 1.5), (2.5, 4.5), (5.5, 7.0)]);
+        // Negative offsets wrap around the period of the pattern.
+        assert_eq!(intervals(DashPattern::new(vec![2.0, 1.0], -0.5), 4.0), vec![(0.5, 2.5), (3.5, 4.0)]);
+        // Odd patterns are repeated, so the single length is both the dash and the gap.
+        assert_eq!(intervals(DashPattern::new(vec![1.0], 0.0), 3.5), vec![(0.0, 1.0), (2.0, 3.0)]);
+    }
+
+    #[test]
+    fn dots_are_dashes_of_no_length() {
+        assert_eq!(DashPattern::dotted(1.0, 0.25).intervals(3.0), vec![(0.75, 0.75), (1.75, 1.75), (2.75, 2.75)]);
+        assert_eq!(DashPattern::dotted(1.0, 0.0).intervals(2.0), vec![(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]);
+    }
+
+    #[test]
+    fn square_caps_reach_half_the_width_past_butt_caps() {
+        // Gets the first and last columns drawn on the row through a horizontal line with the provided cap.
+        let extent = |cap : LineCap| -> (u16, u16) {
+            let settings =
+                FunctionRenderSettings::new(WHITE, Thickness::Absolute(6), 50, RenderingType::RoundAliased)
+                .with_stroke(StrokeStyle::new(cap, LineJoin::Round));
+
+            let screen = blank_screen(60, 20, BLACK).render(Function::new_line_segment(Point::new(15.0, 10.0), Point::new(45.0, 10.0), (0.0, 1.0)), settings);
+            let drawn : Vec<u16> = (0..60).filter(|x| screen.pixel(*x, 10) == Some(WHITE)).collect();
+
+            (drawn[0], drawn[drawn.len() - 1])
+        };
+
+        let (butt, square) = (extent(LineCap::Butt), extent(LineCap::Square));
+
+        // Butt caps stop at the ends, and thicknesses are measured from the path to the edge, so are half the width of the stroke.
+        assert_eq!(butt, (15, 45));
+        assert_eq!((butt.0 - square.0, square.1 - butt.1), (6, 6));
+    }
+
+    /// Counts the pixels of a colour in a column of the screen.
+    fn column_height(screen : &Screen, x : u16, colour : Colour) -> usize {
+        (0..screen.vertical_resolution).filter(|y| screen.pixel(x, *y) == Some(colour)).count()
+    }
+
+    #[test]
+    fn double_headed_vectors_have_a_head_at_each_end() {
+        let arrow_head = ArrowHead::new(ArrowHeadShape::Triangle, 8.0, 8.0, ArrowHeadPlacement::Both);
+        let screen =
+            blank_screen(60, 40, BLACK)
+            .render(Vector::with_arrow_head(Point::new(50.0, 20.0), Point::new(10.0, 20.0), arrow_head), VectorRenderSettings::new(WHITE, Thickness::Absolute(1), 100, RenderingType::RoundAliased));
+
+        let height = |x : u16| column_height(&screen, x, WHITE);
+
+        // The heads mirror each other about the middle of the vector, where only the line is drawn.
+        assert_eq!(height(45), height(14));
+        assert!(height(45) >= 4);
+        assert!(height(30) < height(45) && height(30) > 0);
+        assert_eq!((height(8), height(51)), (0, 0));
+    }
+
+    #[test]
+    fn both_places_heads_at_the_origin_end_of_each_axis() {
+        let draw = |placement : ArrowHeadPlacement| {
+            let plane = CartesianPlane::new(Point::new(0.0, 0.0), Point::new(60.0, 40.0), Point::new(30.0, 20.0), 6.0, 6.0).with_arrow_head(ArrowHead::new(ArrowHeadShape::Triangle, 6.0, 6.0, placement));
+
+            blank_screen(60, 40, BLACK).render(plane, CartesianPlaneRenderSettings::new(WHITE, Thickness::Absolute(1), 100))
+        };
+
+        let (end, both) = (draw(ArrowHeadPlacement::End), draw(ArrowHeadPlacement::Both));
+
+        // Next to the origin, the heads of Both are wider than the axis alone.
+        for x in [26, 34] {
+            assert!(column_height(&both, x, WHITE) > column_height(&end, x, WHITE));
         }
+
+        // Both keeps the heads at the outer end of each axis.
+        assert_eq!(column_height(&end, 57, WHITE), column_height(&both, 57, WHITE));
+        assert!(column_height(&end, 57, WHITE) > column_height(&end, 45, WHITE));
+    }
+
+    #[test]
+    fn arrow_heads_trim_the_line_by_their_setback() {
+        let arc = Function::new_arc(20.0, Point::new(40.0, 5.0), 0.0, std::f32::consts::PI);
+        let samples = arc.sample(400);
+        let progress = Function::progress(&samples, &FunctionRenderSettings::new(WHITE, Thickness::Absolute(1), 400, RenderingType::RoundAliased));
+        let total = 20.0 * std::f32::consts::PI;
+
+        // Distances along the arc, from the angle of a point on it.
+        let along = |point : Point| 20.0 * (point.y - 5.0).atan2(point.x - 40.0);
+
+        let both = ArrowHead::new(ArrowHeadShape::Barbed, 4.0, 5.0, ArrowHeadPlacement::Both);
+        let (trimmed, trimmed_progress, heads) = Function::place_arrow_heads(samples.clone(), progress.clone(), both);
+
+        assert_eq!(heads.len(), 2);
+        assert_eq!(trimmed.len(), trimmed_progress.len());
+        assert!((along(trimmed[0]) - both.setback()).abs() < 0.01);
+        assert!((along(trimmed[trimmed.len() - 1]) - (total - both.setback())).abs() < 0.01);
+        assert!(trimmed_progress.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        // Middle arrowheads sit over the line, so it is not trimmed, and they are centred on the middle of it.
+        let middle = ArrowHead::new(ArrowHeadShape::Triangle, 4.0, 5.0, ArrowHeadPlacement::Middle);
+        let (untrimmed, _, heads) = Function::place_arrow_heads(samples.clone(), progress, middle);
+
+        assert_eq!(untrimmed.len(), samples.len());
+        assert!((along(heads[0].tip) - (total + 5.0) / 2.0).abs() < 0.01);
+        assert!((along(heads[0].from) - (total - 5.0) / 2.0).abs() < 0.01);
+        assert!((heads[0].progress - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn middle_arrow_heads_point_along_curves() {
+        let arrow_head = ArrowHead::new(ArrowHeadShape::Triangle, 8.0, 8.0, ArrowHeadPlacement::Middle);
+        let settings =
+            FunctionRenderSettings::new(WHITE, Thickness::Absolute(1), 400, RenderingType::RoundAliased)
+            .with_stroke(StrokeStyle::new(LineCap::Butt, LineJoin::Round))
+            .with_arrow_head(arrow_head);
+
+        let screen = blank_screen(80, 40, BLACK).render(Function::new_arc(20.0, Point::new(40.0, 5.0), 0.0, std::f32::consts::PI), settings);
+
+        // The arc runs anticlockwise, so over the top it heads left, with the base of the arrowhead to the right of the top and its tip to the left.
+        let height = |x : u16| column_height(&screen, x, WHITE);
+
+        assert!(height(43) >= 6);
+        assert!(height(38) < height(43));
+        // Either side of the arrowhead, the arc is only as thick as its stroke.
+        assert!(height(47) <= 3 && height(33) <= 3);
+        assert!(screen.pixel(40, 25) == Some(WHITE));
     }
 }